@@ -0,0 +1,5 @@
+mod deprecated_usage_collector;
+mod operation_normalizer;
+
+pub use deprecated_usage_collector::DeprecatedUsageCollector;
+pub use operation_normalizer::OperationNormalizer;
@@ -0,0 +1,606 @@
+use crate::helpers::{line_and_column, RArrayIter, Warden};
+use crate::ruby_api::{DeprecatedUsage, DeprecatedUsageKind, SchemaDefinition};
+use crate::visibility_scoped::{
+    ScopedBaseInputType, ScopedCompositeType as CompositeType, ScopedInputType,
+    ScopedInputValueDefinition, ScopedSchemaDefinition, VisibilityCache,
+};
+use bluejay_core::definition::{InputType as CoreInputType, InputTypeReference};
+use bluejay_core::executable::{
+    OperationDefinition as CoreOperationDefinition, Selection as CoreSelection,
+};
+use bluejay_core::{
+    definition::{prelude::*, SchemaDefinition as CoreSchemaDefinition, TypeDefinitionReference},
+    AsIter, OperationType, Variable,
+};
+use bluejay_parser::ast::executable::{
+    ExecutableDocument, Field, OperationDefinition, Selection, SelectionSet,
+};
+use bluejay_parser::ast::{Directive, Value as ParserValue, VariableArgument};
+use bluejay_parser::HasSpan;
+use bluejay_validator::Path;
+use itertools::Itertools;
+use magnus::{exception, r_hash::ForEach, Error, RArray, RHash, RString, Value};
+use std::collections::HashSet;
+
+/// Walks an executable document against the schema as seen by the given context and
+/// reports every usage of a deprecated field, argument, input field or enum value.
+/// Values passed through variables are looked up in the given variables, falling back to
+/// the default value of the variable definition.
+/// Unknown names are skipped rather than reported, validation is left to `validate_query`.
+pub struct DeprecatedUsageCollector<'a> {
+    schema_definition: ScopedSchemaDefinition<'a>,
+    document: &'a ExecutableDocument<'a>,
+    query: &'a str,
+    variables: RHash,
+}
+
+impl<'a> DeprecatedUsageCollector<'a> {
+    pub fn collect(
+        schema: &SchemaDefinition,
+        query: &str,
+        operation_name: Option<&str>,
+        variables: RHash,
+        context: Value,
+    ) -> Result<Vec<DeprecatedUsage>, Error> {
+        let document = ExecutableDocument::parse(query).map_err(|errors| {
+            Error::new(
+                exception::arg_error(),
+                format!(
+                    "Document could not be parsed: {}",
+                    errors.iter().map(|error| error.message()).join(", "),
+                ),
+            )
+        })?;
+
+        let warden = Warden::new(context);
+        let visibility_cache = VisibilityCache::new(warden);
+        let schema_definition = ScopedSchemaDefinition::new(schema, &visibility_cache);
+
+        let instance = DeprecatedUsageCollector {
+            schema_definition,
+            document: &document,
+            query,
+            variables,
+        };
+
+        let mut usages = Vec::new();
+
+        for operation_definition in
+            document
+                .operation_definitions()
+                .iter()
+                .filter(|od| match operation_name {
+                    Some(operation_name) => {
+                        matches!(od.as_ref().name(), Some(n) if n == operation_name)
+                    }
+                    None => true,
+                })
+        {
+            instance.visit_operation_definition(operation_definition, &mut usages)?;
+        }
+
+        visibility_cache.warden().to_result().map(|_| usages)
+    }
+
+    fn visit_operation_definition(
+        &'a self,
+        operation_definition: &'a OperationDefinition<'a>,
+        usages: &mut Vec<DeprecatedUsage>,
+    ) -> Result<(), Error> {
+        self.visit_directives(
+            operation_definition
+                .as_ref()
+                .directives()
+                .into_iter()
+                .flat_map(AsIter::iter),
+            operation_definition,
+            &Path::default(),
+            usages,
+        )?;
+
+        let root_type = match operation_definition.as_ref().operation_type() {
+            OperationType::Query => Some(self.schema_definition.query()),
+            OperationType::Mutation => self.schema_definition.mutation(),
+            OperationType::Subscription => None,
+        };
+
+        match root_type {
+            Some(root_type) => self.visit_selection_set(
+                operation_definition.selection_set(),
+                CompositeType::Object(root_type),
+                operation_definition,
+                &Path::default(),
+                &mut HashSet::new(),
+                usages,
+            ),
+            None => Ok(()),
+        }
+    }
+
+    fn visit_selection_set(
+        &'a self,
+        selection_set: &'a SelectionSet<'a>,
+        parent_type: CompositeType<'a>,
+        operation_definition: &'a OperationDefinition<'a>,
+        path: &Path<'a>,
+        visited_fragments: &mut HashSet<&'a str>,
+        usages: &mut Vec<DeprecatedUsage>,
+    ) -> Result<(), Error> {
+        for selection in selection_set.iter() {
+            let directives = selection.as_ref().directives();
+
+            match selection {
+                Selection::Field(field) => {
+                    self.visit_field(
+                        field,
+                        parent_type,
+                        operation_definition,
+                        path,
+                        visited_fragments,
+                        usages,
+                    )?;
+                    self.visit_directives(
+                        directives.iter(),
+                        operation_definition,
+                        &path.push(field.response_key()),
+                        usages,
+                    )?;
+                }
+                Selection::InlineFragment(inline_fragment) => {
+                    self.visit_directives(directives.iter(), operation_definition, path, usages)?;
+                    let fragment_type = match inline_fragment.type_condition() {
+                        Some(type_condition) => {
+                            self.composite_type(type_condition.named_type().as_ref())
+                        }
+                        None => Some(parent_type),
+                    };
+                    if let Some(fragment_type) = fragment_type {
+                        self.visit_selection_set(
+                            inline_fragment.selection_set(),
+                            fragment_type,
+                            operation_definition,
+                            path,
+                            visited_fragments,
+                            usages,
+                        )?;
+                    }
+                }
+                Selection::FragmentSpread(fragment_spread) => {
+                    self.visit_directives(directives.iter(), operation_definition, path, usages)?;
+                    let fragment_name = fragment_spread.name().as_ref();
+                    // guards against cycles, which only exist in invalid documents
+                    if !visited_fragments.insert(fragment_name) {
+                        continue;
+                    }
+                    let fragment_definition = self
+                        .document
+                        .fragment_definitions()
+                        .iter()
+                        .find(|fd| fd.name().as_ref() == fragment_name);
+                    if let Some(fragment_definition) = fragment_definition {
+                        self.visit_directives(
+                            fragment_definition.directives().iter(),
+                            operation_definition,
+                            path,
+                            usages,
+                        )?;
+                        let fragment_type = self.composite_type(
+                            fragment_definition.type_condition().named_type().as_ref(),
+                        );
+                        if let Some(fragment_type) = fragment_type {
+                            self.visit_selection_set(
+                                fragment_definition.selection_set(),
+                                fragment_type,
+                                operation_definition,
+                                path,
+                                visited_fragments,
+                                usages,
+                            )?;
+                        }
+                    }
+                    visited_fragments.remove(fragment_name);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn visit_field(
+        &'a self,
+        field: &'a Field<'a>,
+        parent_type: CompositeType<'a>,
+        operation_definition: &'a OperationDefinition<'a>,
+        path: &Path<'a>,
+        visited_fragments: &mut HashSet<&'a str>,
+        usages: &mut Vec<DeprecatedUsage>,
+    ) -> Result<(), Error> {
+        let field_name = field.name().as_ref();
+        let field_definition = match parent_type
+            .field_definition(field_name, self.schema_definition.inner().index())
+        {
+            Some(field_definition) => field_definition,
+            None => return Ok(()),
+        };
+        let path = path.push(field.response_key());
+
+        if let Some(reason) = field_definition.inner().deprecation_reason() {
+            usages.push(self.deprecated_usage(
+                DeprecatedUsageKind::Field,
+                format!("{}.{}", parent_type.name(), field_name),
+                reason,
+                &path,
+                None,
+                field.name().span().byte_range().start,
+            ));
+        }
+
+        if let (Some(arguments), Some(arguments_definition)) =
+            (field.arguments(), field_definition.arguments_definition())
+        {
+            for argument in arguments.iter() {
+                let argument_name = argument.name().as_ref();
+                if let Some(ivd) = arguments_definition
+                    .iter()
+                    .find(|ivd| ivd.name() == argument_name)
+                {
+                    self.visit_argument(
+                        argument,
+                        ivd,
+                        format!("{}.{}({}:)", parent_type.name(), field_name, argument_name),
+                        operation_definition,
+                        &path,
+                        usages,
+                    )?;
+                }
+            }
+        }
+
+        if let Some(selection_set) = field.selection_set() {
+            if let Some(field_type) = CompositeType::from_output_type(field_definition.r#type()) {
+                self.visit_selection_set(
+                    selection_set,
+                    field_type,
+                    operation_definition,
+                    &path,
+                    visited_fragments,
+                    usages,
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn visit_directives(
+        &'a self,
+        directives: impl Iterator<Item = &'a Directive<'a, false>>,
+        operation_definition: &'a OperationDefinition<'a>,
+        path: &Path<'a>,
+        usages: &mut Vec<DeprecatedUsage>,
+    ) -> Result<(), Error> {
+        for directive in directives {
+            let directive_name = directive.name().as_ref();
+            let arguments_definition = self
+                .schema_definition
+                .get_directive_definition(directive_name)
+                .and_then(|directive_definition| directive_definition.arguments_definition());
+            if let (Some(arguments), Some(arguments_definition)) =
+                (directive.arguments(), arguments_definition)
+            {
+                for argument in arguments.iter() {
+                    let argument_name = argument.name().as_ref();
+                    if let Some(ivd) = arguments_definition
+                        .iter()
+                        .find(|ivd| ivd.name() == argument_name)
+                    {
+                        self.visit_argument(
+                            argument,
+                            ivd,
+                            format!("@{}({}:)", directive_name, argument_name),
+                            operation_definition,
+                            path,
+                            usages,
+                        )?;
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn visit_argument(
+        &'a self,
+        argument: &'a VariableArgument<'a>,
+        ivd: &'a ScopedInputValueDefinition<'a>,
+        coordinate: String,
+        operation_definition: &'a OperationDefinition<'a>,
+        path: &Path<'a>,
+        usages: &mut Vec<DeprecatedUsage>,
+    ) -> Result<(), Error> {
+        let argument_path = vec![argument.name().as_ref().to_owned()];
+
+        if let Some(reason) = ivd.inner().deprecation_reason() {
+            usages.push(self.deprecated_usage(
+                DeprecatedUsageKind::Argument,
+                coordinate,
+                reason,
+                path,
+                Some(argument_path.clone()),
+                argument.name().span().byte_range().start,
+            ));
+        }
+
+        self.visit_value(
+            argument.value(),
+            ivd.r#type(),
+            operation_definition,
+            path,
+            &argument_path,
+            usages,
+        )
+    }
+
+    fn visit_value<const CONST: bool>(
+        &'a self,
+        value: &'a ParserValue<'a, CONST>,
+        input_type: &'a ScopedInputType<'a>,
+        operation_definition: &'a OperationDefinition<'a>,
+        path: &Path<'a>,
+        argument_path: &[String],
+        usages: &mut Vec<DeprecatedUsage>,
+    ) -> Result<(), Error> {
+        match (value, input_type.as_ref()) {
+            (ParserValue::Variable(var), _) => {
+                let variable_name = var.name();
+                match self.variables.get(variable_name) {
+                    Some(variable_value) => self.visit_variable_value(
+                        variable_value,
+                        input_type,
+                        path,
+                        argument_path,
+                        value.span().byte_range().start,
+                        usages,
+                    ),
+                    None => {
+                        let default_value = operation_definition
+                            .as_ref()
+                            .variable_definitions()
+                            .into_iter()
+                            .flat_map(AsIter::iter)
+                            .find(|vd| vd.variable().name() == variable_name)
+                            .and_then(|vd| vd.default_value());
+                        match default_value {
+                            Some(default_value) => self.visit_value(
+                                default_value,
+                                input_type,
+                                operation_definition,
+                                path,
+                                argument_path,
+                                usages,
+                            ),
+                            None => Ok(()),
+                        }
+                    }
+                }
+            }
+            (ParserValue::List(l), InputTypeReference::List(inner, _)) => {
+                for (idx, value) in l.iter().enumerate() {
+                    self.visit_value(
+                        value,
+                        inner,
+                        operation_definition,
+                        path,
+                        &child_argument_path(argument_path, idx.to_string()),
+                        usages,
+                    )?;
+                }
+                Ok(())
+            }
+            (_, InputTypeReference::List(inner, _)) => self.visit_value(
+                value,
+                inner,
+                operation_definition,
+                path,
+                argument_path,
+                usages,
+            ),
+            (ParserValue::Enum(e), InputTypeReference::Base(ScopedBaseInputType::Enum(etd), _)) => {
+                if let Some(reason) = etd
+                    .enum_value_definitions()
+                    .iter()
+                    .find(|evd| evd.name() == e.as_str())
+                    .and_then(|evd| evd.inner().deprecation_reason())
+                {
+                    usages.push(self.deprecated_usage(
+                        DeprecatedUsageKind::EnumValue,
+                        format!("{}.{}", etd.name(), e.as_str()),
+                        reason,
+                        path,
+                        Some(argument_path.to_vec()),
+                        value.span().byte_range().start,
+                    ));
+                }
+                Ok(())
+            }
+            (
+                ParserValue::Object(o),
+                InputTypeReference::Base(ScopedBaseInputType::InputObject(iotd), _),
+            ) => {
+                for (key, value) in o.iter() {
+                    let ivd = match iotd
+                        .input_field_definitions()
+                        .iter()
+                        .find(|ivd| ivd.name() == key.as_ref())
+                    {
+                        Some(ivd) => ivd,
+                        None => continue,
+                    };
+                    let argument_path = child_argument_path(argument_path, key.as_ref());
+
+                    if let Some(reason) = ivd.inner().deprecation_reason() {
+                        usages.push(self.deprecated_usage(
+                            DeprecatedUsageKind::InputField,
+                            format!("{}.{}", iotd.name(), key.as_ref()),
+                            reason,
+                            path,
+                            Some(argument_path.clone()),
+                            key.span().byte_range().start,
+                        ));
+                    }
+
+                    self.visit_value(
+                        value,
+                        ivd.r#type(),
+                        operation_definition,
+                        path,
+                        &argument_path,
+                        usages,
+                    )?;
+                }
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Visits a Ruby value given for a variable, the usages it contains are reported at the
+    /// location of the variable reference in the document.
+    fn visit_variable_value(
+        &'a self,
+        value: Value,
+        input_type: &'a ScopedInputType<'a>,
+        path: &Path<'a>,
+        argument_path: &[String],
+        byte_offset: usize,
+        usages: &mut Vec<DeprecatedUsage>,
+    ) -> Result<(), Error> {
+        match input_type.as_ref() {
+            InputTypeReference::List(inner, _) => match RArray::from_value(value) {
+                Some(array) => {
+                    for (idx, value) in RArrayIter::<Value>::from(&array).enumerate() {
+                        self.visit_variable_value(
+                            value,
+                            inner,
+                            path,
+                            &child_argument_path(argument_path, idx.to_string()),
+                            byte_offset,
+                            usages,
+                        )?;
+                    }
+                    Ok(())
+                }
+                None => self.visit_variable_value(
+                    value,
+                    inner,
+                    path,
+                    argument_path,
+                    byte_offset,
+                    usages,
+                ),
+            },
+            InputTypeReference::Base(ScopedBaseInputType::Enum(etd), _) => {
+                let name = match RString::from_value(value) {
+                    Some(name) => name.to_string()?,
+                    None => return Ok(()),
+                };
+                if let Some(reason) = etd
+                    .enum_value_definitions()
+                    .iter()
+                    .find(|evd| evd.name() == name)
+                    .and_then(|evd| evd.inner().deprecation_reason())
+                {
+                    usages.push(self.deprecated_usage(
+                        DeprecatedUsageKind::EnumValue,
+                        format!("{}.{}", etd.name(), name),
+                        reason,
+                        path,
+                        Some(argument_path.to_vec()),
+                        byte_offset,
+                    ));
+                }
+                Ok(())
+            }
+            InputTypeReference::Base(ScopedBaseInputType::InputObject(iotd), _) => {
+                let hash = match RHash::from_value(value) {
+                    Some(hash) => hash,
+                    None => return Ok(()),
+                };
+                hash.foreach(|key: Value, value: Value| {
+                    let key = match RString::from_value(key) {
+                        Some(key) => key.to_string()?,
+                        None => return Ok(ForEach::Continue),
+                    };
+                    let ivd = match iotd
+                        .input_field_definitions()
+                        .iter()
+                        .find(|ivd| ivd.name() == key)
+                    {
+                        Some(ivd) => ivd,
+                        None => return Ok(ForEach::Continue),
+                    };
+                    let argument_path = child_argument_path(argument_path, key.as_str());
+
+                    if let Some(reason) = ivd.inner().deprecation_reason() {
+                        usages.push(self.deprecated_usage(
+                            DeprecatedUsageKind::InputField,
+                            format!("{}.{}", iotd.name(), key),
+                            reason,
+                            path,
+                            Some(argument_path.clone()),
+                            byte_offset,
+                        ));
+                    }
+
+                    self.visit_variable_value(
+                        value,
+                        ivd.r#type(),
+                        path,
+                        &argument_path,
+                        byte_offset,
+                        usages,
+                    )?;
+                    Ok(ForEach::Continue)
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn composite_type(&'a self, name: &str) -> Option<CompositeType<'a>> {
+        match self.schema_definition.get_type_definition(name)? {
+            TypeDefinitionReference::Object(otd) => Some(CompositeType::Object(otd)),
+            TypeDefinitionReference::Interface(itd) => Some(CompositeType::Interface(itd)),
+            TypeDefinitionReference::Union(utd) => Some(CompositeType::Union(utd)),
+            _ => None,
+        }
+    }
+
+    fn deprecated_usage(
+        &self,
+        kind: DeprecatedUsageKind,
+        coordinate: String,
+        reason: &str,
+        path: &Path,
+        argument_path: Option<Vec<String>>,
+        byte_offset: usize,
+    ) -> DeprecatedUsage {
+        let (line, column) = line_and_column(self.query, byte_offset);
+        DeprecatedUsage::new(
+            kind,
+            coordinate,
+            reason.to_owned(),
+            path.to_vec(),
+            argument_path,
+            line,
+            column,
+        )
+    }
+}
+
+fn child_argument_path(argument_path: &[String], key: impl Into<String>) -> Vec<String> {
+    let mut child = argument_path.to_vec();
+    child.push(key.into());
+    child
+}
@@ -0,0 +1,233 @@
+use bluejay_core::executable::{
+    OperationDefinition as CoreOperationDefinition, Selection as CoreSelection,
+    VariableType as CoreVariableType, VariableTypeReference,
+};
+use bluejay_core::{
+    AsIter, Directive as CoreDirective, OperationType, Value as CoreValue, ValueReference, Variable,
+};
+use bluejay_parser::ast::executable::{
+    ExecutableDocument, OperationDefinition, Selection, SelectionSet, VariableType,
+};
+use bluejay_parser::ast::{Directive, VariableArguments};
+use itertools::Itertools;
+use magnus::{exception, Error};
+use std::collections::HashSet;
+
+/// Prints an operation in a canonical form, so that operations that only differ in their literal
+/// values, aliases, formatting, fragment usage or selection order have the same signature.
+/// Literals are replaced with placeholders, fragment spreads are inlined, aliases and ignored
+/// tokens are removed, and selections, arguments and variable definitions are sorted.
+/// Directives on the operation and on variable definitions are not included.
+pub struct OperationNormalizer<'a> {
+    document: &'a ExecutableDocument<'a>,
+}
+
+impl<'a> OperationNormalizer<'a> {
+    pub fn signature(query: &str, operation_name: Option<&str>) -> Result<String, Error> {
+        let document = ExecutableDocument::parse(query).map_err(|errors| {
+            Error::new(
+                exception::arg_error(),
+                format!(
+                    "Document could not be parsed: {}",
+                    errors.iter().map(|error| error.message()).join(", "),
+                ),
+            )
+        })?;
+
+        let operation_definition = match operation_name {
+            Some(operation_name) => document
+                .operation_definitions()
+                .iter()
+                .find(|od| matches!(od.as_ref().name(), Some(n) if n == operation_name))
+                .ok_or_else(|| {
+                    Error::new(
+                        exception::arg_error(),
+                        format!("No operation definition named `{operation_name}`"),
+                    )
+                })?,
+            None if document.operation_definitions().len() == 1 => {
+                &document.operation_definitions()[0]
+            }
+            None => {
+                return Err(Error::new(
+                    exception::arg_error(),
+                    "Operation name is required when document does not contain exactly 1 operation definition",
+                ))
+            }
+        };
+
+        let instance = OperationNormalizer {
+            document: &document,
+        };
+
+        Ok(instance.operation_definition(operation_definition))
+    }
+
+    fn operation_definition(&self, operation_definition: &'a OperationDefinition<'a>) -> String {
+        let operation_definition_reference = operation_definition.as_ref();
+        let mut signature = String::from(match operation_definition_reference.operation_type() {
+            OperationType::Query => "query",
+            OperationType::Mutation => "mutation",
+            OperationType::Subscription => "subscription",
+        });
+
+        if let Some(name) = operation_definition_reference.name() {
+            signature.push(' ');
+            signature.push_str(name);
+        }
+
+        if let Some(variable_definitions) = operation_definition_reference.variable_definitions() {
+            let variable_definitions: Vec<String> = variable_definitions
+                .iter()
+                .map(|variable_definition| {
+                    let mut s = format!("${}:", variable_definition.variable().name());
+                    Self::write_variable_type(&mut s, variable_definition.r#type());
+                    if let Some(default_value) = variable_definition.default_value() {
+                        s.push('=');
+                        Self::write_value(&mut s, default_value);
+                    }
+                    s
+                })
+                .sorted()
+                .collect();
+            if !variable_definitions.is_empty() {
+                signature.push('(');
+                signature.push_str(&variable_definitions.join(","));
+                signature.push(')');
+            }
+        }
+
+        signature.push_str(
+            &self.selection_set(operation_definition.selection_set(), &mut HashSet::new()),
+        );
+
+        signature
+    }
+
+    fn selection_set(
+        &self,
+        selection_set: &'a SelectionSet<'a>,
+        visited_fragments: &mut HashSet<&'a str>,
+    ) -> String {
+        let selections: Vec<String> = selection_set
+            .iter()
+            .filter_map(|selection| self.selection(selection, visited_fragments))
+            .sorted()
+            .dedup()
+            .collect();
+        format!("{{{}}}", selections.join(" "))
+    }
+
+    fn selection(
+        &self,
+        selection: &'a Selection<'a>,
+        visited_fragments: &mut HashSet<&'a str>,
+    ) -> Option<String> {
+        let mut s = String::new();
+        match selection {
+            Selection::Field(field) => {
+                s.push_str(field.name().as_ref());
+                Self::write_arguments(&mut s, field.arguments());
+                Self::write_directives(&mut s, selection.as_ref().directives().iter());
+                if let Some(selection_set) = field.selection_set() {
+                    s.push_str(&self.selection_set(selection_set, visited_fragments));
+                }
+            }
+            Selection::InlineFragment(inline_fragment) => {
+                s.push_str("...");
+                if let Some(type_condition) = inline_fragment.type_condition() {
+                    s.push_str("on ");
+                    s.push_str(type_condition.named_type().as_ref());
+                }
+                Self::write_directives(&mut s, selection.as_ref().directives().iter());
+                s.push_str(&self.selection_set(inline_fragment.selection_set(), visited_fragments));
+            }
+            Selection::FragmentSpread(fragment_spread) => {
+                let fragment_name = fragment_spread.name().as_ref();
+                let fragment_definition = self
+                    .document
+                    .fragment_definitions()
+                    .iter()
+                    .find(|fd| fd.name().as_ref() == fragment_name)?;
+                // guards against cycles, which only exist in invalid documents
+                if !visited_fragments.insert(fragment_name) {
+                    return None;
+                }
+                s.push_str("...on ");
+                s.push_str(fragment_definition.type_condition().named_type().as_ref());
+                Self::write_directives(&mut s, selection.as_ref().directives().iter());
+                s.push_str(
+                    &self.selection_set(fragment_definition.selection_set(), visited_fragments),
+                );
+                visited_fragments.remove(fragment_name);
+            }
+        }
+        Some(s)
+    }
+
+    fn write_arguments(s: &mut String, arguments: Option<&VariableArguments>) {
+        let arguments: Vec<String> = arguments
+            .into_iter()
+            .flat_map(AsIter::iter)
+            .map(|argument| {
+                let mut argument_s = format!("{}:", argument.name().as_ref());
+                Self::write_value(&mut argument_s, argument.value());
+                argument_s
+            })
+            .sorted()
+            .collect();
+        if !arguments.is_empty() {
+            s.push('(');
+            s.push_str(&arguments.join(","));
+            s.push(')');
+        }
+    }
+
+    fn write_directives(
+        s: &mut String,
+        directives: impl Iterator<Item = &'a Directive<'a, false>>,
+    ) {
+        directives.for_each(|directive| {
+            s.push('@');
+            s.push_str(directive.name().as_ref());
+            Self::write_arguments(s, directive.arguments());
+        });
+    }
+
+    /// Writes the placeholder of a literal value: `0` for numbers, `""` for strings, `[]` for lists
+    /// and `{}` for objects. Variables, booleans, enum values and `null` are written as is.
+    fn write_value<const CONST: bool>(s: &mut String, value: &impl CoreValue<CONST>) {
+        match value.as_ref() {
+            ValueReference::Variable(var) => {
+                s.push('$');
+                s.push_str(var.name());
+            }
+            ValueReference::Integer(_) | ValueReference::Float(_) => s.push('0'),
+            ValueReference::String(_) => s.push_str("\"\""),
+            ValueReference::Boolean(b) => s.push_str(if b { "true" } else { "false" }),
+            ValueReference::Null => s.push_str("null"),
+            ValueReference::Enum(e) => s.push_str(e),
+            ValueReference::List(_) => s.push_str("[]"),
+            ValueReference::Object(_) => s.push_str("{}"),
+        }
+    }
+
+    fn write_variable_type(s: &mut String, variable_type: &VariableType) {
+        match variable_type.as_ref() {
+            VariableTypeReference::Named(_, required) => {
+                s.push_str(variable_type.as_ref().name());
+                if required {
+                    s.push('!');
+                }
+            }
+            VariableTypeReference::List(inner, required) => {
+                s.push('[');
+                Self::write_variable_type(s, inner);
+                s.push(']');
+                if required {
+                    s.push('!');
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use convert_case::{Case, Casing};
+
+mod operation_types_generator;
+mod ruby_writer;
+mod schema_classes_generator;
+
+pub use operation_types_generator::OperationTypesGenerator;
+use ruby_writer::RubyWriter;
+pub use schema_classes_generator::SchemaClassesGenerator;
+
+/// The constant name generated for a response key or a type name
+pub fn class_name(name: &str) -> String {
+    name.to_case(Case::Pascal)
+}
+
+/// The property name generated for a response key, the double underscore of
+/// introspection fields like `__typename` is kept
+pub fn ruby_name(name: &str) -> String {
+    match name.strip_prefix("__") {
+        Some(name) => format!("__{}", name.to_case(Case::Snake)),
+        None => name.to_case(Case::Snake),
+    }
+}
+
+/// A double quoted Ruby string literal for `s`, escaping `#` to prevent interpolation
+pub fn ruby_string_literal(s: &str) -> String {
+    format!("{s:?}").replace('#', "\\#")
+}
@@ -0,0 +1,1113 @@
+use crate::codegen::{class_name, ruby_name, RubyWriter};
+use crate::helpers::Warden;
+use crate::ruby_api::{
+    CustomScalarTypeDefinition, ObjectTypeDefinition, Scalar, SchemaDefinition,
+    UnionTypeDefinition, ValidationError,
+};
+use crate::visibility_scoped::{
+    ScopedBaseInputType, ScopedBaseOutputType, ScopedCompositeType as CompositeType,
+    ScopedEnumTypeDefinition, ScopedFieldDefinition, ScopedInputObjectTypeDefinition,
+    ScopedInputType, ScopedObjectTypeDefinition, ScopedOutputType, ScopedSchemaDefinition,
+    VisibilityCache,
+};
+use bluejay_core::definition::{
+    prelude::*, InputType as CoreInputType, InputTypeReference, OutputType as CoreOutputType,
+    OutputTypeReference, SchemaDefinition as CoreSchemaDefinition, TypeDefinitionReference,
+};
+use bluejay_core::executable::{
+    OperationDefinition as CoreOperationDefinition, Selection as CoreSelection,
+    VariableType as CoreVariableType, VariableTypeReference,
+};
+use bluejay_core::{AsIter, BuiltinScalarDefinition, Directive as CoreDirective, OperationType};
+use bluejay_parser::ast::executable::{
+    ExecutableDocument, OperationDefinition, Selection, SelectionSet, VariableType,
+};
+use bluejay_validator::executable::{BuiltinRulesValidator, Cache as ValidationCache};
+use indexmap::IndexMap;
+use itertools::Itertools;
+use magnus::{exception, Error, Value};
+use std::collections::{HashMap, HashSet};
+
+struct CollectedField<'a> {
+    field_definition: &'a ScopedFieldDefinition<'a>,
+    selection_sets: Vec<&'a SelectionSet<'a>>,
+    /// whether the field is only selected under `@skip` or `@include`
+    conditional: bool,
+}
+
+type CollectedFields<'a> = IndexMap<&'a str, CollectedField<'a>>;
+
+/// The struct of the possible types of an abstract type without a struct of their own,
+/// `ExecutionEngine` falls back to it as well
+const OTHER_VARIANT_NAME: &str = "Other";
+
+/// A struct generated for a field of composite type
+struct CompositeStruct {
+    /// the Sorbet type of a value of the field, a union of structs for abstract types
+    sorbet_type: String,
+    /// the constant responding to `from_h`
+    constant: String,
+}
+
+impl CompositeStruct {
+    fn single(name: String) -> Self {
+        Self {
+            sorbet_type: name.clone(),
+            constant: name,
+        }
+    }
+}
+
+/// The constant generated in a response struct for a field, named after its response key
+enum FieldClass {
+    Composite(CompositeStruct),
+    /// an alias of the `T::Enum` generated for an enum type
+    Enum {
+        constant: String,
+    },
+    /// an alias of the class of a custom scalar, whose `coerce_input` builds the internal representation
+    CustomScalar {
+        constant: String,
+        sorbet_type: String,
+        returns_result: bool,
+    },
+}
+
+impl FieldClass {
+    fn sorbet_type(&self) -> &str {
+        match self {
+            Self::Composite(composite_struct) => &composite_struct.sorbet_type,
+            Self::Enum { constant } => constant,
+            Self::CustomScalar { sorbet_type, .. } => sorbet_type,
+        }
+    }
+
+    /// Ruby expression building the value of the field from its JSON representation `source`
+    fn from_json_expression(&self, source: &str) -> String {
+        match self {
+            Self::Composite(CompositeStruct { constant, .. }) => {
+                format!("{constant}.from_h({source})")
+            }
+            Self::Enum { constant } => format!("{constant}.deserialize({source})"),
+            Self::CustomScalar {
+                constant,
+                returns_result,
+                ..
+            } => {
+                let expression = format!("{constant}.coerce_input({source})");
+                if *returns_result {
+                    format!("{expression}.unwrap")
+                } else {
+                    expression
+                }
+            }
+        }
+    }
+}
+
+/// The Ruby representation of an input type for the variables of an operation
+enum InputShape<'a> {
+    Leaf(String),
+    Enum(&'a ScopedEnumTypeDefinition<'a>),
+    CustomScalar(&'a CustomScalarTypeDefinition),
+    InputObject(&'a ScopedInputObjectTypeDefinition<'a>),
+    List(Box<InputShape<'a>>),
+    Nullable(Box<InputShape<'a>>),
+}
+
+impl<'a> InputShape<'a> {
+    fn from_input_type(input_type: &'a ScopedInputType<'a>) -> Self {
+        let shape = match input_type.as_ref() {
+            InputTypeReference::Base(base, _) => match base {
+                ScopedBaseInputType::BuiltinScalar(bstd) => Self::Leaf(
+                    Scalar::from(*bstd)
+                        .sorbet_type_fully_qualified_name()
+                        .to_owned(),
+                ),
+                ScopedBaseInputType::CustomScalar(cstd) => Self::CustomScalar(cstd.inner()),
+                ScopedBaseInputType::Enum(etd) => Self::Enum(etd),
+                ScopedBaseInputType::InputObject(iotd) => Self::InputObject(iotd),
+            },
+            InputTypeReference::List(inner, _) => {
+                Self::List(Box::new(Self::from_input_type(inner)))
+            }
+        };
+        Self::with_nullability(shape, input_type.as_ref().is_required())
+    }
+
+    fn with_nullability(shape: Self, required: bool) -> Self {
+        if required {
+            shape
+        } else {
+            Self::Nullable(Box::new(shape))
+        }
+    }
+
+    fn sorbet_type(&self) -> String {
+        match self {
+            Self::Leaf(sorbet_type) => sorbet_type.clone(),
+            Self::Enum(etd) => class_name(etd.name()),
+            Self::CustomScalar(cstd) => cstd.internal_representation_sorbet_type_name().to_owned(),
+            Self::InputObject(iotd) => class_name(iotd.name()),
+            Self::List(inner) => format!("T::Array[{}]", inner.sorbet_type()),
+            Self::Nullable(inner) => format!("T.nilable({})", inner.sorbet_type()),
+        }
+    }
+
+    /// Ruby expression converting `source` to its JSON-like hash representation
+    fn to_h_expression(&self, source: &str, depth: usize) -> String {
+        match self {
+            Self::Leaf(_) => source.to_owned(),
+            Self::Enum(_) => format!("{source}.serialize"),
+            Self::CustomScalar(cstd) => {
+                let expression = format!("{}.coerce_result({source})", cstd.ruby_class_name());
+                if cstd.coerce_result_returns_result() {
+                    format!("{expression}.unwrap")
+                } else {
+                    expression
+                }
+            }
+            Self::InputObject(_) => format!("{source}.to_h"),
+            Self::List(inner) => {
+                let item = format!("v{depth}");
+                let item_expression = inner.to_h_expression(&item, depth + 1);
+                if item_expression == item {
+                    source.to_owned()
+                } else {
+                    format!("{source}.map {{ |{item}| {item_expression} }}")
+                }
+            }
+            Self::Nullable(inner) => match inner.as_ref() {
+                Self::InputObject(_) => format!("{source}&.to_h"),
+                Self::Enum(_) => format!("{source}&.serialize"),
+                Self::CustomScalar(_) => {
+                    let item = format!("v{depth}");
+                    format!(
+                        "{source}&.then {{ |{item}| {} }}",
+                        inner.to_h_expression(&item, depth + 1),
+                    )
+                }
+                Self::List(list_inner) => {
+                    let item = format!("v{depth}");
+                    let item_expression = list_inner.to_h_expression(&item, depth + 1);
+                    if item_expression == item {
+                        source.to_owned()
+                    } else {
+                        format!("{source}&.map {{ |{item}| {item_expression} }}")
+                    }
+                }
+                _ => inner.to_h_expression(source, depth),
+            },
+        }
+    }
+}
+
+/// Generates `T::Struct` classes describing the variables and the response of every operation
+/// in an executable document, so that operations executed from Ruby can be consumed with types.
+/// Selections on abstract types that use type conditions become one struct per concrete type,
+/// discriminated by `__typename`, and the field is typed as the union of those structs.
+pub struct OperationTypesGenerator<'a> {
+    schema_definition: ScopedSchemaDefinition<'a>,
+    document: &'a ExecutableDocument<'a>,
+}
+
+impl<'a> OperationTypesGenerator<'a> {
+    pub fn generate(
+        schema: &SchemaDefinition,
+        query: &str,
+        context: Value,
+    ) -> Result<String, Error> {
+        let document = ExecutableDocument::parse(query).map_err(|errors| {
+            Error::new(
+                exception::arg_error(),
+                format!(
+                    "Document could not be parsed: {}",
+                    errors.iter().map(|error| error.message()).join(", "),
+                ),
+            )
+        })?;
+
+        let warden = Warden::new(context);
+        let visibility_cache = VisibilityCache::new(warden);
+        let schema_definition = ScopedSchemaDefinition::new(schema, &visibility_cache);
+
+        let validation_errors: Vec<String> = BuiltinRulesValidator::validate(
+            &document,
+            &schema_definition,
+            &ValidationCache::new(&document, &schema_definition),
+        )
+        .map(|error| ValidationError::from(error).message().to_owned())
+        .collect();
+        if !validation_errors.is_empty() {
+            return Err(Error::new(
+                exception::arg_error(),
+                format!("Document is invalid: {}", validation_errors.join(", ")),
+            ));
+        }
+
+        let instance = OperationTypesGenerator {
+            schema_definition,
+            document: &document,
+        };
+
+        let mut writer = RubyWriter::default();
+        writer.line("# typed: strict");
+        writer.line("# frozen_string_literal: true");
+        writer.blank_line();
+        writer.line("# This file is generated from a GraphQL document, do not edit it by hand");
+
+        let result = document.operation_definitions().iter().try_for_each(|od| {
+            writer.blank_line();
+            instance.write_operation_definition(&mut writer, od)
+        });
+
+        visibility_cache
+            .warden()
+            .to_result()
+            .and(result)
+            .map(|_| writer.into_string())
+    }
+
+    fn write_operation_definition(
+        &'a self,
+        writer: &mut RubyWriter,
+        operation_definition: &'a OperationDefinition<'a>,
+    ) -> Result<(), Error> {
+        let operation_name = operation_definition.as_ref().name().ok_or_else(|| {
+            Error::new(
+                exception::arg_error(),
+                "Operations must be named to generate types for them",
+            )
+        })?;
+        let root_type = match operation_definition.as_ref().operation_type() {
+            OperationType::Query => self.schema_definition.query(),
+            OperationType::Mutation => self.schema_definition.mutation().ok_or_else(|| {
+                Error::new(exception::arg_error(), "Schema does not support mutations")
+            })?,
+            OperationType::Subscription => {
+                return Err(Error::new(
+                    exception::arg_error(),
+                    format!("Cannot generate types for subscription `{operation_name}`"),
+                ))
+            }
+        };
+
+        let module_name = class_name(operation_name);
+        let variables = self.variables(operation_definition);
+        let fields = self.collect_fields(
+            CompositeType::Object(root_type),
+            Some(root_type),
+            &[operation_definition.selection_set()],
+        );
+
+        let mut input_objects = Vec::new();
+        let mut enums = Vec::new();
+        let mut forward_declarations = HashSet::new();
+        variables.iter().for_each(|(_, shape)| {
+            Self::collect_input_types(
+                shape,
+                &mut input_objects,
+                &mut enums,
+                &mut Vec::new(),
+                &mut forward_declarations,
+            )
+        });
+        self.collect_response_enums(&fields, &mut enums);
+
+        let mut type_class_names: HashMap<String, &str> = HashMap::new();
+        for type_name in input_objects
+            .iter()
+            .map(|iotd| iotd.name())
+            .chain(enums.iter().map(|etd| etd.name()))
+        {
+            let constant = class_name(type_name);
+            if matches!(constant.as_str(), "Data" | "Variables") {
+                return Err(Error::new(
+                    exception::arg_error(),
+                    format!(
+                        "Type `{type_name}` generates the class `{constant}`, which is reserved in the types of `{operation_name}`"
+                    ),
+                ));
+            }
+            if let Some(other_type_name) = type_class_names.insert(constant.clone(), type_name) {
+                return Err(Error::new(
+                    exception::arg_error(),
+                    format!(
+                        "Types `{other_type_name}` and `{type_name}` generate the same class `{constant}` in the types of `{operation_name}`"
+                    ),
+                ));
+            }
+        }
+
+        writer.open(format!("module {module_name}"));
+
+        for etd in &enums {
+            Self::write_enum(writer, etd)?;
+            writer.blank_line();
+        }
+
+        Self::write_variables(writer, &variables, &input_objects, &forward_declarations);
+
+        writer.blank_line();
+
+        self.write_struct(writer, "Data", &fields, operation_name, &module_name)?;
+
+        writer.close();
+
+        Ok(())
+    }
+
+    fn variables(
+        &'a self,
+        operation_definition: &'a OperationDefinition<'a>,
+    ) -> Vec<(&'a str, InputShape<'a>)> {
+        operation_definition
+            .as_ref()
+            .variable_definitions()
+            .map(|variable_definitions| {
+                variable_definitions
+                    .iter()
+                    .map(|variable_definition| {
+                        let shape = self.variable_shape(variable_definition.r#type());
+                        // a variable with a default value can always be omitted
+                        let shape = match shape {
+                            InputShape::Nullable(_) => shape,
+                            _ if variable_definition.default_value().is_some() => {
+                                InputShape::Nullable(Box::new(shape))
+                            }
+                            _ => shape,
+                        };
+                        (variable_definition.variable().name(), shape)
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    fn write_variables(
+        writer: &mut RubyWriter,
+        variables: &[(&str, InputShape)],
+        input_objects: &[&'a ScopedInputObjectTypeDefinition<'a>],
+        forward_declarations: &HashSet<&'a str>,
+    ) {
+        input_objects
+            .iter()
+            .filter(|iotd| forward_declarations.contains(iotd.name()))
+            .for_each(|iotd| {
+                writer.line(format!(
+                    "class {} < T::Struct; end",
+                    class_name(iotd.name())
+                ));
+                writer.blank_line();
+            });
+
+        input_objects.iter().for_each(|iotd| {
+            let fields: Vec<(&str, InputShape)> = iotd
+                .input_field_definitions()
+                .iter()
+                .map(|ivd| (ivd.name(), InputShape::from_input_type(ivd.r#type())))
+                .collect();
+            Self::write_input_struct(writer, &class_name(iotd.name()), &fields);
+            writer.blank_line();
+        });
+
+        Self::write_input_struct(writer, "Variables", variables);
+    }
+
+    /// Writes a `T::Enum` with a value for each enum value, its constants are the Pascal case of the names
+    fn write_enum(
+        writer: &mut RubyWriter,
+        etd: &ScopedEnumTypeDefinition<'a>,
+    ) -> Result<(), Error> {
+        writer.open(format!("class {} < T::Enum", class_name(etd.name())));
+        writer.open("enums do");
+        let mut constants: HashMap<String, &str> = HashMap::new();
+        for evd in etd.enum_value_definitions().iter() {
+            let constant = class_name(evd.name());
+            if !constant.starts_with(|c: char| c.is_ascii_uppercase()) {
+                return Err(Error::new(
+                    exception::arg_error(),
+                    format!(
+                        "Enum value `{}.{}` cannot be the name of a constant",
+                        etd.name(),
+                        evd.name(),
+                    ),
+                ));
+            }
+            if let Some(other_value) = constants.insert(constant.clone(), evd.name()) {
+                return Err(Error::new(
+                    exception::arg_error(),
+                    format!(
+                        "Enum values `{other_value}` and `{}` of `{}` generate the same constant `{constant}`",
+                        evd.name(),
+                        etd.name(),
+                    ),
+                ));
+            }
+            writer.line(format!("{constant} = new({:?})", evd.name()));
+        }
+        writer.close();
+        writer.close();
+        Ok(())
+    }
+
+    fn variable_shape(&'a self, variable_type: &'a VariableType<'a>) -> InputShape<'a> {
+        match variable_type.as_ref() {
+            VariableTypeReference::Named(_, required) => {
+                let shape = match self
+                    .schema_definition
+                    .get_type_definition(variable_type.as_ref().name())
+                    .unwrap()
+                {
+                    TypeDefinitionReference::BuiltinScalar(bstd) => InputShape::Leaf(
+                        Scalar::from(bstd)
+                            .sorbet_type_fully_qualified_name()
+                            .to_owned(),
+                    ),
+                    TypeDefinitionReference::InputObject(iotd) => InputShape::InputObject(iotd),
+                    TypeDefinitionReference::Enum(etd) => InputShape::Enum(etd),
+                    TypeDefinitionReference::CustomScalar(cstd) => {
+                        InputShape::CustomScalar(cstd.inner())
+                    }
+                    _ => InputShape::Leaf("T.untyped".to_owned()),
+                };
+                InputShape::with_nullability(shape, required)
+            }
+            VariableTypeReference::List(inner, required) => InputShape::with_nullability(
+                InputShape::List(Box::new(self.variable_shape(inner))),
+                required,
+            ),
+        }
+    }
+
+    /// Collects the input objects and enums reachable from `shape` so that dependencies come first.
+    /// Input objects referenced before their definition, which can only happen for cyclic
+    /// references, are recorded in `forward_declarations`.
+    fn collect_input_types(
+        shape: &InputShape<'a>,
+        input_objects: &mut Vec<&'a ScopedInputObjectTypeDefinition<'a>>,
+        enums: &mut Vec<&'a ScopedEnumTypeDefinition<'a>>,
+        in_progress: &mut Vec<&'a str>,
+        forward_declarations: &mut HashSet<&'a str>,
+    ) {
+        match shape {
+            InputShape::Leaf(_) | InputShape::CustomScalar(_) => {}
+            InputShape::Enum(etd) => Self::collect_enum(etd, enums),
+            InputShape::List(inner) | InputShape::Nullable(inner) => Self::collect_input_types(
+                inner,
+                input_objects,
+                enums,
+                in_progress,
+                forward_declarations,
+            ),
+            InputShape::InputObject(iotd) => {
+                if in_progress.contains(&iotd.name()) {
+                    forward_declarations.insert(iotd.name());
+                    return;
+                }
+                if input_objects.iter().any(|io| io.name() == iotd.name()) {
+                    return;
+                }
+                in_progress.push(iotd.name());
+                iotd.input_field_definitions().iter().for_each(|ivd| {
+                    Self::collect_input_types(
+                        &InputShape::from_input_type(ivd.r#type()),
+                        input_objects,
+                        enums,
+                        in_progress,
+                        forward_declarations,
+                    )
+                });
+                in_progress.pop();
+                input_objects.push(iotd);
+            }
+        }
+    }
+
+    /// Collects the enums of the fields selected in `fields`, at any depth
+    fn collect_response_enums(
+        &'a self,
+        fields: &CollectedFields<'a>,
+        enums: &mut Vec<&'a ScopedEnumTypeDefinition<'a>>,
+    ) {
+        for collected_field in fields.values() {
+            let output_type = collected_field.field_definition.r#type();
+            if let ScopedBaseOutputType::Enum(etd) = output_type.as_ref().base() {
+                Self::collect_enum(etd, enums);
+            }
+            if let Some(composite_type) = CompositeType::from_output_type(output_type) {
+                let selection_sets = collected_field.selection_sets.as_slice();
+                self.collect_response_enums(
+                    &self.collect_fields(composite_type, None, selection_sets),
+                    enums,
+                );
+                for object_type in self.possible_types(composite_type) {
+                    self.collect_response_enums(
+                        &self.collect_fields(composite_type, Some(object_type), selection_sets),
+                        enums,
+                    );
+                }
+            }
+        }
+    }
+
+    fn collect_enum(
+        etd: &'a ScopedEnumTypeDefinition<'a>,
+        enums: &mut Vec<&'a ScopedEnumTypeDefinition<'a>>,
+    ) {
+        if !enums.iter().any(|e| e.name() == etd.name()) {
+            enums.push(etd);
+        }
+    }
+
+    fn write_input_struct(writer: &mut RubyWriter, name: &str, fields: &[(&str, InputShape)]) {
+        writer.open(format!("class {name} < T::Struct"));
+        writer.line("extend(T::Sig)");
+        writer.blank_line();
+
+        fields.iter().for_each(|(field_name, shape)| {
+            writer.line(format!(
+                "const :{}, {}",
+                ruby_name(field_name),
+                shape.sorbet_type()
+            ));
+        });
+
+        if !fields.is_empty() {
+            writer.blank_line();
+        }
+
+        writer.line("sig { returns(T::Hash[String, T.untyped]) }");
+        writer.open("def to_h");
+        if fields.is_empty() {
+            writer.line("{}");
+        } else {
+            writer.open("{");
+            fields.iter().for_each(|(field_name, shape)| {
+                writer.line(format!(
+                    "{:?} => {},",
+                    field_name,
+                    shape.to_h_expression(&ruby_name(field_name), 1),
+                ));
+            });
+            writer.close_with("}.compact");
+        }
+        writer.close();
+
+        writer.close();
+    }
+
+    fn write_struct(
+        &'a self,
+        writer: &mut RubyWriter,
+        name: &str,
+        fields: &CollectedFields<'a>,
+        path: &str,
+        module_name: &str,
+    ) -> Result<(), Error> {
+        writer.open(format!("class {name} < T::Struct"));
+        writer.line("extend(T::Sig)");
+        writer.blank_line();
+
+        let mut typed_fields = Vec::with_capacity(fields.len());
+        let mut field_class_names: HashMap<String, &str> = HashMap::new();
+
+        for (response_key, collected_field) in fields {
+            let field_class =
+                self.write_field_class(writer, response_key, collected_field, path, module_name)?;
+            if field_class.is_some() {
+                let constant = class_name(response_key);
+                if let Some(other_response_key) =
+                    field_class_names.insert(constant.clone(), response_key)
+                {
+                    return Err(Error::new(
+                        exception::arg_error(),
+                        format!(
+                            "Fields `{other_response_key}` and `{response_key}` of `{path}` generate the same class `{constant}`, alias one of them to generate types for it"
+                        ),
+                    ));
+                }
+            }
+            typed_fields.push((*response_key, collected_field, field_class));
+        }
+
+        for (response_key, collected_field, field_class) in &typed_fields {
+            let output_type = collected_field.field_definition.r#type();
+            let mut sorbet_type = Self::response_sorbet_type(output_type, field_class.as_ref());
+            if collected_field.conditional && output_type.as_ref().is_required() {
+                sorbet_type = format!("T.nilable({sorbet_type})");
+            }
+            writer.line(format!(
+                "const :{}, {}",
+                ruby_name(response_key),
+                sorbet_type
+            ));
+        }
+
+        if !typed_fields.is_empty() {
+            writer.blank_line();
+        }
+
+        writer.line(format!(
+            "sig {{ params(hash: T::Hash[String, T.untyped]).returns({name}) }}"
+        ));
+        writer.open("def self.from_h(hash)");
+        if typed_fields.is_empty() {
+            writer.line("new");
+        } else {
+            writer.open("new(");
+            for (response_key, collected_field, field_class) in &typed_fields {
+                let expression = Self::from_h_expression(
+                    collected_field.field_definition.r#type(),
+                    field_class.as_ref(),
+                    &format!("hash[{response_key:?}]"),
+                    collected_field.conditional,
+                    1,
+                );
+                writer.line(format!("{}: {},", ruby_name(response_key), expression));
+            }
+            writer.close_with(")");
+        }
+        writer.close();
+
+        writer.close();
+
+        Ok(())
+    }
+
+    /// Writes the constant for a field of composite, enum or custom scalar type, which
+    /// `ExecutionEngine` also uses to build the value of the field for a typed response
+    fn write_field_class(
+        &'a self,
+        writer: &mut RubyWriter,
+        response_key: &str,
+        collected_field: &CollectedField<'a>,
+        path: &str,
+        module_name: &str,
+    ) -> Result<Option<FieldClass>, Error> {
+        let output_type = collected_field.field_definition.r#type();
+
+        if let Some(composite_type) = CompositeType::from_output_type(output_type) {
+            let composite_struct = self.write_composite_field(
+                writer,
+                response_key,
+                composite_type,
+                collected_field,
+                &format!("{path}.{response_key}"),
+                module_name,
+            )?;
+            writer.blank_line();
+            return Ok(Some(FieldClass::Composite(composite_struct)));
+        }
+
+        let constant = class_name(response_key);
+        let field_class = match output_type.as_ref().base() {
+            ScopedBaseOutputType::Enum(etd) => {
+                writer.line(format!(
+                    "{constant} = {module_name}::{}",
+                    class_name(etd.name())
+                ));
+                FieldClass::Enum { constant }
+            }
+            ScopedBaseOutputType::CustomScalar(cstd) => {
+                let cstd = cstd.inner();
+                writer.line(format!("{constant} = {}", cstd.ruby_class_name()));
+                FieldClass::CustomScalar {
+                    constant,
+                    sorbet_type: cstd.internal_representation_sorbet_type_name().to_owned(),
+                    returns_result: cstd.coerce_input_returns_result(),
+                }
+            }
+            _ => return Ok(None),
+        };
+        writer.blank_line();
+
+        Ok(Some(field_class))
+    }
+
+    /// Writes the struct, or the module of structs, for a field of composite type
+    fn write_composite_field(
+        &'a self,
+        writer: &mut RubyWriter,
+        response_key: &str,
+        composite_type: CompositeType<'a>,
+        collected_field: &CollectedField<'a>,
+        path: &str,
+        module_name: &str,
+    ) -> Result<CompositeStruct, Error> {
+        let name = class_name(response_key);
+        let selection_sets = collected_field.selection_sets.as_slice();
+
+        if let CompositeType::Object(object_type) = composite_type {
+            let fields = self.collect_fields(composite_type, Some(object_type), selection_sets);
+            self.write_struct(writer, &name, &fields, path, module_name)?;
+            return Ok(CompositeStruct::single(name));
+        }
+
+        let unconditional_fields = self.collect_fields(composite_type, None, selection_sets);
+        let unconditional_signature = Self::signature(&unconditional_fields);
+        let possible_types = self.possible_types(composite_type);
+        let mut variants: Vec<(&ScopedObjectTypeDefinition, CollectedFields)> = possible_types
+            .iter()
+            .filter_map(|object_type| {
+                let fields =
+                    self.collect_fields(composite_type, Some(*object_type), selection_sets);
+                (Self::signature(&fields) != unconditional_signature)
+                    .then_some((*object_type, fields))
+            })
+            .collect();
+
+        if variants.is_empty() {
+            self.write_struct(writer, &name, &unconditional_fields, path, module_name)?;
+            return Ok(CompositeStruct::single(name));
+        }
+
+        // the struct of an object type named `Other` would take the name of the struct for the types
+        // without one, so every type gets its own struct instead
+        if variants.len() < possible_types.len()
+            && variants
+                .iter()
+                .any(|(object_type, _)| class_name(object_type.name()) == OTHER_VARIANT_NAME)
+        {
+            variants = possible_types
+                .iter()
+                .map(|object_type| {
+                    (
+                        *object_type,
+                        self.collect_fields(composite_type, Some(*object_type), selection_sets),
+                    )
+                })
+                .collect();
+        }
+
+        let typename_key = unconditional_fields
+            .iter()
+            .find(|(_, cf)| cf.field_definition.name() == "__typename" && !cf.conditional)
+            .map(|(response_key, _)| *response_key)
+            .ok_or_else(|| {
+                Error::new(
+                    exception::arg_error(),
+                    format!(
+                        "Selection on `{path}` uses type conditions and must select `__typename` to generate types for it"
+                    ),
+                )
+            })?;
+
+        let has_other = variants.len() < possible_types.len();
+        let mut variant_names: Vec<String> = variants
+            .iter()
+            .map(|(object_type, _)| class_name(object_type.name()))
+            .collect();
+        if has_other {
+            variant_names.push(OTHER_VARIANT_NAME.to_owned());
+        }
+
+        writer.open(format!("module {name}"));
+        writer.line("extend(T::Sig)");
+        writer.blank_line();
+
+        for ((object_type, fields), variant_name) in variants.iter().zip(&variant_names) {
+            self.write_struct(
+                writer,
+                variant_name,
+                fields,
+                &format!("{path}<{}>", object_type.name()),
+                module_name,
+            )?;
+            writer.blank_line();
+        }
+        if has_other {
+            self.write_struct(
+                writer,
+                OTHER_VARIANT_NAME,
+                &unconditional_fields,
+                path,
+                module_name,
+            )?;
+            writer.blank_line();
+        }
+
+        writer.line(format!(
+            "sig {{ params(hash: T::Hash[String, T.untyped]).returns(T.any({})) }}",
+            variant_names.join(", "),
+        ));
+        writer.open("def self.from_h(hash)");
+        writer.line(format!("case hash[{typename_key:?}]"));
+        for ((object_type, _), variant_name) in variants.iter().zip(&variant_names) {
+            writer.line(format!(
+                "when {:?} then {variant_name}.from_h(hash)",
+                object_type.name(),
+            ));
+        }
+        if has_other {
+            writer.line(format!("else {OTHER_VARIANT_NAME}.from_h(hash)"));
+        } else {
+            writer.line(format!(
+                "else raise(ArgumentError, \"Unexpected __typename: #{{hash[{typename_key:?}].inspect}}\")"
+            ));
+        }
+        writer.line("end");
+        writer.close();
+
+        writer.close();
+
+        Ok(CompositeStruct {
+            sorbet_type: format!(
+                "T.any({})",
+                variant_names
+                    .iter()
+                    .map(|variant_name| format!("{name}::{variant_name}"))
+                    .join(", ")
+            ),
+            constant: name,
+        })
+    }
+
+    fn response_sorbet_type(
+        output_type: &ScopedOutputType,
+        field_class: Option<&FieldClass>,
+    ) -> String {
+        let sorbet_type = match output_type.as_ref() {
+            OutputTypeReference::Base(base, _) => match field_class {
+                Some(field_class) => field_class.sorbet_type().to_owned(),
+                None => Self::leaf_sorbet_type(base).to_owned(),
+            },
+            OutputTypeReference::List(inner, _) => {
+                format!(
+                    "T::Array[{}]",
+                    Self::response_sorbet_type(inner, field_class)
+                )
+            }
+        };
+        if output_type.as_ref().is_required() {
+            sorbet_type
+        } else {
+            format!("T.nilable({sorbet_type})")
+        }
+    }
+
+    fn leaf_sorbet_type(base: &ScopedBaseOutputType) -> &'static str {
+        match base {
+            ScopedBaseOutputType::BuiltinScalar(bstd) => match bstd {
+                BuiltinScalarDefinition::Boolean => "T::Boolean",
+                BuiltinScalarDefinition::Float => "Float",
+                BuiltinScalarDefinition::ID => "String",
+                BuiltinScalarDefinition::Int => "Integer",
+                BuiltinScalarDefinition::String => "String",
+            },
+            _ => "T.untyped",
+        }
+    }
+
+    /// Ruby expression building the value of a response struct property from `source`
+    fn from_h_expression(
+        output_type: &ScopedOutputType,
+        field_class: Option<&FieldClass>,
+        source: &str,
+        conditional: bool,
+        depth: usize,
+    ) -> String {
+        let nullable = conditional || !output_type.as_ref().is_required();
+        let item = format!("v{depth}");
+        match output_type.as_ref() {
+            OutputTypeReference::Base(_, _) => match field_class {
+                Some(field_class) if nullable => format!(
+                    "{source}&.then {{ |{item}| {} }}",
+                    field_class.from_json_expression(&item),
+                ),
+                Some(field_class) => field_class.from_json_expression(source),
+                None => source.to_owned(),
+            },
+            OutputTypeReference::List(inner, _) => {
+                let item_expression =
+                    Self::from_h_expression(inner, field_class, &item, false, depth + 1);
+                if item_expression == item {
+                    source.to_owned()
+                } else if nullable {
+                    format!("{source}&.map {{ |{item}| {item_expression} }}")
+                } else {
+                    format!("{source}.map {{ |{item}| {item_expression} }}")
+                }
+            }
+        }
+    }
+
+    fn collect_fields(
+        &'a self,
+        parent_type: CompositeType<'a>,
+        object_type: Option<&'a ScopedObjectTypeDefinition<'a>>,
+        selection_sets: &[&'a SelectionSet<'a>],
+    ) -> CollectedFields<'a> {
+        let mut fields = IndexMap::new();
+        selection_sets.iter().for_each(|selection_set| {
+            self.collect_fields_into(
+                &mut fields,
+                parent_type,
+                object_type,
+                selection_set,
+                false,
+                &mut HashSet::new(),
+            )
+        });
+        fields
+    }
+
+    /// Like the `CollectFields` algorithm of execution, but statically: with an `object_type`
+    /// fragments apply as they would at runtime for that type, without one only fragments
+    /// guaranteed to apply to every possible type of `parent_type` are included
+    fn collect_fields_into(
+        &'a self,
+        fields: &mut CollectedFields<'a>,
+        parent_type: CompositeType<'a>,
+        object_type: Option<&'a ScopedObjectTypeDefinition<'a>>,
+        selection_set: &'a SelectionSet<'a>,
+        conditional: bool,
+        visited_fragments: &mut HashSet<&'a str>,
+    ) {
+        for selection in selection_set.iter() {
+            let conditional = conditional
+                || selection
+                    .as_ref()
+                    .directives()
+                    .iter()
+                    .any(|directive| matches!(directive.name().as_ref(), "skip" | "include"));
+
+            match selection {
+                Selection::Field(field) => {
+                    let index = self.schema_definition.inner().index();
+                    let field_definition = match object_type {
+                        Some(object_type) => CompositeType::Object(object_type)
+                            .field_definition(field.name().as_ref(), index),
+                        None => parent_type.field_definition(field.name().as_ref(), index),
+                    };
+                    let field_definition = match field_definition {
+                        Some(field_definition) => field_definition,
+                        None => continue,
+                    };
+                    let collected_field =
+                        fields
+                            .entry(field.response_key())
+                            .or_insert_with(|| CollectedField {
+                                field_definition,
+                                selection_sets: Vec::new(),
+                                conditional,
+                            });
+                    collected_field.conditional &= conditional;
+                    collected_field.selection_sets.extend(field.selection_set());
+                }
+                Selection::InlineFragment(inline_fragment) => {
+                    let applies = inline_fragment
+                        .type_condition()
+                        .map_or(true, |type_condition| {
+                            self.does_fragment_type_apply(
+                                parent_type,
+                                object_type,
+                                type_condition.named_type().as_ref(),
+                            )
+                        });
+                    if applies {
+                        self.collect_fields_into(
+                            fields,
+                            parent_type,
+                            object_type,
+                            inline_fragment.selection_set(),
+                            conditional,
+                            visited_fragments,
+                        );
+                    }
+                }
+                Selection::FragmentSpread(fragment_spread) => {
+                    let fragment_name = fragment_spread.name().as_ref();
+                    if !visited_fragments.insert(fragment_name) {
+                        continue;
+                    }
+                    let fragment_definition = self
+                        .document
+                        .fragment_definitions()
+                        .iter()
+                        .find(|fd| fd.name().as_ref() == fragment_name);
+                    if let Some(fragment_definition) = fragment_definition {
+                        if self.does_fragment_type_apply(
+                            parent_type,
+                            object_type,
+                            fragment_definition.type_condition().named_type().as_ref(),
+                        ) {
+                            self.collect_fields_into(
+                                fields,
+                                parent_type,
+                                object_type,
+                                fragment_definition.selection_set(),
+                                conditional,
+                                visited_fragments,
+                            );
+                        }
+                    }
+                    visited_fragments.remove(fragment_name);
+                }
+            }
+        }
+    }
+
+    fn does_fragment_type_apply(
+        &'a self,
+        parent_type: CompositeType<'a>,
+        object_type: Option<&'a ScopedObjectTypeDefinition<'a>>,
+        fragment_type_name: &str,
+    ) -> bool {
+        let object_type = match object_type {
+            Some(object_type) => object_type,
+            None => return fragment_type_name == parent_type.name(),
+        };
+        match self
+            .schema_definition
+            .get_type_definition(fragment_type_name)
+        {
+            Some(TypeDefinitionReference::Object(otd)) => otd.name() == object_type.name(),
+            Some(TypeDefinitionReference::Interface(itd)) => {
+                ObjectTypeDefinition::implements_interface(object_type, itd)
+            }
+            Some(TypeDefinitionReference::Union(utd)) => {
+                UnionTypeDefinition::contains_type(utd, object_type)
+            }
+            _ => false,
+        }
+    }
+
+    fn possible_types(
+        &'a self,
+        composite_type: CompositeType<'a>,
+    ) -> Vec<&'a ScopedObjectTypeDefinition<'a>> {
+        match composite_type {
+            CompositeType::Object(otd) => vec![otd],
+            CompositeType::Interface(itd) => self
+                .schema_definition
+                .get_interface_implementors(itd)
+                .collect(),
+            CompositeType::Union(utd) => utd
+                .union_member_types()
+                .iter()
+                .map(|union_member_type| union_member_type.member_type())
+                .collect(),
+        }
+    }
+
+    /// Identifies the shape of collected fields, two types with the same signature can share a struct
+    fn signature(
+        fields: &CollectedFields<'a>,
+    ) -> Vec<(&'a str, bool, Vec<*const SelectionSet<'a>>)> {
+        fields
+            .iter()
+            .map(|(response_key, collected_field)| {
+                (
+                    *response_key,
+                    collected_field.conditional,
+                    collected_field
+                        .selection_sets
+                        .iter()
+                        .map(|selection_set| *selection_set as *const _)
+                        .collect(),
+                )
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,39 @@
+/// Accumulates Ruby source, tracking the indentation of nested `class`/`module` bodies
+#[derive(Default)]
+pub struct RubyWriter {
+    buf: String,
+    depth: usize,
+}
+
+impl RubyWriter {
+    pub fn line(&mut self, line: impl AsRef<str>) {
+        let line = line.as_ref();
+        if !line.is_empty() {
+            (0..self.depth).for_each(|_| self.buf.push_str("  "));
+            self.buf.push_str(line);
+        }
+        self.buf.push('\n');
+    }
+
+    pub fn blank_line(&mut self) {
+        self.line("");
+    }
+
+    pub fn open(&mut self, line: impl AsRef<str>) {
+        self.line(line);
+        self.depth += 1;
+    }
+
+    pub fn close(&mut self) {
+        self.close_with("end");
+    }
+
+    pub fn close_with(&mut self, line: impl AsRef<str>) {
+        self.depth -= 1;
+        self.line(line);
+    }
+
+    pub fn into_string(self) -> String {
+        self.buf
+    }
+}
@@ -0,0 +1,577 @@
+use crate::codegen::{class_name, ruby_name, ruby_string_literal, RubyWriter};
+use crate::helpers::value_from_core_value;
+use bluejay_core::definition::{
+    prelude::*, BaseInputTypeReference, BaseOutputTypeReference, EnumTypeDefinition,
+    FieldDefinition, InputObjectTypeDefinition, InputType as CoreInputType, InputTypeReference,
+    InputValueDefinition, InterfaceTypeDefinition, ObjectTypeDefinition,
+    OutputType as CoreOutputType, OutputTypeReference, ScalarTypeDefinition,
+    SchemaDefinition as CoreSchemaDefinition, TypeDefinitionReference, UnionTypeDefinition,
+};
+use bluejay_core::{Argument as CoreArgument, AsIter, Directive as CoreDirective, ValueReference};
+use bluejay_parser::ast::definition::{DefinitionDocument, SchemaDefinition};
+use itertools::Itertools;
+use magnus::{exception, Error, RHash};
+use std::collections::HashMap;
+
+const SCHEMA_CLASS_NAME: &str = "Schema";
+
+/// Generates the Ruby classes defining the types of a schema written in SDL,
+/// one file per type plus one for the schema itself
+pub struct SchemaClassesGenerator<'a> {
+    namespace: Vec<&'a str>,
+}
+
+impl<'a> SchemaClassesGenerator<'a> {
+    pub fn generate(sdl: &str, namespace: Option<&'a str>) -> Result<RHash, Error> {
+        let document = DefinitionDocument::parse(sdl).map_err(|errors| {
+            Error::new(
+                exception::arg_error(),
+                format!(
+                    "Document could not be parsed: {}",
+                    errors.iter().map(|error| error.message()).join(", "),
+                ),
+            )
+        })?;
+        let schema_definition = SchemaDefinition::try_from(&document).map_err(|errors| {
+            Error::new(
+                exception::arg_error(),
+                format!(
+                    "Document is invalid: {}",
+                    errors.iter().map(|error| error.message()).join(", "),
+                ),
+            )
+        })?;
+
+        let instance = SchemaClassesGenerator {
+            namespace: namespace
+                .map(|namespace| namespace.split("::").collect())
+                .unwrap_or_default(),
+        };
+
+        let files = RHash::new();
+        // the type that generated each file, so types whose names map to the same file are reported
+        // instead of one overwriting the other
+        let mut file_types = HashMap::from([(file_name(SCHEMA_CLASS_NAME), SCHEMA_CLASS_NAME)]);
+        schema_definition
+            .type_definitions()
+            .filter(|td| !td.name().starts_with("__"))
+            .sorted_by_key(|td| td.name().to_owned())
+            .try_for_each(|td| {
+                if constant_name(td.name()) == SCHEMA_CLASS_NAME {
+                    return Err(Error::new(
+                        exception::arg_error(),
+                        format!(
+                            "Type `{}` generates the class `{SCHEMA_CLASS_NAME}`, which is reserved for the schema",
+                            td.name()
+                        ),
+                    ));
+                }
+                let source = match td {
+                    TypeDefinitionReference::BuiltinScalar(_) => return Ok(()),
+                    TypeDefinitionReference::CustomScalar(cstd) => {
+                        instance.file(|w| Self::write_custom_scalar_type(w, cstd))
+                    }
+                    TypeDefinitionReference::Enum(etd) if !etd.is_builtin() => {
+                        instance.file(|w| Self::write_enum_type(w, etd))
+                    }
+                    TypeDefinitionReference::Enum(_) => return Ok(()),
+                    TypeDefinitionReference::InputObject(iotd) => {
+                        instance.file(|w| Self::write_input_object_type(w, iotd))
+                    }
+                    TypeDefinitionReference::Interface(itd) => {
+                        instance.file(|w| Self::write_interface_type(w, itd))
+                    }
+                    TypeDefinitionReference::Object(otd) if !otd.is_builtin() => {
+                        let is_query_root = otd.name() == schema_definition.query().name();
+                        instance.file(|w| Self::write_object_type(w, otd, is_query_root))
+                    }
+                    TypeDefinitionReference::Object(_) => return Ok(()),
+                    TypeDefinitionReference::Union(utd) => {
+                        instance.file(|w| Self::write_union_type(w, utd))
+                    }
+                };
+                let file_name = file_name(td.name());
+                match file_types.insert(file_name.clone(), td.name()) {
+                    Some(SCHEMA_CLASS_NAME) => Err(Error::new(
+                        exception::arg_error(),
+                        format!(
+                            "Type `{}` generates the file `{file_name}`, which is reserved for the schema",
+                            td.name()
+                        ),
+                    )),
+                    Some(other_type_name) => Err(Error::new(
+                        exception::arg_error(),
+                        format!(
+                            "Types `{other_type_name}` and `{}` both generate the file `{file_name}`",
+                            td.name()
+                        ),
+                    )),
+                    None => files.aset(file_name, source),
+                }
+            })?;
+
+        let schema_source = instance.file(|w| {
+            Self::write_schema(
+                w,
+                schema_definition.description(),
+                schema_definition.query().name(),
+                schema_definition.mutation().map(|mutation| mutation.name()),
+            )
+        });
+        files.aset(file_name(SCHEMA_CLASS_NAME), schema_source)?;
+
+        Ok(files)
+    }
+
+    fn file(&self, write_body: impl FnOnce(&mut RubyWriter)) -> String {
+        let mut writer = RubyWriter::default();
+        writer.line("# typed: strict");
+        writer.line("# frozen_string_literal: true");
+        writer.blank_line();
+        self.namespace
+            .iter()
+            .for_each(|module| writer.open(format!("module {module}")));
+        write_body(&mut writer);
+        self.namespace.iter().for_each(|_| writer.close());
+        writer.into_string()
+    }
+
+    fn write_schema(
+        writer: &mut RubyWriter,
+        description: Option<&str>,
+        query: &str,
+        mutation: Option<&str>,
+    ) {
+        writer.open(format!("class {SCHEMA_CLASS_NAME} < Bluejay::Schema"));
+        Self::open_class_methods(writer);
+        Self::write_description(writer, description);
+        writer.blank_line();
+        Self::write_method(
+            writer,
+            "override.returns(T.class_of(Bluejay::QueryRoot))",
+            "query",
+            &[constant_name(query)],
+        );
+        if let Some(mutation) = mutation {
+            writer.blank_line();
+            Self::write_method(
+                writer,
+                "override.returns(T.class_of(Bluejay::ObjectType))",
+                "mutation",
+                &[constant_name(mutation)],
+            );
+        }
+        Self::close_class_methods(writer);
+    }
+
+    fn write_object_type<T: ObjectTypeDefinition>(
+        writer: &mut RubyWriter,
+        otd: &T,
+        is_query_root: bool,
+    ) {
+        let superclass = if is_query_root {
+            "Bluejay::QueryRoot"
+        } else {
+            "Bluejay::ObjectType"
+        };
+        writer.open(format!(
+            "class {} < {superclass}",
+            constant_name(otd.name())
+        ));
+        Self::open_class_methods(writer);
+        Self::write_graphql_name(writer, otd.name());
+        Self::write_description(writer, otd.description());
+        Self::write_field_definitions(writer, otd.fields_definition().iter());
+        Self::write_interface_implementations(
+            writer,
+            otd.interface_implementations()
+                .into_iter()
+                .flat_map(|iis| iis.iter().map(|ii| ii.interface().name())),
+        );
+        Self::close_class_methods(writer);
+    }
+
+    fn write_interface_type<T: InterfaceTypeDefinition>(writer: &mut RubyWriter, itd: &T) {
+        writer.open(format!(
+            "class {} < Bluejay::InterfaceType",
+            constant_name(itd.name())
+        ));
+        Self::open_class_methods(writer);
+        Self::write_graphql_name(writer, itd.name());
+        Self::write_description(writer, itd.description());
+        Self::write_field_definitions(writer, itd.fields_definition().iter());
+        Self::write_interface_implementations(
+            writer,
+            itd.interface_implementations()
+                .into_iter()
+                .flat_map(|iis| iis.iter().map(|ii| ii.interface().name())),
+        );
+        Self::close_class_methods(writer);
+    }
+
+    fn write_union_type<T: UnionTypeDefinition>(writer: &mut RubyWriter, utd: &T) {
+        writer.open(format!(
+            "class {} < Bluejay::UnionType",
+            constant_name(utd.name())
+        ));
+        Self::open_class_methods(writer);
+        Self::write_graphql_name(writer, utd.name());
+        Self::write_description(writer, utd.description());
+        writer.blank_line();
+        Self::write_method(
+            writer,
+            "override.returns(T::Array[Bluejay::UnionMemberType])",
+            "member_types",
+            &array_lines(utd.union_member_types().iter().map(|member_type| {
+                format!(
+                    "Bluejay::UnionMemberType.new(type: {}),",
+                    constant_name(member_type.member_type().name())
+                )
+            })),
+        );
+        Self::close_class_methods(writer);
+    }
+
+    fn write_enum_type<T: EnumTypeDefinition>(writer: &mut RubyWriter, etd: &T) {
+        writer.open(format!(
+            "class {} < Bluejay::EnumType",
+            constant_name(etd.name())
+        ));
+        Self::open_class_methods(writer);
+        Self::write_graphql_name(writer, etd.name());
+        Self::write_description(writer, etd.description());
+        writer.blank_line();
+        Self::write_method(
+            writer,
+            "override.returns(T::Array[Bluejay::EnumValueDefinition])",
+            "enum_value_definitions",
+            &array_lines(etd.enum_value_definitions().iter().map(|evd| {
+                let mut kwargs = vec![format!("name: {}", ruby_string_literal(evd.name()))];
+                if let Some(description) = evd.description() {
+                    kwargs.push(format!("description: {}", ruby_string_literal(description)));
+                }
+                if let Some(reason) =
+                    deprecation_reason(evd.directives().into_iter().flat_map(AsIter::iter))
+                {
+                    kwargs.push(format!(
+                        "deprecation_reason: {}",
+                        ruby_string_literal(&reason)
+                    ));
+                }
+                format!("Bluejay::EnumValueDefinition.new({}),", kwargs.join(", "))
+            })),
+        );
+        Self::close_class_methods(writer);
+    }
+
+    fn write_input_object_type<T: InputObjectTypeDefinition>(writer: &mut RubyWriter, iotd: &T) {
+        writer.open(format!(
+            "class {} < Bluejay::InputObjectType",
+            constant_name(iotd.name())
+        ));
+        Self::open_class_methods(writer);
+        Self::write_graphql_name(writer, iotd.name());
+        Self::write_description(writer, iotd.description());
+        writer.blank_line();
+        Self::write_method(
+            writer,
+            "override.returns(T::Array[Bluejay::InputValueDefinition])",
+            "input_field_definitions",
+            &array_lines(
+                iotd.input_field_definitions()
+                    .iter()
+                    .map(|ivd| format!("{},", input_value_definition(ivd))),
+            ),
+        );
+        Self::close_class_methods(writer);
+    }
+
+    fn write_custom_scalar_type<T: ScalarTypeDefinition>(writer: &mut RubyWriter, cstd: &T) {
+        writer.open(format!(
+            "class {} < Bluejay::CustomScalarType",
+            constant_name(cstd.name())
+        ));
+        writer.line("extend(T::Generic)");
+        writer.blank_line();
+        // values are passed through as they are received until `coerce_input` is overridden
+        writer.line("InternalRepresentation = type_template { { fixed: T.untyped } }");
+        writer.blank_line();
+        writer.open("class << self");
+        writer.line("extend(T::Sig)");
+        Self::write_graphql_name(writer, cstd.name());
+        Self::write_description(writer, cstd.description());
+        let specified_by_url = string_argument(
+            cstd.directives().into_iter().flat_map(AsIter::iter),
+            "specifiedBy",
+            "url",
+        );
+        if let Some(url) = specified_by_url.flatten() {
+            writer.blank_line();
+            Self::write_method(
+                writer,
+                "override.returns(T.nilable(String))",
+                "specified_by_url",
+                &[ruby_string_literal(&url)],
+            );
+        }
+        writer.blank_line();
+        writer.line(
+            "sig { override.overridable.params(value: T.untyped).returns(Bluejay::Result[InternalRepresentation, String]) }",
+        );
+        writer.open("def coerce_input(value)");
+        writer.line("Bluejay::Result.ok(value)");
+        writer.close();
+        Self::close_class_methods(writer);
+    }
+
+    fn open_class_methods(writer: &mut RubyWriter) {
+        writer.open("class << self");
+        writer.line("extend(T::Sig)");
+    }
+
+    fn close_class_methods(writer: &mut RubyWriter) {
+        writer.close();
+        writer.close();
+    }
+
+    /// Only needed when the class name can't be used as the GraphQL name
+    fn write_graphql_name(writer: &mut RubyWriter, name: &str) {
+        if constant_name(name) != name {
+            writer.blank_line();
+            Self::write_method(
+                writer,
+                "override.returns(String)",
+                "graphql_name",
+                &[ruby_string_literal(name)],
+            );
+        }
+    }
+
+    fn write_description(writer: &mut RubyWriter, description: Option<&str>) {
+        if let Some(description) = description {
+            writer.blank_line();
+            Self::write_method(
+                writer,
+                "override.returns(T.nilable(String))",
+                "description",
+                &[ruby_string_literal(description)],
+            );
+        }
+    }
+
+    fn write_field_definitions<'b, T: FieldDefinition + 'b>(
+        writer: &mut RubyWriter,
+        field_definitions: impl Iterator<Item = &'b T>,
+    ) {
+        let lines = array_lines(
+            field_definitions
+                .filter(|fd| !fd.is_builtin() && !fd.name().starts_with("__"))
+                .flat_map(field_definition_lines),
+        );
+        writer.blank_line();
+        Self::write_method(
+            writer,
+            "override.returns(T::Array[Bluejay::FieldDefinition])",
+            "field_definitions",
+            &lines,
+        );
+    }
+
+    fn write_interface_implementations<'b>(
+        writer: &mut RubyWriter,
+        interface_names: impl Iterator<Item = &'b str>,
+    ) {
+        let lines: Vec<String> = interface_names
+            .map(|name| {
+                format!(
+                    "Bluejay::InterfaceImplementation.new(interface: {}),",
+                    constant_name(name)
+                )
+            })
+            .collect();
+        if !lines.is_empty() {
+            writer.blank_line();
+            Self::write_method(
+                writer,
+                "override.returns(T::Array[Bluejay::InterfaceImplementation])",
+                "interface_implementations",
+                &array_lines(lines.into_iter()),
+            );
+        }
+    }
+
+    fn write_method(writer: &mut RubyWriter, sig: &str, name: &str, body: &[String]) {
+        writer.line(format!("sig {{ {sig} }}"));
+        writer.open(format!("def {name}"));
+        body.iter().for_each(|line| writer.line(line));
+        writer.close();
+    }
+}
+
+/// The lines of `FieldDefinition.new(...)`, on one line unless the field has arguments
+fn field_definition_lines<T: FieldDefinition>(fd: &T) -> Vec<String> {
+    let mut kwargs = vec![
+        format!("name: {}", ruby_string_literal(fd.name())),
+        format!("type: {}", output_type(fd.r#type())),
+    ];
+    if let Some(description) = fd.description() {
+        kwargs.push(format!("description: {}", ruby_string_literal(description)));
+    }
+    if let Some(reason) = deprecation_reason(fd.directives().into_iter().flat_map(AsIter::iter)) {
+        kwargs.push(format!(
+            "deprecation_reason: {}",
+            ruby_string_literal(&reason)
+        ));
+    }
+    let argument_definitions: Vec<String> = fd
+        .arguments_definition()
+        .into_iter()
+        .flat_map(|ad| {
+            ad.iter()
+                .map(|ivd| format!("  {},", input_value_definition(ivd)))
+        })
+        .collect();
+
+    if argument_definitions.is_empty() {
+        return vec![format!(
+            "Bluejay::FieldDefinition.new({}),",
+            kwargs.join(", ")
+        )];
+    }
+
+    let mut lines = vec!["Bluejay::FieldDefinition.new(".to_owned()];
+    lines.extend(kwargs.into_iter().map(|kwarg| format!("  {kwarg},")));
+    lines.push("  argument_definitions: [".to_owned());
+    lines.extend(
+        argument_definitions
+            .into_iter()
+            .map(|line| format!("  {line}")),
+    );
+    lines.push("  ],".to_owned());
+    lines.push("),".to_owned());
+    lines
+}
+
+fn input_value_definition<T: InputValueDefinition>(ivd: &T) -> String {
+    let mut kwargs = vec![
+        format!("name: {}", ruby_string_literal(ivd.name())),
+        format!("type: {}", input_type(ivd.r#type())),
+    ];
+    if let Some(description) = ivd.description() {
+        kwargs.push(format!("description: {}", ruby_string_literal(description)));
+    }
+    if let Some(default_value) = ivd.default_value() {
+        kwargs.push(format!(
+            "default_value: {}",
+            value_from_core_value(default_value, &()).inspect()
+        ));
+    }
+    if let Some(reason) = deprecation_reason(ivd.directives().into_iter().flat_map(AsIter::iter)) {
+        kwargs.push(format!(
+            "deprecation_reason: {}",
+            ruby_string_literal(&reason)
+        ));
+    }
+    format!("Bluejay::InputValueDefinition.new({})", kwargs.join(", "))
+}
+
+/// The `ot`/`lot` shorthand expression for an output type
+fn output_type<T: CoreOutputType>(t: &T) -> String {
+    match t.as_ref() {
+        OutputTypeReference::Base(base, required) => {
+            let constant = match base.as_ref() {
+                BaseOutputTypeReference::BuiltinScalar(bstd) => {
+                    format!("Bluejay::Scalar::{}", bstd.name())
+                }
+                BaseOutputTypeReference::CustomScalar(cstd) => constant_name(cstd.name()),
+                BaseOutputTypeReference::Enum(etd) => constant_name(etd.name()),
+                BaseOutputTypeReference::Object(otd) => constant_name(otd.name()),
+                BaseOutputTypeReference::Interface(itd) => constant_name(itd.name()),
+                BaseOutputTypeReference::Union(utd) => constant_name(utd.name()),
+            };
+            format!("ot{}({constant})", if required { "!" } else { "" })
+        }
+        OutputTypeReference::List(inner, required) => {
+            format!(
+                "lot{}({})",
+                if required { "!" } else { "" },
+                output_type(inner)
+            )
+        }
+    }
+}
+
+/// The `it`/`lit` shorthand expression for an input type
+fn input_type<T: CoreInputType>(t: &T) -> String {
+    match t.as_ref() {
+        InputTypeReference::Base(base, required) => {
+            let constant = match base.as_ref() {
+                BaseInputTypeReference::BuiltinScalar(bstd) => {
+                    format!("Bluejay::Scalar::{}", bstd.name())
+                }
+                BaseInputTypeReference::CustomScalar(cstd) => constant_name(cstd.name()),
+                BaseInputTypeReference::Enum(etd) => constant_name(etd.name()),
+                BaseInputTypeReference::InputObject(iotd) => constant_name(iotd.name()),
+            };
+            format!("it{}({constant})", if required { "!" } else { "" })
+        }
+        InputTypeReference::List(inner, required) => {
+            format!(
+                "lit{}({})",
+                if required { "!" } else { "" },
+                input_type(inner)
+            )
+        }
+    }
+}
+
+/// The reason of a `@deprecated` directive, which defaults to the one from the spec
+fn deprecation_reason<'b, D: CoreDirective<true> + 'b>(
+    directives: impl Iterator<Item = &'b D>,
+) -> Option<String> {
+    string_argument(directives, "deprecated", "reason")
+        .map(|reason| reason.unwrap_or_else(|| "No longer supported".to_owned()))
+}
+
+/// The value of a string argument of the first directive named `directive_name`, `None` when
+/// there is no such directive
+fn string_argument<'b, D: CoreDirective<true> + 'b>(
+    mut directives: impl Iterator<Item = &'b D>,
+    directive_name: &str,
+    argument_name: &str,
+) -> Option<Option<String>> {
+    directives
+        .find(|directive| directive.name() == directive_name)
+        .map(|directive| {
+            directive.arguments().and_then(|arguments| {
+                arguments
+                    .iter()
+                    .find(|argument| argument.name() == argument_name)
+                    .and_then(|argument| match argument.value().as_ref() {
+                        ValueReference::String(value) => Some(value.to_owned()),
+                        _ => None,
+                    })
+            })
+        })
+}
+
+/// Wraps the lines of array elements in brackets
+fn array_lines(elements: impl Iterator<Item = String>) -> Vec<String> {
+    let mut lines = vec!["[".to_owned()];
+    lines.extend(elements.map(|element| format!("  {element}")));
+    lines.push("]".to_owned());
+    lines
+}
+
+/// The constant defined for a GraphQL type, its name when it is a valid constant name
+fn constant_name(name: &str) -> String {
+    if name.starts_with(|c: char| c.is_ascii_uppercase()) {
+        name.to_owned()
+    } else {
+        class_name(name)
+    }
+}
+
+fn file_name(name: &str) -> String {
+    format!("{}.rb", ruby_name(&constant_name(name)))
+}
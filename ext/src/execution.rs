@@ -3,6 +3,7 @@ mod engine;
 mod execution_error;
 mod field_error;
 mod key_store;
+mod result_coercion_options;
 mod selection_set_provider;
 mod variable_definition_input_type_cache;
 
@@ -11,5 +12,6 @@ pub use engine::Engine;
 use execution_error::ExecutionError;
 pub use field_error::FieldError;
 use key_store::KeyStore;
+pub use result_coercion_options::ResultCoercionOptions;
 use selection_set_provider::SelectionSetProvider;
 use variable_definition_input_type_cache::VariableDefinitionInputTypeCache;
@@ -1,13 +1,29 @@
-use crate::execution::FieldError;
+use crate::execution::{FieldError, ResultCoercionOptions};
 use bluejay_core::BuiltinScalarDefinition;
-use magnus::{Integer, TryConvert, Value};
+use magnus::{Float, Integer, TryConvert, Value};
+
+/// The largest integer such that it and every smaller integer can be represented exactly as a
+/// double, i.e. JavaScript's `Number.MAX_SAFE_INTEGER`
+const MAX_SAFE_INTEGER: u64 = (1 << 53) - 1;
 
 pub trait CoerceResult {
-    fn coerce_result(&self, value: Value) -> Result<Value, FieldError>;
+    fn coerce_result(
+        &self,
+        value: Value,
+        options: &ResultCoercionOptions,
+    ) -> Result<Value, FieldError>;
 }
 
 impl CoerceResult for BuiltinScalarDefinition {
-    fn coerce_result(&self, value: Value) -> Result<Value, FieldError> {
+    fn coerce_result(
+        &self,
+        value: Value,
+        options: &ResultCoercionOptions,
+    ) -> Result<Value, FieldError> {
+        let coercion_error = || FieldError::CannotCoerceResultToBuiltinScalar {
+            builtin_scalar: *self,
+            value: options.strict.then(|| value.inspect()),
+        };
         match self {
             Self::Boolean => {
                 if value.is_kind_of(magnus::class::true_class())
@@ -15,47 +31,55 @@ impl CoerceResult for BuiltinScalarDefinition {
                 {
                     Ok(value)
                 } else {
-                    Err(FieldError::CannotCoerceResultToBuiltinScalar {
-                        builtin_scalar: *self,
-                    })
+                    Err(coercion_error())
                 }
             }
             Self::Float => {
                 if matches!(f64::try_convert(value), Ok(f) if f.is_finite()) {
                     Ok(value)
                 } else {
-                    Err(FieldError::CannotCoerceResultToBuiltinScalar {
-                        builtin_scalar: *self,
-                    })
+                    Err(coercion_error())
                 }
             }
             Self::ID => {
-                if value.is_kind_of(magnus::class::string())
-                    || matches!(Integer::from_value(value), Some(i) if i.to_i32().is_ok())
-                {
+                if value.is_kind_of(magnus::class::string()) {
                     Ok(value)
                 } else {
-                    Err(FieldError::CannotCoerceResultToBuiltinScalar {
-                        builtin_scalar: *self,
-                    })
+                    match Integer::from_value(value) {
+                        Some(i) if options.wide_integer_ids => match i.to_i64() {
+                            Ok(i) if i.unsigned_abs() <= MAX_SAFE_INTEGER => Ok(value),
+                            // serialized as strings, since clients cannot be assumed to read
+                            // integers that a double cannot represent exactly
+                            _ => value
+                                .to_r_string()
+                                .map(|s| *s)
+                                .map_err(|_| coercion_error()),
+                        },
+                        Some(i) if i.to_i32().is_ok() => Ok(value),
+                        _ => Err(coercion_error()),
+                    }
                 }
             }
             Self::Int => {
                 if matches!(Integer::from_value(value), Some(i) if i.to_i32().is_ok()) {
-                    Ok(value)
-                } else {
-                    Err(FieldError::CannotCoerceResultToBuiltinScalar {
-                        builtin_scalar: *self,
-                    })
+                    return Ok(value);
+                }
+                match Float::from_value(value).map(|f| f.to_f64()) {
+                    Some(f)
+                        if options.coerce_integral_floats_to_int
+                            && f.fract() == 0.0
+                            && (f64::from(i32::MIN)..=f64::from(i32::MAX)).contains(&f) =>
+                    {
+                        Ok(*Integer::from_i64(f as i64))
+                    }
+                    _ => Err(coercion_error()),
                 }
             }
             Self::String => {
                 if value.is_kind_of(magnus::class::string()) {
                     Ok(value)
                 } else {
-                    Err(FieldError::CannotCoerceResultToBuiltinScalar {
-                        builtin_scalar: *self,
-                    })
+                    Err(coercion_error())
                 }
             }
         }
@@ -1,16 +1,21 @@
+use crate::codegen::class_name;
 use crate::execution::{
-    CoerceResult, ExecutionError, FieldError, KeyStore, SelectionSetProvider,
-    VariableDefinitionInputTypeCache,
+    CoerceResult, ExecutionError, FieldError, KeyStore, ResultCoercionOptions,
+    SelectionSetProvider, VariableDefinitionInputTypeCache,
+};
+use crate::helpers::{
+    line_and_column, rhash_with_capacity, FuncallKw, NewInstanceKw, RArrayIter, Warden,
 };
-use crate::helpers::{rhash_with_capacity, FuncallKw, NewInstanceKw, RArrayIter, Warden};
 use crate::ruby_api::{
-    CoerceInput, ExecutionResult, ExtraResolverArg, ObjectTypeDefinition, SchemaDefinition,
-    UnionTypeDefinition,
+    CoerceInput, CoercionError, ExecutionError as RubyExecutionError, ExecutionResult,
+    ExtraResolverArg, Lookahead, ObjectTypeDefinition, SchemaDefinition, UnionTypeDefinition,
 };
+use crate::schema_index::SchemaIndex;
 use crate::visibility_scoped::{
-    ScopedBaseOutputType, ScopedFieldDefinition, ScopedInputType, ScopedInputValueDefinition,
-    ScopedInterfaceTypeDefinition, ScopedObjectTypeDefinition, ScopedOutputType,
-    ScopedSchemaDefinition, ScopedUnionTypeDefinition, VisibilityCache,
+    indexed_field_definition, ScopedBaseOutputType, ScopedCompositeType, ScopedFieldDefinition,
+    ScopedInputType, ScopedInputValueDefinition, ScopedInterfaceTypeDefinition,
+    ScopedObjectTypeDefinition, ScopedOutputType, ScopedSchemaDefinition,
+    ScopedUnionTypeDefinition, VisibilityCache,
 };
 use bluejay_core::definition::{OutputType as CoreOutputType, OutputTypeReference};
 use bluejay_core::executable::{
@@ -20,11 +25,16 @@ use bluejay_core::{
     definition::{prelude::*, SchemaDefinition as CoreSchemaDefinition, TypeDefinitionReference},
     AsIter, Directive as CoreDirective, OperationType,
 };
-use bluejay_parser::ast::executable::{ExecutableDocument, Field, OperationDefinition, Selection};
+use bluejay_parser::ast::executable::{
+    ExecutableDocument, Field, OperationDefinition, Selection, SelectionSet,
+};
 use bluejay_parser::ast::{Directive, VariableArguments, VariableValue};
 use bluejay_validator::Path;
 use indexmap::IndexMap;
-use magnus::{Error, RArray, RHash, Value, QNIL};
+use itertools::Itertools;
+use magnus::{
+    exception, typed_data::Obj, Error, Module, RArray, RClass, RHash, RModule, RString, Value, QNIL,
+};
 use std::cell::RefCell;
 use std::collections::{HashMap, HashSet};
 use std::rc::Rc;
@@ -32,12 +42,25 @@ use std::rc::Rc;
 type CollectFieldsCache<'a> =
     RefCell<HashMap<SelectionSetProvider<'a>, Rc<IndexMap<&'a str, Rc<Vec<&'a Field<'a>>>>>>>;
 
+type LookaheadFields<'a> = IndexMap<&'a str, (&'a ScopedFieldDefinition<'a>, Vec<&'a Field<'a>>)>;
+
+type LookaheadCache<'a> = RefCell<HashMap<Vec<*const Field<'a>>, Obj<Lookahead>>>;
+
 pub struct Engine<'a> {
     schema_definition: ScopedSchemaDefinition<'a>,
+    query: &'a str,
     document: &'a ExecutableDocument<'a>,
     variables: &'a RHash,
     key_store: KeyStore<'a>,
     collect_fields_cache: CollectFieldsCache<'a>,
+    /// The lookaheads built for each set of merged fields, which are the same for every object
+    /// the fields are executed on, as the arguments only depend on the document and variables
+    lookahead_cache: LookaheadCache<'a>,
+    /// Keeps the cached lookaheads reachable by the GC
+    lookahead_roots: RArray,
+    response_class: Option<RClass>,
+    result_coercion_options: ResultCoercionOptions,
+    context: Value,
 }
 
 impl<'a> Engine<'a> {
@@ -48,55 +71,196 @@ impl<'a> Engine<'a> {
         variable_values: RHash,
         initial_value: Value,
         context: Value,
+        response_class: Option<RClass>,
     ) -> Result<ExecutionResult, Error> {
         let document = match ExecutableDocument::parse(query) {
             Ok(document) => document,
             Err(parse_errors) => {
-                return Ok(Self::execution_result(
-                    Default::default(),
-                    parse_errors
-                        .into_iter()
-                        .map(ExecutionError::ParseError)
-                        .collect(),
+                return Ok(ExecutionResult::request_error(
+                    parse_errors.into_iter().map(ExecutionError::ParseError),
                 ));
             }
         };
 
-        let operation_definition = match Self::get_operation(&document, operation_name) {
+        let visibility_cache = VisibilityCache::new(Warden::new(context));
+        let variable_definition_input_type_cache = VariableDefinitionInputTypeCache::new();
+
+        Self::execute_document(
+            schema,
+            query,
+            &document,
+            operation_name,
+            variable_values,
+            initial_value,
+            context,
+            response_class,
+            &visibility_cache,
+            &variable_definition_input_type_cache,
+        )
+        .and_then(|exc_result| visibility_cache.warden().to_result().map(|_| exc_result))
+    }
+
+    /// Executes each of `requests`, which are tuples of a query, an operation name and variables.
+    /// Each distinct query is only parsed once, and the visibility of the schema is only
+    /// evaluated once for the whole batch, as every request shares the same `context`.
+    /// Each result is wrapped and added to the returned array as soon as it is produced, so that
+    /// the values it references are reachable by the GC while later requests execute.
+    pub fn execute_batch(
+        schema: &SchemaDefinition,
+        requests: &[(String, Option<String>, RHash)],
+        initial_value: Value,
+        context: Value,
+    ) -> Result<RArray, Error> {
+        let mut documents: HashMap<&str, Result<ExecutableDocument, Vec<RubyExecutionError>>> =
+            HashMap::new();
+        for (query, _, _) in requests {
+            documents.entry(query.as_str()).or_insert_with(|| {
+                ExecutableDocument::parse(query).map_err(|parse_errors| {
+                    parse_errors
+                        .into_iter()
+                        .map(|error| ExecutionError::ParseError(error).into())
+                        .collect()
+                })
+            });
+        }
+
+        let visibility_cache = VisibilityCache::new(Warden::new(context));
+        let variable_definition_input_type_cache = VariableDefinitionInputTypeCache::new();
+
+        let results = RArray::with_capacity(requests.len());
+        for (query, operation_name, variable_values) in requests {
+            let result = match &documents[query.as_str()] {
+                Ok(document) => Self::execute_document(
+                    schema,
+                    query,
+                    document,
+                    operation_name.as_deref(),
+                    *variable_values,
+                    initial_value,
+                    context,
+                    None,
+                    &visibility_cache,
+                    &variable_definition_input_type_cache,
+                )?,
+                Err(errors) => ExecutionResult::request_error(errors.clone()),
+            };
+            results.push(Obj::wrap(result))?;
+        }
+
+        visibility_cache.warden().to_result().map(|_| results)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn execute_document<'b>(
+        schema: &'b SchemaDefinition,
+        query: &'b str,
+        document: &'b ExecutableDocument<'b>,
+        operation_name: Option<&'b str>,
+        variable_values: RHash,
+        initial_value: Value,
+        context: Value,
+        response_class: Option<RClass>,
+        visibility_cache: &'b VisibilityCache<'b>,
+        variable_definition_input_type_cache: &'b VariableDefinitionInputTypeCache,
+    ) -> Result<ExecutionResult, Error> {
+        let operation_definition = match Self::get_operation(document, operation_name) {
             Ok(od) => od,
             Err(error) => {
-                return Ok(Self::execution_result(Default::default(), vec![error]));
+                return Ok(ExecutionResult::request_error(vec![error]));
             }
         };
 
-        let visibility_cache = VisibilityCache::new(Warden::new(context));
-        let schema_definition = ScopedSchemaDefinition::new(schema, &visibility_cache);
-        let variable_definition_input_type_cache = VariableDefinitionInputTypeCache::new();
+        let schema_definition = ScopedSchemaDefinition::new(schema, visibility_cache);
 
         let variables = match Self::get_variable_values(
             schema,
             operation_definition,
             variable_values,
-            &visibility_cache,
-            &variable_definition_input_type_cache,
+            visibility_cache,
+            variable_definition_input_type_cache,
         ) {
             Ok(cvv) => cvv,
             Err(errors) => {
-                return Ok(Self::execution_result(Default::default(), errors));
+                return Ok(ExecutionResult::request_error(errors));
             }
         };
 
         let instance = Engine {
             schema_definition,
-            document: &document,
+            query,
+            document,
             variables: &variables,
             key_store: KeyStore::new(),
             collect_fields_cache: Default::default(),
+            lookahead_cache: Default::default(),
+            lookahead_roots: RArray::new(),
+            response_class,
+            result_coercion_options: schema.result_coercion_options(),
+            context,
         };
 
-        instance
-            .execute_operation(operation_definition, initial_value)
-            .and_then(|exc_result| visibility_cache.warden().to_result().map(|_| exc_result))
+        instance.execute_operation(operation_definition, initial_value)
+    }
+
+    pub fn coerce_variable_values(
+        schema: &SchemaDefinition,
+        query: &str,
+        operation_name: Option<&str>,
+        variable_values: RHash,
+        context: Value,
+    ) -> Result<Result<RHash, Vec<CoercionError>>, Error> {
+        let document = ExecutableDocument::parse(query).map_err(|errors| {
+            Error::new(
+                exception::arg_error(),
+                format!(
+                    "Document could not be parsed: {}",
+                    errors.iter().map(|error| error.message()).join(", "),
+                ),
+            )
+        })?;
+
+        let operation_definition =
+            Self::get_operation(&document, operation_name).map_err(|error| {
+                Error::new(
+                    exception::arg_error(),
+                    RubyExecutionError::from(error).message().to_owned(),
+                )
+            })?;
+
+        let visibility_cache = VisibilityCache::new(Warden::new(context));
+        let variable_definition_input_type_cache = VariableDefinitionInputTypeCache::new();
+
+        let result = match Self::get_variable_values(
+            schema,
+            operation_definition,
+            variable_values,
+            &visibility_cache,
+            &variable_definition_input_type_cache,
+        ) {
+            Ok(coerced_variables) => Ok(coerced_variables),
+            Err(errors) => Err(errors
+                .into_iter()
+                .map(Self::variable_coercion_error)
+                .collect::<Result<Vec<_>, Error>>()?),
+        };
+
+        visibility_cache.warden().to_result().map(|_| result)
+    }
+
+    /// Only errors about the given variable values are returned as `CoercionError`s,
+    /// anything else is an error in the application and is raised
+    fn variable_coercion_error(error: ExecutionError) -> Result<CoercionError, Error> {
+        match error {
+            ExecutionError::CoercionError(error) => Ok(error),
+            ExecutionError::RequiredVariableMissingValue { name } => Ok(CoercionError::new(
+                RubyExecutionError::from(error).message().to_owned(),
+                vec![name.to_owned()],
+            )),
+            _ => Err(Error::new(
+                exception::runtime_error(),
+                RubyExecutionError::from(error).message().to_owned(),
+            )),
+        }
     }
 
     fn get_operation<'b>(
@@ -222,6 +386,7 @@ impl<'a> Engine<'a> {
             root_type,
             root_value,
             Path::default(),
+            self.response_class,
         );
 
         Ok(Self::execution_result(value, errors))
@@ -233,6 +398,7 @@ impl<'a> Engine<'a> {
         object_type: &ScopedObjectTypeDefinition<'a>,
         object_value: Value,
         path: Path<'a>,
+        response_class: Option<RClass>,
     ) -> (Value, Vec<ExecutionError<'a>>) {
         let mut visited_fragments = HashSet::new();
         let grouped_field_set =
@@ -244,41 +410,135 @@ impl<'a> Engine<'a> {
 
         for (&response_key, fields) in grouped_field_set.as_ref() {
             let field_name = fields.first().unwrap().name().as_ref();
-            let field_definition = object_type
-                .fields_definition()
-                .get(field_name)
-                .unwrap_or_else(|| {
-                    panic!(
-                        "No field definition with name {field_name} on type {}",
-                        object_type.name()
-                    )
-                });
+            let field_definition = indexed_field_definition(
+                object_type.name(),
+                object_type.fields_definition(),
+                field_name,
+                self.index(),
+            )
+            .unwrap_or_else(|| {
+                panic!(
+                    "No field definition with name {field_name} on type {}",
+                    object_type.name()
+                )
+            });
+            let field_response_class = match response_class {
+                Some(response_class) => {
+                    let field_response_class = match field_definition.r#type().as_ref().base() {
+                        ScopedBaseOutputType::BuiltinScalar(_) => Ok(None),
+                        ScopedBaseOutputType::CustomScalar(_) | ScopedBaseOutputType::Enum(_) => {
+                            Self::leaf_response_class(response_class, response_key)
+                        }
+                        _ => Self::field_response_class(response_class, response_key).map(Some),
+                    };
+                    match field_response_class {
+                        Ok(field_response_class) => field_response_class,
+                        Err(error) => {
+                            errors.push(ExecutionError::ResponseClassError {
+                                message: error.to_string(),
+                                path: path.push(response_key),
+                            });
+                            has_null_for_required = true;
+                            continue;
+                        }
+                    }
+                }
+                None => None,
+            };
             let (response_value, mut errs) = self.execute_field(
                 object_type,
                 object_value,
                 field_definition,
                 fields.clone(),
                 path.push(response_key),
+                field_response_class,
             );
             if field_definition.r#type().as_ref().is_required() && response_value.is_nil() {
                 has_null_for_required = true;
             }
-            let key = if response_key == field_name {
-                field_definition.inner().name_r_string()
+            if response_class.is_some() {
+                result_map
+                    .aset(self.key_store.get_symbol(response_key), response_value)
+                    .unwrap();
             } else {
-                self.key_store.get(response_key)
-            };
-            result_map.aset(key, response_value).unwrap();
+                let key = if response_key == field_name {
+                    field_definition.inner().name_r_string()
+                } else {
+                    self.key_store.get(response_key)
+                };
+                result_map.aset(key, response_value).unwrap();
+            }
             errors.append(&mut errs);
         }
 
         if has_null_for_required {
             (*QNIL, errors)
+        } else if let Some(response_class) = response_class {
+            match response_class.new_instance_kw(result_map) {
+                Ok(response_object) => (response_object, errors),
+                Err(error) => {
+                    errors.push(ExecutionError::ResponseClassError {
+                        message: error.to_string(),
+                        path,
+                    });
+                    (*QNIL, errors)
+                }
+            }
         } else {
             (*result_map, errors)
         }
     }
 
+    /// The class or module for the value of the field with `response_key` in a typed
+    /// response, following the naming of `SchemaDefinition#generate_operation_types`. Only
+    /// constants defined on `response_class` itself are used, not those of its ancestors.
+    fn field_response_class(response_class: RClass, response_key: &str) -> Result<RModule, Error> {
+        response_class.funcall("const_get", (class_name(response_key), false))
+    }
+
+    /// The `T::Enum` or custom scalar class for the value of an enum or custom scalar field in a
+    /// typed response, if `response_class` defines one. Without it the field holds the coerced result.
+    fn leaf_response_class(
+        response_class: RClass,
+        response_key: &str,
+    ) -> Result<Option<RModule>, Error> {
+        let constant = class_name(response_key);
+        let is_defined: bool =
+            response_class.funcall("const_defined?", (constant.as_str(), false))?;
+        if is_defined {
+            response_class
+                .funcall("const_get", (constant.as_str(), false))
+                .map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// The class to instantiate for an object of `object_type` in a typed response. A module
+    /// holds one class per object type, and `Other` for the types without a dedicated class.
+    fn object_response_class(
+        field_response_class: RModule,
+        object_type: &ScopedObjectTypeDefinition,
+    ) -> Result<RClass, Error> {
+        if let Some(class) = RClass::from_value(*field_response_class) {
+            return Ok(class);
+        }
+        let variant_name = class_name(object_type.name());
+        let has_variant: bool =
+            field_response_class.funcall("const_defined?", (variant_name.as_str(), false))?;
+        field_response_class.funcall(
+            "const_get",
+            (
+                if has_variant {
+                    variant_name.as_str()
+                } else {
+                    "Other"
+                },
+                false,
+            ),
+        )
+    }
+
     fn collect_fields(
         &'a self,
         object_type: &ScopedObjectTypeDefinition<'a>,
@@ -296,31 +556,7 @@ impl<'a> Engine<'a> {
         let mut grouped_fields: IndexMap<&'a str, Rc<Vec<&'a Field>>> = IndexMap::new();
 
         for selection in selection_set_provider.selection_set() {
-            let should_skip = selection.as_ref().directives().iter().any(|directive| {
-                if directive.name().as_ref() == "skip" {
-                    self.coerce_directive(directive)
-                        .map(|coerced_directive| -> bool {
-                            coerced_directive.funcall("if_arg", ()).unwrap()
-                        })
-                        .unwrap_or(false)
-                } else {
-                    false
-                }
-            });
-
-            let should_include = selection.as_ref().directives().iter().all(|directive| {
-                if directive.name().as_ref() == "include" {
-                    self.coerce_directive(directive)
-                        .map(|coerced_directive| -> bool {
-                            coerced_directive.funcall("if_arg", ()).unwrap()
-                        })
-                        .unwrap_or(true)
-                } else {
-                    true
-                }
-            });
-
-            if should_skip || !should_include {
+            if !self.should_include_selection(selection) {
                 continue;
             }
 
@@ -403,11 +639,46 @@ impl<'a> Engine<'a> {
         wrapped
     }
 
+    /// Whether a selection is included according to its `@skip` and `@include` directives
+    fn should_include_selection(&'a self, selection: &'a Selection<'a>) -> bool {
+        let should_skip = selection.as_ref().directives().iter().any(|directive| {
+            if directive.name().as_ref() == "skip" {
+                self.coerce_directive(directive)
+                    .map(|coerced_directive| -> bool {
+                        coerced_directive.funcall("if_arg", ()).unwrap()
+                    })
+                    .unwrap_or(false)
+            } else {
+                false
+            }
+        });
+
+        let should_include = selection.as_ref().directives().iter().all(|directive| {
+            if directive.name().as_ref() == "include" {
+                self.coerce_directive(directive)
+                    .map(|coerced_directive| -> bool {
+                        coerced_directive.funcall("if_arg", ()).unwrap()
+                    })
+                    .unwrap_or(true)
+            } else {
+                true
+            }
+        });
+
+        !should_skip && should_include
+    }
+
     fn does_fragment_type_apply(
         &'a self,
         object_type: &ScopedObjectTypeDefinition,
         fragment_type_name: &str,
     ) -> bool {
+        if !self.index().has_visibility() {
+            return self
+                .index()
+                .is_possible_type(fragment_type_name, object_type.name());
+        }
+
         let fragment_type = self
             .schema_definition
             .get_type_definition(fragment_type_name)
@@ -438,15 +709,20 @@ impl<'a> Engine<'a> {
         field_definition: &ScopedFieldDefinition<'a>,
         fields: Rc<Vec<&'a Field>>,
         path: Path<'a>,
+        response_class: Option<RModule>,
     ) -> (Value, Vec<ExecutionError<'a>>) {
-        let field = fields.first().unwrap();
+        // `__typename` is the name of the object type that the value was resolved to, which may
+        // have been determined without calling the value's `resolve_typename`
+        if field_definition.name() == "__typename" {
+            return (*RString::new(object_type.name()), Vec::new());
+        }
 
         // TODO: better `resolver_arg_count` with visibility
         if field_definition.inner().resolver_arg_count() == 0 {
             self.resolve_field_value(object_type, object_value, field_definition, None)
                 .map_err(|err| vec![err])
         } else {
-            self.coerce_argument_values(field_definition, field)
+            self.coerce_argument_values(object_type, field_definition, &fields, &path)
                 .and_then(|argument_values| {
                     self.resolve_field_value(
                         object_type,
@@ -458,39 +734,101 @@ impl<'a> Engine<'a> {
                 })
         }
         .map(|resolved_value| {
-            self.complete_value(field_definition.r#type(), fields, resolved_value, path)
+            self.complete_value(
+                field_definition.r#type(),
+                fields,
+                resolved_value,
+                path,
+                response_class,
+            )
         })
         .unwrap_or_else(|errors| (*QNIL, errors))
     }
 
     fn coerce_argument_values(
+        &'a self,
+        object_type: &ScopedObjectTypeDefinition<'a>,
+        field_definition: &ScopedFieldDefinition<'a>,
+        fields: &[&'a Field<'a>],
+        path: &Path<'a>,
+    ) -> Result<RHash, Vec<ExecutionError<'a>>> {
+        let coerced_args =
+            self.coerce_field_arguments(field_definition, fields.first().unwrap(), path)?;
+
+        for extra_resolver_arg in field_definition.inner().extra_resolver_args() {
+            match extra_resolver_arg {
+                ExtraResolverArg::SchemaClass => coerced_args
+                    .aset(
+                        extra_resolver_arg.kwarg_name(),
+                        self.schema_definition.inner().ruby_class(),
+                    )
+                    .unwrap(),
+                ExtraResolverArg::Context => coerced_args
+                    .aset(extra_resolver_arg.kwarg_name(), self.context)
+                    .unwrap(),
+                ExtraResolverArg::Path => coerced_args
+                    .aset(
+                        extra_resolver_arg.kwarg_name(),
+                        RArray::from_iter(path.to_vec()),
+                    )
+                    .unwrap(),
+                ExtraResolverArg::FieldDefinition => {
+                    let position = self
+                        .index()
+                        .field_position(object_type.name(), field_definition.name())
+                        .ok_or_else(|| {
+                            vec![ExecutionError::ApplicationError(format!(
+                                "No field `{}` on `{}`",
+                                field_definition.name(),
+                                object_type.name(),
+                            ))]
+                        })?;
+                    let field_definition_object =
+                        RArray::from(*object_type.inner().fields_definition())
+                            .entry::<Value>(position as isize)
+                            .map_err(|error| {
+                                vec![ExecutionError::ApplicationError(error.to_string())]
+                            })?;
+                    coerced_args
+                        .aset(extra_resolver_arg.kwarg_name(), field_definition_object)
+                        .unwrap()
+                }
+                ExtraResolverArg::Lookahead => coerced_args
+                    .aset(
+                        extra_resolver_arg.kwarg_name(),
+                        self.lookahead(field_definition, fields, path),
+                    )
+                    .unwrap(),
+            }
+        }
+
+        Ok(coerced_args)
+    }
+
+    fn coerce_field_arguments(
         &'a self,
         field_definition: &ScopedFieldDefinition<'a>,
         field: &Field,
+        path: &Path<'a>,
     ) -> Result<RHash, Vec<ExecutionError<'a>>> {
         // TODO: better `resolver_arg_count` with visibility
         let coerced_args = rhash_with_capacity(field_definition.inner().resolver_arg_count());
         let mut errors: Vec<ExecutionError<'a>> = Vec::new();
         if let Some(argument_definitions) = field_definition.arguments_definition() {
             for argument_definition in argument_definitions.iter() {
-                match self.coerce_argument_value(argument_definition, field.arguments()) {
-                    Ok(value) => coerced_args
-                        .aset(argument_definition.inner().ruby_name(), value)
+                let key = argument_definition.inner().ruby_name();
+                match self.coerce_argument_value(argument_definition, field.arguments(), path) {
+                    Ok(Some(value)) => coerced_args.aset(key, value).unwrap(),
+                    Ok(None) => argument_definition
+                        .inner()
+                        .absent_behavior()
+                        .unwrap_or_default()
+                        .apply(coerced_args, key)
                         .unwrap(),
                     Err(errs) => errors.extend(errs.into_iter()),
                 }
             }
         }
-        for extra_resolver_arg in field_definition.inner().extra_resolver_args() {
-            match extra_resolver_arg {
-                ExtraResolverArg::SchemaClass => coerced_args
-                    .aset(
-                        extra_resolver_arg.kwarg_name(),
-                        self.schema_definition.inner().ruby_class(),
-                    )
-                    .unwrap(),
-            }
-        }
 
         if errors.is_empty() {
             Ok(coerced_args)
@@ -499,11 +837,179 @@ impl<'a> Engine<'a> {
         }
     }
 
+    /// Builds the lookahead for `fields`, which are merged because they share a response key.
+    /// Lookaheads are cached by their fields, so they are only built once however many objects the
+    /// fields are executed on. Errors coercing the arguments are raised when the arguments of the
+    /// lookahead are read, and are also reported when the selection is executed.
+    fn lookahead(
+        &'a self,
+        field_definition: &ScopedFieldDefinition<'a>,
+        fields: &[&'a Field<'a>],
+        path: &Path<'a>,
+    ) -> Obj<Lookahead> {
+        let cache_key: Vec<*const Field<'a>> = fields
+            .iter()
+            .map(|&field| field as *const Field<'a>)
+            .collect();
+        if let Some(lookahead) = self.lookahead_cache.borrow().get(&cache_key) {
+            return *lookahead;
+        }
+
+        let field = fields.first().unwrap();
+        let arguments = self
+            .coerce_field_arguments(field_definition, field, path)
+            .map_err(|errors| {
+                errors
+                    .into_iter()
+                    .map(|error| RubyExecutionError::from(error).message().to_owned())
+                    .collect()
+            });
+        let mut grouped_fields: LookaheadFields<'a> = IndexMap::new();
+        if let Some(composite_type) = Self::composite_type(field_definition.r#type()) {
+            let mut visited_fragments = HashSet::new();
+            for &field in fields {
+                if let Some(selection_set) = field.selection_set() {
+                    self.collect_lookahead_fields(
+                        composite_type,
+                        selection_set,
+                        &mut visited_fragments,
+                        &mut grouped_fields,
+                    );
+                }
+            }
+        }
+        // each lookahead is added to the array as soon as it is built, so that it is reachable by
+        // the GC while its siblings are built
+        let selections = RArray::with_capacity(grouped_fields.len());
+        for (response_key, (field_definition, fields)) in grouped_fields {
+            selections
+                .push(self.lookahead(field_definition, &fields, &path.push(response_key)))
+                .unwrap();
+        }
+        let lookahead = Obj::wrap(Lookahead::new(
+            field_definition.name().to_owned(),
+            field.response_key().to_owned(),
+            arguments,
+            selections,
+        ));
+        self.lookahead_roots.push(lookahead).unwrap();
+        self.lookahead_cache
+            .borrow_mut()
+            .insert(cache_key, lookahead);
+        lookahead
+    }
+
+    /// Groups the fields of `selection_set` by response key like `collect_fields`, except that
+    /// fragments are resolved against their type condition instead of a single object type, so
+    /// that the selections on all of the possible types of an abstract type are included
+    fn collect_lookahead_fields(
+        &'a self,
+        composite_type: ScopedCompositeType<'a>,
+        selection_set: &'a SelectionSet<'a>,
+        visited_fragments: &mut HashSet<&'a str>,
+        grouped_fields: &mut LookaheadFields<'a>,
+    ) {
+        for selection in selection_set.iter() {
+            if !self.should_include_selection(selection) {
+                continue;
+            }
+
+            match selection {
+                Selection::Field(field) => {
+                    if let Some(field_definition) =
+                        composite_type.field_definition(field.name().as_ref(), self.index())
+                    {
+                        grouped_fields
+                            .entry(field.response_key())
+                            .or_insert_with(|| (field_definition, Vec::new()))
+                            .1
+                            .push(field);
+                    }
+                }
+                Selection::FragmentSpread(fragment_spread) => {
+                    let fragment_spread_name = fragment_spread.name().as_ref();
+                    if !visited_fragments.insert(fragment_spread_name) {
+                        continue;
+                    }
+                    let fragment = self
+                        .document
+                        .fragment_definitions()
+                        .iter()
+                        .find(|fd| fd.name().as_ref() == fragment_spread_name);
+                    let fragment_type = fragment.and_then(|fragment| {
+                        self.composite_type_named(fragment.type_condition().named_type().as_ref())
+                    });
+                    if let (Some(fragment), Some(fragment_type)) = (fragment, fragment_type) {
+                        self.collect_lookahead_fields(
+                            fragment_type,
+                            fragment.selection_set(),
+                            visited_fragments,
+                            grouped_fields,
+                        );
+                    }
+                }
+                Selection::InlineFragment(inline_fragment) => {
+                    let fragment_type = match inline_fragment.type_condition() {
+                        Some(type_condition) => {
+                            self.composite_type_named(type_condition.named_type().as_ref())
+                        }
+                        None => Some(composite_type),
+                    };
+                    if let Some(fragment_type) = fragment_type {
+                        self.collect_lookahead_fields(
+                            fragment_type,
+                            inline_fragment.selection_set(),
+                            visited_fragments,
+                            grouped_fields,
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    fn composite_type(output_type: &ScopedOutputType<'a>) -> Option<ScopedCompositeType<'a>> {
+        match output_type.as_ref() {
+            OutputTypeReference::Base(base, _) => match base {
+                ScopedBaseOutputType::Object(otd) => Some(ScopedCompositeType::Object(otd)),
+                ScopedBaseOutputType::Interface(itd) => Some(ScopedCompositeType::Interface(itd)),
+                ScopedBaseOutputType::Union(utd) => Some(ScopedCompositeType::Union(utd)),
+                ScopedBaseOutputType::BuiltinScalar(_)
+                | ScopedBaseOutputType::CustomScalar(_)
+                | ScopedBaseOutputType::Enum(_) => None,
+            },
+            OutputTypeReference::List(inner, _) => Self::composite_type(inner),
+        }
+    }
+
+    /// The 1-based line and column of the first of `fields` in the query
+    fn field_location(&self, fields: &[&Field]) -> (usize, usize) {
+        let byte_offset = fields.first().unwrap().name().span().byte_range().start;
+        line_and_column(self.query, byte_offset)
+    }
+
+    fn index(&'a self) -> &'a SchemaIndex {
+        self.schema_definition.inner().index()
+    }
+
+    fn composite_type_named(&'a self, name: &str) -> Option<ScopedCompositeType<'a>> {
+        match self.schema_definition.get_type_definition(name)? {
+            TypeDefinitionReference::Object(otd) => Some(ScopedCompositeType::Object(otd)),
+            TypeDefinitionReference::Interface(itd) => Some(ScopedCompositeType::Interface(itd)),
+            TypeDefinitionReference::Union(utd) => Some(ScopedCompositeType::Union(utd)),
+            TypeDefinitionReference::BuiltinScalar(_)
+            | TypeDefinitionReference::CustomScalar(_)
+            | TypeDefinitionReference::Enum(_)
+            | TypeDefinitionReference::InputObject(_) => None,
+        }
+    }
+
     fn coerce_argument_value(
         &'a self,
         argument_definition: &ScopedInputValueDefinition<'a>,
         arguments: Option<&VariableArguments>,
-    ) -> Result<Value, Vec<ExecutionError<'a>>> {
+        path: &Path<'a>,
+    ) -> Result<Option<Value>, Vec<ExecutionError<'a>>> {
         let argument_name = argument_definition.name();
         let argument_type = argument_definition.r#type();
         let default_value = argument_definition.inner().default_value();
@@ -513,32 +1019,46 @@ impl<'a> Engine<'a> {
                 .find(|argument| argument.name().as_ref() == argument_name)
                 .map(|argument| argument.value())
         });
+        // a variable without a value is treated the same as an absent argument
+        let argument_value = argument_value.filter(|argument_value| match argument_value {
+            VariableValue::Variable(var) => self.variables.get(var.name()).is_some(),
+            _ => true,
+        });
         let has_value = argument_value.is_some();
         match default_value {
-            Some(default_value) if !has_value => Ok(default_value.to_value()),
+            Some(default_value) if !has_value => Ok(Some(default_value.to_value())),
             _ => {
                 if argument_type.as_ref().is_required() && !has_value {
-                    // TODO: field error
-                    // shouldn't this never happen if query is validated and variables coerced to match definition in query?
-                    todo!()
+                    Err(vec![ExecutionError::CoercionError(
+                        CoercionError::new(
+                            format!(
+                                "No value was provided for required argument `{argument_name}`"
+                            ),
+                            vec![argument_name.to_owned()],
+                        )
+                        .with_argument(path.to_vec(), argument_name),
+                    )])
                 } else if let Some(argument_value) = argument_value {
-                    // TODO: see if it is possible to distinguish between null and no value being passed
                     match argument_type.coerce_parser_value(
                         argument_value,
                         Path::new(argument_name),
                         self.variables,
                     ) {
-                        Ok(Ok(coerced_value)) => Ok(coerced_value),
+                        Ok(Ok(coerced_value)) => Ok(Some(coerced_value)),
                         Ok(Err(coercion_errors)) => Err(coercion_errors
                             .into_iter()
-                            .map(ExecutionError::CoercionError)
+                            .map(|error| {
+                                ExecutionError::CoercionError(
+                                    error.with_argument(path.to_vec(), argument_name),
+                                )
+                            })
                             .collect()),
                         Err(error) => {
                             Err(vec![ExecutionError::ApplicationError(error.to_string())])
                         }
                     }
                 } else {
-                    Ok(*QNIL)
+                    Ok(None)
                 }
             }
         }
@@ -552,11 +1072,14 @@ impl<'a> Engine<'a> {
         argument_values: Option<RHash>,
     ) -> Result<Value, ExecutionError<'a>> {
         // TODO: use object_type somehow?
-        match argument_values {
-            Some(kwargs) => {
+        match (field_definition.inner().resolver(), argument_values) {
+            (Some(resolver), argument_values) => resolver.resolve(object_value, argument_values),
+            (None, Some(kwargs)) => {
                 object_value.funcall_kw(field_definition.inner().ruby_resolver_method_id(), kwargs)
             }
-            None => object_value.funcall(field_definition.inner().ruby_resolver_method_id(), ()),
+            (None, None) => {
+                object_value.funcall(field_definition.inner().ruby_resolver_method_id(), ())
+            }
         }
         .map_err(|error| ExecutionError::ApplicationError(error.to_string()))
     }
@@ -567,6 +1090,7 @@ impl<'a> Engine<'a> {
         fields: Rc<Vec<&'a Field>>,
         result: Value,
         path: Path<'a>,
+        response_class: Option<RModule>,
     ) -> (Value, Vec<ExecutionError<'a>>) {
         if field_type.as_ref().is_required() && result.is_nil() {
             return (
@@ -574,6 +1098,7 @@ impl<'a> Engine<'a> {
                 vec![ExecutionError::FieldError {
                     error: FieldError::ReturnedNullForNonNullType,
                     path,
+                    location: self.field_location(&fields),
                 }],
             );
         } else if result.is_nil() {
@@ -582,29 +1107,84 @@ impl<'a> Engine<'a> {
 
         match field_type.as_ref() {
             OutputTypeReference::Base(inner, _) => match inner {
-                ScopedBaseOutputType::BuiltinScalar(bstd) => match bstd.coerce_result(result) {
-                    Ok(value) => (value, vec![]),
-                    Err(error) => (*QNIL, vec![ExecutionError::FieldError { error, path }]),
-                },
-                ScopedBaseOutputType::CustomScalar(cstd) => match cstd.coerce_result(result) {
-                    Ok(value) => (value, vec![]),
-                    Err(error) => (*QNIL, vec![ExecutionError::FieldError { error, path }]),
-                },
-                ScopedBaseOutputType::Enum(etd) => match etd.coerce_result(result) {
-                    Ok(value) => (value, vec![]),
-                    Err(error) => (*QNIL, vec![ExecutionError::FieldError { error, path }]),
-                },
+                ScopedBaseOutputType::BuiltinScalar(bstd) => {
+                    match bstd.coerce_result(result, &self.result_coercion_options) {
+                        Ok(value) => (value, vec![]),
+                        Err(error) => (
+                            *QNIL,
+                            vec![ExecutionError::FieldError {
+                                error,
+                                path,
+                                location: self.field_location(&fields),
+                            }],
+                        ),
+                    }
+                }
+                ScopedBaseOutputType::CustomScalar(cstd) => {
+                    match cstd.coerce_result(result, &self.result_coercion_options) {
+                        // a typed response holds the internal representation
+                        Ok(_) if response_class.is_some() => (result, vec![]),
+                        Ok(value) => (value, vec![]),
+                        Err(error) => (
+                            *QNIL,
+                            vec![ExecutionError::FieldError {
+                                error,
+                                path,
+                                location: self.field_location(&fields),
+                            }],
+                        ),
+                    }
+                }
+                ScopedBaseOutputType::Enum(etd) => {
+                    match etd.coerce_result(result, &self.result_coercion_options) {
+                        Ok(value) => match response_class {
+                            Some(enum_class) => match enum_class.funcall("deserialize", (value,)) {
+                                Ok(value) => (value, vec![]),
+                                Err(error) => (
+                                    *QNIL,
+                                    vec![ExecutionError::ResponseClassError {
+                                        message: error.to_string(),
+                                        path,
+                                    }],
+                                ),
+                            },
+                            None => (value, vec![]),
+                        },
+                        Err(error) => (
+                            *QNIL,
+                            vec![ExecutionError::FieldError {
+                                error,
+                                path,
+                                location: self.field_location(&fields),
+                            }],
+                        ),
+                    }
+                }
                 ScopedBaseOutputType::Object(otd) => {
-                    self.execute_selection_set(fields.into(), otd, result, path)
+                    self.complete_object_value(fields, otd, result, path, response_class)
                 }
                 ScopedBaseOutputType::Interface(itd) => {
-                    let object_type = self.resolve_interface_type(itd, result);
-                    self.execute_selection_set(fields.into(), object_type, result, path)
-                }
-                ScopedBaseOutputType::Union(utd) => {
-                    let object_type = self.resolve_union_type(utd, result);
-                    self.execute_selection_set(fields.into(), object_type, result, path)
+                    match self.resolve_interface_type(itd, result) {
+                        Ok(object_type) => self.complete_object_value(
+                            fields,
+                            object_type,
+                            result,
+                            path,
+                            response_class,
+                        ),
+                        Err(error) => (*QNIL, vec![error]),
+                    }
                 }
+                ScopedBaseOutputType::Union(utd) => match self.resolve_union_type(utd, result) {
+                    Ok(object_type) => self.complete_object_value(
+                        fields,
+                        object_type,
+                        result,
+                        path,
+                        response_class,
+                    ),
+                    Err(error) => (*QNIL, vec![error]),
+                },
             },
             OutputTypeReference::List(inner, _) => {
                 if let Some(arr) = RArray::from_value(result) {
@@ -612,8 +1192,13 @@ impl<'a> Engine<'a> {
                     let mut errors: Vec<ExecutionError<'a>> = Vec::new();
                     let mut has_null = false;
                     for (idx, item) in RArrayIter::from(&arr).enumerate() {
-                        let (value, mut errs) =
-                            self.complete_value(inner, fields.clone(), item, path.push(idx));
+                        let (value, mut errs) = self.complete_value(
+                            inner,
+                            fields.clone(),
+                            item,
+                            path.push(idx),
+                            response_class,
+                        );
                         completed.push(value).unwrap(); // TODO: make sure unwrapping is ok here
                         errors.append(&mut errs);
                         if value.is_nil() {
@@ -631,6 +1216,7 @@ impl<'a> Engine<'a> {
                         vec![ExecutionError::FieldError {
                             error: FieldError::ReturnedNonListForListType,
                             path,
+                            location: self.field_location(&fields),
                         }],
                     )
                 }
@@ -638,44 +1224,96 @@ impl<'a> Engine<'a> {
         }
     }
 
+    fn complete_object_value(
+        &'a self,
+        fields: Rc<Vec<&'a Field>>,
+        object_type: &ScopedObjectTypeDefinition<'a>,
+        result: Value,
+        path: Path<'a>,
+        response_class: Option<RModule>,
+    ) -> (Value, Vec<ExecutionError<'a>>) {
+        let response_class = match response_class
+            .map(|response_class| Self::object_response_class(response_class, object_type))
+            .transpose()
+        {
+            Ok(response_class) => response_class,
+            Err(error) => {
+                return (
+                    *QNIL,
+                    vec![ExecutionError::ResponseClassError {
+                        message: error.to_string(),
+                        path,
+                    }],
+                )
+            }
+        };
+        self.execute_selection_set(fields.into(), object_type, result, path, response_class)
+    }
+
     fn resolve_interface_type(
         &'a self,
         interface_type: &'a ScopedInterfaceTypeDefinition<'a>,
         object_value: Value,
-    ) -> &'a ScopedObjectTypeDefinition<'a> {
-        // TODO: change to return Result<_, FieldError>
-        let typename: String = object_value.funcall("resolve_typename", ()).unwrap();
-        let object_type = self
+    ) -> Result<&'a ScopedObjectTypeDefinition<'a>, ExecutionError<'a>> {
+        let possible_types: Vec<_> = self
             .schema_definition
-            .get_type_definition(typename.as_str())
-            .unwrap()
-            .into_object()
-            .unwrap_or_else(|_| panic!("Returned type is not an object"));
-        if ObjectTypeDefinition::implements_interface(object_type, interface_type) {
-            object_type
-        } else {
-            panic!()
-        }
+            .get_interface_implementors(interface_type)
+            .collect();
+        Self::resolve_abstract_type(interface_type.name(), &possible_types, object_value)
     }
 
     fn resolve_union_type(
         &'a self,
         union_type: &'a ScopedUnionTypeDefinition<'a>,
         object_value: Value,
-    ) -> &'a ScopedObjectTypeDefinition<'a> {
-        // TODO: change to return Result<_, FieldError>
-        let typename: String = object_value.funcall("resolve_typename", ()).unwrap();
-        let object_type = self
-            .schema_definition
-            .get_type_definition(typename.as_str())
-            .unwrap()
-            .into_object()
-            .unwrap_or_else(|_| panic!("Type is not an object"));
-        if UnionTypeDefinition::contains_type(union_type, object_type) {
+    ) -> Result<&'a ScopedObjectTypeDefinition<'a>, ExecutionError<'a>> {
+        let possible_types: Vec<_> = union_type
+            .union_member_types()
+            .iter()
+            .map(|union_member_type| union_member_type.member_type())
+            .collect();
+        Self::resolve_abstract_type(union_type.name(), &possible_types, object_value)
+    }
+
+    /// Resolves the object type of a value of an abstract type. When the value includes the
+    /// `Interface` module of exactly one of the possible types, that type is used. Otherwise the
+    /// value's `resolve_typename` is called, which can return the name of the type or a `T::Enum`
+    /// serializing to it.
+    fn resolve_abstract_type(
+        abstract_type_name: &str,
+        possible_types: &[&'a ScopedObjectTypeDefinition<'a>],
+        object_value: Value,
+    ) -> Result<&'a ScopedObjectTypeDefinition<'a>, ExecutionError<'a>> {
+        let mut included_types = possible_types.iter().filter(|object_type| {
             object_type
-        } else {
-            panic!()
+                .inner()
+                .interface_module()
+                .map_or(false, |interface_module| {
+                    object_value.is_kind_of(interface_module)
+                })
+        });
+        if let (Some(&object_type), None) = (included_types.next(), included_types.next()) {
+            return Ok(object_type);
         }
+
+        let typename: Result<String, Error> = object_value
+            .funcall("resolve_typename", ())
+            .and_then(|typename: Value| match RString::from_value(typename) {
+                Some(typename) => typename.to_string(),
+                None => typename.funcall("serialize", ()),
+            });
+        let typename =
+            typename.map_err(|error| ExecutionError::ApplicationError(error.to_string()))?;
+
+        possible_types
+            .iter()
+            .find(|object_type| object_type.name() == typename)
+            .copied()
+            .ok_or_else(|| {
+                ExecutionError::ApplicationError(format!(
+                    "`{typename}` is not a possible type for `{abstract_type_name}`"
+                ))
+            })
     }
 
     fn coerce_directive(
@@ -692,9 +1330,18 @@ impl<'a> Engine<'a> {
                 let coerced_args = rhash_with_capacity(arguments_definition.len());
                 let mut errors = Vec::new();
                 for argument_definition in arguments_definition.iter() {
-                    match self.coerce_argument_value(argument_definition, directive.arguments()) {
-                        Ok(value) => coerced_args
-                            .aset(argument_definition.inner().ruby_name(), value)
+                    let key = argument_definition.inner().ruby_name();
+                    match self.coerce_argument_value(
+                        argument_definition,
+                        directive.arguments(),
+                        &Path::default(),
+                    ) {
+                        Ok(Some(value)) => coerced_args.aset(key, value).unwrap(),
+                        Ok(None) => argument_definition
+                            .inner()
+                            .absent_behavior()
+                            .unwrap_or_default()
+                            .apply(coerced_args, key)
                             .unwrap(),
                         Err(errs) => errors.extend(errs.into_iter()),
                     }
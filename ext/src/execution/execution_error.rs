@@ -5,13 +5,25 @@ use bluejay_validator::Path;
 
 #[derive(Debug)]
 pub enum ExecutionError<'a> {
-    NoOperationWithName { name: &'a str },
+    NoOperationWithName {
+        name: &'a str,
+    },
     CannotUseAnonymousOperation,
-    RequiredVariableMissingValue { name: &'a str },
+    RequiredVariableMissingValue {
+        name: &'a str,
+    },
     ApplicationError(String),
     CoercionError(CoercionError),
     ParseError(ParseError),
-    FieldError { error: FieldError, path: Path<'a> },
+    FieldError {
+        error: FieldError,
+        path: Path<'a>,
+        location: (usize, usize),
+    },
+    ResponseClassError {
+        message: String,
+        path: Path<'a>,
+    },
 }
 
 impl<'a> From<ExecutionError<'a>> for RubyExecutionError {
@@ -23,7 +35,16 @@ impl<'a> From<ExecutionError<'a>> for RubyExecutionError {
             ExecutionError::ApplicationError(error) => Self::new(format!("Internal error: {error}"), None),
             ExecutionError::CoercionError(error) =>  error.into(),
             ExecutionError::ParseError(error) => Self::new(error.message().to_owned(), None),
-            ExecutionError::FieldError { error, path } => Self::new(error.message().to_string(), Some(path.to_vec())),
+            ExecutionError::FieldError { error, path, location } => {
+                let execution_error = Self::new(error.message().to_string(), Some(path.to_vec())).with_locations(vec![location]);
+                let entries = error.extension_entries();
+                if entries.is_empty() {
+                    execution_error
+                } else {
+                    execution_error.with_extensions(entries)
+                }
+            }
+            ExecutionError::ResponseClassError { message, path } => Self::new(format!("Response class error: {message}"), Some(path.to_vec())),
         }
     }
 }
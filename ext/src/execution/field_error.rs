@@ -1,3 +1,4 @@
+use crate::ruby_api::ExtensionValue;
 use bluejay_core::BuiltinScalarDefinition;
 use std::borrow::Cow;
 
@@ -7,9 +8,11 @@ pub enum FieldError {
     ReturnedNonListForListType,
     CannotCoerceResultToBuiltinScalar {
         builtin_scalar: BuiltinScalarDefinition,
+        value: Option<String>,
     },
     CannotCoerceResultToEnumType,
     CannotCoerceResultToCustomScalar {
+        scalar_name: String,
         message: String,
     },
     ApplicationError(String),
@@ -20,14 +23,35 @@ impl FieldError {
         match self {
             Self::ReturnedNullForNonNullType => "Cannot return null for non-nullable field".into(),
             Self::ReturnedNonListForListType => "Cannot return non-list for list field".into(),
-            Self::CannotCoerceResultToBuiltinScalar { builtin_scalar } => format!(
+            Self::CannotCoerceResultToBuiltinScalar {
+                builtin_scalar,
+                value: None,
+            } => format!(
                 "Cannot coerce result to builtin scalar `{}`",
                 builtin_scalar.name()
             )
             .into(),
+            Self::CannotCoerceResultToBuiltinScalar {
+                builtin_scalar,
+                value: Some(value),
+            } => format!(
+                "Cannot coerce result to builtin scalar `{}`, got {value}",
+                builtin_scalar.name()
+            )
+            .into(),
             Self::CannotCoerceResultToEnumType => "Cannot coerce result to enum type".into(),
-            Self::CannotCoerceResultToCustomScalar { message } => message.as_str().into(),
+            Self::CannotCoerceResultToCustomScalar { message, .. } => message.as_str().into(),
             Self::ApplicationError(message) => format!("Application error: {}", message).into(),
         }
     }
+
+    /// The entries of the `extensions` of the execution error
+    pub fn extension_entries(&self) -> Vec<(&'static str, ExtensionValue)> {
+        match self {
+            Self::CannotCoerceResultToCustomScalar { scalar_name, .. } => {
+                vec![("scalarName", ExtensionValue::String(scalar_name.clone()))]
+            }
+            _ => Vec::new(),
+        }
+    }
 }
@@ -1,9 +1,11 @@
-use magnus::{RArray, RString};
+use crate::codegen::ruby_name;
+use magnus::{RArray, RString, Symbol};
 use std::cell::RefCell;
 use std::collections::HashMap;
 
 pub(super) struct KeyStore<'a> {
     hash_map: RefCell<HashMap<&'a str, RString>>,
+    symbols: RefCell<HashMap<&'a str, Symbol>>,
     strings: RArray,
 }
 
@@ -11,6 +13,7 @@ impl<'a> KeyStore<'a> {
     pub fn new() -> Self {
         Self {
             hash_map: RefCell::new(HashMap::new()),
+            symbols: RefCell::new(HashMap::new()),
             strings: RArray::new(),
         }
     }
@@ -23,4 +26,13 @@ impl<'a> KeyStore<'a> {
             s
         })
     }
+
+    /// The keyword used for a response key when instantiating typed response objects
+    pub fn get_symbol(&self, s: &'a str) -> Symbol {
+        *self.symbols.borrow_mut().entry(s).or_insert_with(|| {
+            let symbol = Symbol::new(ruby_name(s));
+            self.strings.push(symbol).unwrap();
+            symbol
+        })
+    }
 }
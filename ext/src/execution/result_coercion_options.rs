@@ -0,0 +1,11 @@
+/// Schema-wide options controlling how resolved values are coerced to builtin scalars
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ResultCoercionOptions {
+    /// Name the offending value in the errors raised when a value cannot be coerced
+    pub strict: bool,
+    /// Coerce a `Float` with an integral value (e.g. `1.0`) to `Int` instead of rejecting it
+    pub coerce_integral_floats_to_int: bool,
+    /// Accept any `Integer` as an `ID`, instead of only those that fit in 32 bits, serializing those
+    /// beyond ±(2^53 - 1) as strings
+    pub wide_integer_ids: bool,
+}
@@ -1,15 +1,23 @@
 mod funcall_kw;
+mod json;
+mod line_and_column;
 mod public_name;
+mod tracking_allocator;
 mod typed_frozen_r_array;
 mod variables;
 mod warden;
+mod without_gvl;
 mod wrapped_definition;
 
 pub use funcall_kw::{FuncallKw, NewInstanceKw};
+pub use json::{write_json_string, write_json_value};
+pub use line_and_column::line_and_column;
 pub use public_name::public_name;
+pub use tracking_allocator::TrackingAllocator;
 pub use typed_frozen_r_array::TypedFrozenRArray;
 pub use variables::Variables;
 pub use warden::Warden;
+pub use without_gvl::without_gvl;
 pub use wrapped_definition::{HasDefinitionWrapper, WrappedDefinition};
 
 use bluejay_core::{AsIter, ObjectValue, Value as CoreValue, ValueReference, Variable};
@@ -77,6 +85,11 @@ impl<T: TryConvert> Iterator for RArrayIter<'_, T> {
         let remaining = self.data.len() - self.idx;
         (remaining, Some(remaining))
     }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.idx = self.idx.saturating_add(n).min(self.data.len());
+        self.next()
+    }
 }
 
 #[cfg(ruby_gte_3_2)]
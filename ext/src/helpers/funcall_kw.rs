@@ -8,25 +8,53 @@ use std::os::raw::c_int;
 
 pub trait FuncallKw {
     fn funcall_kw<M: IntoId, T: TryConvert>(self, method: M, kwargs: RHash) -> Result<T, Error>;
+
+    fn funcall_with_kw<M: IntoId, T: TryConvert>(
+        self,
+        method: M,
+        args: &[Value],
+        kwargs: RHash,
+    ) -> Result<T, Error>;
 }
 
 impl FuncallKw for Value {
     fn funcall_kw<M: IntoId, T: TryConvert>(self, method: M, kwargs: RHash) -> Result<T, Error> {
-        let args = [kwargs];
-        let slice = args.as_slice();
-        unsafe {
-            let id = method.into_id_unchecked();
-            protect(|| {
-                rb_funcallv_kw(
-                    self.as_raw(),
-                    id.as_raw(),
-                    slice.len() as c_int,
-                    slice.as_ptr() as *const VALUE,
-                    RB_PASS_KEYWORDS as c_int,
-                )
-            })
-            .and_then(|v| Value::from_raw(v).try_convert())
-        }
+        funcall_raw_kw(self, method, &[kwargs.as_raw()])
+    }
+
+    fn funcall_with_kw<M: IntoId, T: TryConvert>(
+        self,
+        method: M,
+        args: &[Value],
+        kwargs: RHash,
+    ) -> Result<T, Error> {
+        let raw_args: Vec<VALUE> = args
+            .iter()
+            .map(|arg| arg.as_raw())
+            .chain(std::iter::once(kwargs.as_raw()))
+            .collect();
+        funcall_raw_kw(self, method, raw_args.as_slice())
+    }
+}
+
+/// Calls `method` on `recv` with `args`, the last of which must be a hash of keyword arguments
+fn funcall_raw_kw<M: IntoId, T: TryConvert>(
+    recv: Value,
+    method: M,
+    args: &[VALUE],
+) -> Result<T, Error> {
+    unsafe {
+        let id = method.into_id_unchecked();
+        protect(|| {
+            rb_funcallv_kw(
+                recv.as_raw(),
+                id.as_raw(),
+                args.len() as c_int,
+                args.as_ptr(),
+                RB_PASS_KEYWORDS as c_int,
+            )
+        })
+        .and_then(|v| Value::from_raw(v).try_convert())
     }
 }
 
@@ -0,0 +1,104 @@
+use crate::helpers::RArrayIter;
+use magnus::{
+    class, r_hash::ForEach, Error, Float, Integer, RArray, RHash, RString, Symbol, Value,
+};
+use std::fmt::Write;
+
+/// Appends `s` to `buf` as a JSON string
+pub fn write_json_string(buf: &mut String, s: &str) {
+    buf.push('"');
+    let mut start = 0;
+    for (idx, c) in s.char_indices() {
+        let escaped = match c {
+            '"' => "\\\"",
+            '\\' => "\\\\",
+            '\n' => "\\n",
+            '\r' => "\\r",
+            '\t' => "\\t",
+            '\u{08}' => "\\b",
+            '\u{0C}' => "\\f",
+            c if c < ' ' => "",
+            _ => continue,
+        };
+        buf.push_str(&s[start..idx]);
+        if escaped.is_empty() {
+            write!(buf, "\\u{:04x}", c as u32).unwrap();
+        } else {
+            buf.push_str(escaped);
+        }
+        start = idx + c.len_utf8();
+    }
+    buf.push_str(&s[start..]);
+    buf.push('"');
+}
+
+/// Appends `f` to `buf` as a JSON number. Like Ruby's `Float#to_s`, integral values keep a
+/// fractional part (e.g. `1.0`), so that they remain distinguishable from integers.
+fn write_json_float(buf: &mut String, f: f64) -> Result<(), Error> {
+    if !f.is_finite() {
+        return Err(Error::new(
+            magnus::exception::arg_error(),
+            format!("{f} is not allowed in JSON"),
+        ));
+    }
+    // the `Debug` implementation is the shortest representation that round-trips, and always
+    // includes a fractional part or an exponent
+    write!(buf, "{f:?}").unwrap();
+    Ok(())
+}
+
+/// Appends the JSON representation of a response value to `buf`. Hashes, arrays, strings,
+/// symbols, numbers, booleans and `nil` are written directly, and any other value is written using
+/// its own `to_json`.
+pub fn write_json_value(buf: &mut String, value: Value) -> Result<(), Error> {
+    if value.is_nil() {
+        buf.push_str("null");
+    } else if value.is_kind_of(class::true_class()) {
+        buf.push_str("true");
+    } else if value.is_kind_of(class::false_class()) {
+        buf.push_str("false");
+    } else if let Some(s) = RString::from_value(value) {
+        write_json_string(buf, unsafe { s.as_str() }?);
+    } else if let Some(symbol) = Symbol::from_value(value) {
+        write_json_string(buf, symbol.name()?.as_ref());
+    } else if let Some(i) = Integer::from_value(value) {
+        match i.to_i64() {
+            Ok(i) => write!(buf, "{i}").unwrap(),
+            Err(_) => buf.push_str(unsafe { value.to_r_string()?.as_str() }?),
+        }
+    } else if let Some(f) = Float::from_value(value) {
+        write_json_float(buf, f.to_f64())?;
+    } else if let Some(array) = RArray::from_value(value) {
+        buf.push('[');
+        for (idx, item) in RArrayIter::<Value>::from(&array).enumerate() {
+            if idx > 0 {
+                buf.push(',');
+            }
+            write_json_value(buf, item)?;
+        }
+        buf.push(']');
+    } else if let Some(hash) = RHash::from_value(value) {
+        buf.push('{');
+        let mut first = true;
+        hash.foreach(|key: Value, value: Value| {
+            if !first {
+                buf.push(',');
+            }
+            first = false;
+            if let Some(symbol) = Symbol::from_value(key) {
+                write_json_string(buf, symbol.name()?.as_ref());
+            } else {
+                write_json_string(buf, unsafe { key.to_r_string()?.as_str() }?);
+            }
+            buf.push(':');
+            write_json_value(buf, value)?;
+            Ok(ForEach::Continue)
+        })?;
+        buf.push('}');
+    } else {
+        let json: RString = value.funcall("to_json", ())?;
+        buf.push_str(unsafe { json.as_str() }?);
+    }
+
+    Ok(())
+}
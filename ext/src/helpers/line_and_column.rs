@@ -0,0 +1,8 @@
+/// 1-based line and column (in characters) of `byte_offset` within `source`
+pub fn line_and_column(source: &str, byte_offset: usize) -> (usize, usize) {
+    let prefix = &source[..byte_offset.min(source.len())];
+    let line = prefix.matches('\n').count() + 1;
+    let line_start = prefix.rfind('\n').map_or(0, |idx| idx + 1);
+    let column = prefix[line_start..].chars().count() + 1;
+    (line, column)
+}
@@ -0,0 +1,82 @@
+use rb_sys::rb_gc_adjust_memory_usage;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+thread_local! {
+    /// The change in allocated memory not reported yet because the GVL is released, `None` while
+    /// it is held. A `const` initialized `Cell` has no destructor, so accessing it never allocates.
+    static DEFERRED_USAGE: Cell<Option<isize>> = const { Cell::new(None) };
+}
+
+/// The system allocator, reporting the memory allocated by the extension to the Ruby GC like the
+/// `global-allocator` feature of rb-sys.
+///
+/// `rb_gc_adjust_memory_usage` updates the GC's accounting and must be called with the GVL held,
+/// which the extension's own threads never release other than through `without_gvl`, and the
+/// extension does not spawn threads of its own. While `without_gvl` releases it, changes are
+/// accumulated in a thread local instead and reported once the GVL is reacquired.
+pub struct TrackingAllocator;
+
+impl TrackingAllocator {
+    fn adjust_memory_usage(delta: isize) {
+        if delta == 0 {
+            return;
+        }
+        let deferred = DEFERRED_USAGE.try_with(|deferred| match deferred.get() {
+            Some(usage) => {
+                deferred.set(Some(usage + delta));
+                true
+            }
+            None => false,
+        });
+        // the thread local is only inaccessible while the thread exits, when it is unknown whether
+        // the GVL is held, so the change is left unreported, which only makes the GC's count inexact
+        if let Ok(false) = deferred {
+            unsafe { rb_gc_adjust_memory_usage(delta as _) };
+        }
+    }
+
+    /// Defers reporting changes in allocated memory until `resume_reporting` is called, which
+    /// must happen before the current thread reacquires the GVL
+    pub(crate) fn defer_reporting() {
+        DEFERRED_USAGE.with(|deferred| deferred.set(Some(0)));
+    }
+
+    /// Reports the changes deferred since `defer_reporting`, the GVL must be held
+    pub(crate) fn resume_reporting() {
+        if let Some(usage) = DEFERRED_USAGE.with(|deferred| deferred.replace(None)) {
+            Self::adjust_memory_usage(usage);
+        }
+    }
+}
+
+unsafe impl GlobalAlloc for TrackingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            Self::adjust_memory_usage(layout.size() as isize);
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            Self::adjust_memory_usage(layout.size() as isize);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        Self::adjust_memory_usage(-(layout.size() as isize));
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            Self::adjust_memory_usage(new_size as isize - layout.size() as isize);
+        }
+        new_ptr
+    }
+}
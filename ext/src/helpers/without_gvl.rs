@@ -0,0 +1,50 @@
+use super::TrackingAllocator;
+use rb_sys::rb_thread_call_without_gvl2;
+use std::os::raw::c_void;
+use std::panic::{self, AssertUnwindSafe};
+
+struct Call<F, R> {
+    f: Option<F>,
+    result: Option<std::thread::Result<R>>,
+}
+
+unsafe extern "C" fn call<F: FnOnce() -> R, R>(data: *mut c_void) -> *mut c_void {
+    let call = &mut *(data as *mut Call<F, R>);
+    if let Some(f) = call.f.take() {
+        call.result = Some(panic::catch_unwind(AssertUnwindSafe(f)));
+    }
+    std::ptr::null_mut()
+}
+
+/// Calls `f` after releasing the GVL, so that other Ruby threads can run while it does.
+/// `f` must not interact with Ruby in any way, including reading Ruby objects or allocating new
+/// ones, which means any data it needs from Ruby must be copied beforehand. It can allocate Rust
+/// memory, as `TrackingAllocator` defers reporting it to the Ruby GC until the GVL is reacquired.
+pub fn without_gvl<F: FnOnce() -> R, R>(f: F) -> R {
+    let mut data = Call {
+        f: Some(f),
+        result: None,
+    };
+
+    TrackingAllocator::defer_reporting();
+    // unlike `rb_thread_call_without_gvl`, this never checks for interrupts after `f` returns,
+    // which could raise and skip the destructors of this frame
+    unsafe {
+        rb_thread_call_without_gvl2(
+            Some(call::<F, R>),
+            &mut data as *mut Call<F, R> as *mut c_void,
+            None,
+            std::ptr::null_mut(),
+        );
+    }
+    TrackingAllocator::resume_reporting();
+
+    // a panic cannot unwind through Ruby's C frames, so it is resumed once the GVL is reacquired
+    match (data.result, data.f) {
+        (Some(Ok(result)), _) => result,
+        (Some(Err(panic)), _) => panic::resume_unwind(panic),
+        // `f` is not called when the thread has pending interrupts, so call it with the GVL held
+        (None, Some(f)) => f(),
+        (None, None) => unreachable!(),
+    }
+}
@@ -1,10 +1,19 @@
+use helpers::TrackingAllocator;
 use magnus::Error;
 
+mod analysis;
+mod codegen;
 mod execution;
 mod helpers;
+mod printing;
 mod ruby_api;
+mod schema_index;
+mod validation;
 mod visibility_scoped;
 
+#[global_allocator]
+static ALLOCATOR: TrackingAllocator = TrackingAllocator;
+
 #[magnus::init]
 fn init() -> Result<(), Error> {
     ruby_api::init()
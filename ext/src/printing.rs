@@ -0,0 +1,3 @@
+mod executable_document_printer;
+
+pub use executable_document_printer::{ExecutableDocumentPrinter, PrintOptions};
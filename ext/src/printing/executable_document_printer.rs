@@ -0,0 +1,497 @@
+use crate::helpers::write_json_string;
+use bluejay_core::executable::{
+    OperationDefinition as CoreOperationDefinition, Selection as CoreSelection,
+    VariableType as CoreVariableType, VariableTypeReference,
+};
+use bluejay_core::{
+    AsIter, Directive as CoreDirective, ObjectValue, OperationType, Value as CoreValue,
+    ValueReference, Variable,
+};
+use bluejay_parser::ast::executable::{
+    ExecutableDocument, FragmentDefinition, OperationDefinition, Selection, SelectionSet,
+    VariableType,
+};
+use bluejay_parser::ast::{Arguments, Directive, VariableArguments, VariableValue};
+use itertools::Itertools;
+use magnus::{exception, Error};
+use std::collections::HashSet;
+
+#[derive(Debug, Default)]
+pub struct PrintOptions {
+    /// Removes all ignored tokens that are not needed to separate other tokens
+    pub minify: bool,
+    /// The names of the operations to leave out of the printed document
+    pub removed_operations: Vec<String>,
+    /// Replaces fragment spreads with inline fragments, leaving out the fragment definitions
+    pub inline_fragments: bool,
+    /// Leaves out the fragment definitions and variable definitions that are not used by any
+    /// printed operation
+    pub remove_unused: bool,
+}
+
+/// The variables and fragments used by an operation, including through fragment spreads
+#[derive(Default)]
+struct Usages<'a> {
+    variables: HashSet<&'a str>,
+    fragments: HashSet<&'a str>,
+}
+
+/// Prints an executable document, optionally transforming it according to [`PrintOptions`].
+pub struct ExecutableDocumentPrinter<'a> {
+    document: &'a ExecutableDocument<'a>,
+    options: &'a PrintOptions,
+    buf: String,
+    indentation: usize,
+}
+
+impl<'a> ExecutableDocumentPrinter<'a> {
+    pub fn print(query: &str, options: &PrintOptions) -> Result<String, Error> {
+        let document = ExecutableDocument::parse(query).map_err(|errors| {
+            Error::new(
+                exception::arg_error(),
+                format!(
+                    "Document could not be parsed: {}",
+                    errors.iter().map(|error| error.message()).join(", "),
+                ),
+            )
+        })?;
+
+        let mut printer = ExecutableDocumentPrinter {
+            document: &document,
+            options,
+            buf: String::new(),
+            indentation: 0,
+        };
+        printer.document();
+
+        Ok(printer.buf)
+    }
+
+    fn document(&mut self) {
+        let operation_definitions: Vec<(&'a OperationDefinition<'a>, Usages<'a>)> = self
+            .document
+            .operation_definitions()
+            .iter()
+            .filter(|od| {
+                !matches!(
+                    od.as_ref().name(),
+                    Some(name) if self.options.removed_operations.iter().any(|removed| removed == name)
+                )
+            })
+            .map(|od| (od, self.usages(od)))
+            .collect();
+
+        let fragment_definitions: Vec<&'a FragmentDefinition<'a>> = if self.options.inline_fragments
+        {
+            Vec::new()
+        } else if self.options.remove_unused {
+            self.document
+                .fragment_definitions()
+                .iter()
+                .filter(|fd| {
+                    operation_definitions
+                        .iter()
+                        .any(|(_, usages)| usages.fragments.contains(fd.name().as_ref()))
+                })
+                .collect()
+        } else {
+            self.document.fragment_definitions().iter().collect()
+        };
+
+        for (idx, (operation_definition, usages)) in operation_definitions.iter().enumerate() {
+            if idx > 0 {
+                self.blank_line();
+            }
+            self.operation_definition(*operation_definition, usages);
+        }
+
+        for (idx, fragment_definition) in fragment_definitions.into_iter().enumerate() {
+            if idx > 0 || !operation_definitions.is_empty() {
+                self.blank_line();
+            }
+            self.fragment_definition(fragment_definition);
+        }
+    }
+
+    fn operation_definition(
+        &mut self,
+        operation_definition: &'a OperationDefinition<'a>,
+        usages: &Usages<'a>,
+    ) {
+        let operation_definition_reference = operation_definition.as_ref();
+        let variable_definitions: Vec<_> = operation_definition_reference
+            .variable_definitions()
+            .into_iter()
+            .flat_map(AsIter::iter)
+            .filter(|variable_definition| {
+                !self.options.remove_unused
+                    || usages
+                        .variables
+                        .contains(variable_definition.variable().name())
+            })
+            .collect();
+        let directives: Vec<&'a Directive<'a, false>> = operation_definition_reference
+            .directives()
+            .into_iter()
+            .flat_map(AsIter::iter)
+            .collect();
+        let operation_type = operation_definition_reference.operation_type();
+        let name = operation_definition_reference.name();
+
+        // the shorthand form is only valid for an anonymous query without variables or directives
+        let is_shorthand = matches!(operation_type, OperationType::Query)
+            && name.is_none()
+            && variable_definitions.is_empty()
+            && directives.is_empty();
+
+        if !is_shorthand {
+            self.write(match operation_type {
+                OperationType::Query => "query",
+                OperationType::Mutation => "mutation",
+                OperationType::Subscription => "subscription",
+            });
+
+            if let Some(name) = name {
+                self.space();
+                self.write(name);
+            }
+
+            if !variable_definitions.is_empty() {
+                self.write("(");
+                for (idx, variable_definition) in variable_definitions.into_iter().enumerate() {
+                    if idx > 0 {
+                        self.list_separator();
+                    }
+                    self.write("$");
+                    self.write(variable_definition.variable().name());
+                    self.write(":");
+                    self.space();
+                    self.variable_type(variable_definition.r#type());
+                    if let Some(default_value) = variable_definition.default_value() {
+                        self.space();
+                        self.write("=");
+                        self.space();
+                        self.value(default_value);
+                    }
+                    self.directives(
+                        variable_definition
+                            .directives()
+                            .into_iter()
+                            .flat_map(AsIter::iter),
+                    );
+                }
+                self.write(")");
+            }
+
+            self.directives(directives);
+            self.space();
+        }
+
+        self.selection_set(operation_definition.selection_set(), &mut HashSet::new());
+    }
+
+    fn fragment_definition(&mut self, fragment_definition: &'a FragmentDefinition<'a>) {
+        self.write("fragment");
+        self.space();
+        self.write(fragment_definition.name().as_ref());
+        self.type_condition(fragment_definition.type_condition().named_type().as_ref());
+        self.directives(fragment_definition.directives().iter());
+        self.space();
+        self.selection_set(fragment_definition.selection_set(), &mut HashSet::new());
+    }
+
+    fn selection_set(
+        &mut self,
+        selection_set: &'a SelectionSet<'a>,
+        inlined_fragments: &mut HashSet<&'a str>,
+    ) {
+        self.write("{");
+        self.indentation += 1;
+        for selection in selection_set.iter() {
+            self.newline();
+            self.selection(selection, inlined_fragments);
+        }
+        self.indentation -= 1;
+        self.newline();
+        self.write("}");
+    }
+
+    fn selection(
+        &mut self,
+        selection: &'a Selection<'a>,
+        inlined_fragments: &mut HashSet<&'a str>,
+    ) {
+        let directives = selection.as_ref().directives();
+        match selection {
+            Selection::Field(field) => {
+                if let Some(alias) = field.alias() {
+                    self.write(alias.as_ref());
+                    self.write(":");
+                    self.space();
+                }
+                self.write(field.name().as_ref());
+                self.arguments(field.arguments());
+                self.directives(directives.iter());
+                if let Some(selection_set) = field.selection_set() {
+                    self.space();
+                    self.selection_set(selection_set, inlined_fragments);
+                }
+            }
+            Selection::InlineFragment(inline_fragment) => {
+                self.write("...");
+                if let Some(type_condition) = inline_fragment.type_condition() {
+                    self.type_condition(type_condition.named_type().as_ref());
+                }
+                self.directives(directives.iter());
+                self.space();
+                self.selection_set(inline_fragment.selection_set(), inlined_fragments);
+            }
+            Selection::FragmentSpread(fragment_spread) => {
+                let fragment_name = fragment_spread.name().as_ref();
+                let fragment_definition = self
+                    .document
+                    .fragment_definitions()
+                    .iter()
+                    .find(|fd| fd.name().as_ref() == fragment_name);
+                match fragment_definition {
+                    // guards against cycles, which only exist in invalid documents
+                    Some(fragment_definition)
+                        if self.options.inline_fragments
+                            && inlined_fragments.insert(fragment_name) =>
+                    {
+                        self.write("...");
+                        self.type_condition(
+                            fragment_definition.type_condition().named_type().as_ref(),
+                        );
+                        // the directives of the fragment definition apply to its selections wherever
+                        // it is spread, so they are kept on the inline fragment after those of the spread
+                        self.directives(
+                            directives
+                                .iter()
+                                .chain(fragment_definition.directives().iter()),
+                        );
+                        self.space();
+                        self.selection_set(fragment_definition.selection_set(), inlined_fragments);
+                        inlined_fragments.remove(fragment_name);
+                    }
+                    _ => {
+                        self.write("...");
+                        self.write(fragment_name);
+                        self.directives(directives.iter());
+                    }
+                }
+            }
+        }
+    }
+
+    fn type_condition(&mut self, named_type: &str) {
+        self.space();
+        self.write("on");
+        self.space();
+        self.write(named_type);
+    }
+
+    fn arguments<const CONST: bool>(&mut self, arguments: Option<&Arguments<CONST>>) {
+        let mut arguments = arguments.into_iter().flat_map(AsIter::iter).peekable();
+        if arguments.peek().is_none() {
+            return;
+        }
+        self.write("(");
+        for (idx, argument) in arguments.enumerate() {
+            if idx > 0 {
+                self.list_separator();
+            }
+            self.write(argument.name().as_ref());
+            self.write(":");
+            self.space();
+            self.value(argument.value());
+        }
+        self.write(")");
+    }
+
+    fn directives<'b, const CONST: bool>(
+        &mut self,
+        directives: impl IntoIterator<Item = &'b Directive<'b, CONST>>,
+    ) {
+        for directive in directives {
+            self.space();
+            self.write("@");
+            self.write(directive.name().as_ref());
+            self.arguments(directive.arguments());
+        }
+    }
+
+    fn value<const CONST: bool>(&mut self, value: &impl CoreValue<CONST>) {
+        match value.as_ref() {
+            ValueReference::Variable(var) => {
+                self.write("$");
+                self.write(var.name());
+            }
+            ValueReference::Integer(i) => self.write(&i.to_string()),
+            // the `Debug` implementation always includes a fractional part or an exponent, so the
+            // value is not read back as an integer
+            ValueReference::Float(f) => self.write(&format!("{f:?}")),
+            ValueReference::String(s) => {
+                let mut string = String::new();
+                write_json_string(&mut string, s);
+                self.write(&string);
+            }
+            ValueReference::Boolean(b) => self.write(if b { "true" } else { "false" }),
+            ValueReference::Null => self.write("null"),
+            ValueReference::Enum(e) => self.write(e),
+            ValueReference::List(l) => {
+                self.write("[");
+                for (idx, item) in l.iter().enumerate() {
+                    if idx > 0 {
+                        self.list_separator();
+                    }
+                    self.value(item);
+                }
+                self.write("]");
+            }
+            ValueReference::Object(o) => {
+                self.write("{");
+                for (idx, (key, value)) in o.iter().enumerate() {
+                    if idx > 0 {
+                        self.list_separator();
+                    }
+                    self.write(key.as_ref());
+                    self.write(":");
+                    self.space();
+                    self.value(value);
+                }
+                self.write("}");
+            }
+        }
+    }
+
+    fn variable_type(&mut self, variable_type: &VariableType) {
+        match variable_type.as_ref() {
+            VariableTypeReference::Named(_, required) => {
+                self.write(variable_type.as_ref().name());
+                if required {
+                    self.write("!");
+                }
+            }
+            VariableTypeReference::List(inner, required) => {
+                self.write("[");
+                self.variable_type(inner);
+                self.write("]");
+                if required {
+                    self.write("!");
+                }
+            }
+        }
+    }
+
+    /// Appends `s`, separating it from the previous token with a space when minifying if both
+    /// are names or numbers, which would otherwise be read as a single token
+    fn write(&mut self, s: &str) {
+        let is_name_char = |c: char| c.is_ascii_alphanumeric() || c == '_';
+        if self.options.minify
+            && self.buf.ends_with(is_name_char)
+            && s.starts_with(|c: char| is_name_char(c) || c == '-')
+        {
+            self.buf.push(' ');
+        }
+        self.buf.push_str(s);
+    }
+
+    fn space(&mut self) {
+        if !self.options.minify {
+            self.buf.push(' ');
+        }
+    }
+
+    fn list_separator(&mut self) {
+        self.buf.push(',');
+        self.space();
+    }
+
+    fn newline(&mut self) {
+        if !self.options.minify {
+            self.buf.push('\n');
+            self.buf.push_str(&"  ".repeat(self.indentation));
+        }
+    }
+
+    fn blank_line(&mut self) {
+        if !self.options.minify {
+            self.buf.push_str("\n\n");
+        }
+    }
+
+    fn usages(&self, operation_definition: &'a OperationDefinition<'a>) -> Usages<'a> {
+        let mut usages = Usages::default();
+        operation_definition
+            .as_ref()
+            .directives()
+            .into_iter()
+            .flat_map(AsIter::iter)
+            .for_each(|directive| Self::argument_usages(directive.arguments(), &mut usages));
+        self.selection_set_usages(operation_definition.selection_set(), &mut usages);
+        usages
+    }
+
+    fn selection_set_usages(&self, selection_set: &'a SelectionSet<'a>, usages: &mut Usages<'a>) {
+        for selection in selection_set.iter() {
+            selection
+                .as_ref()
+                .directives()
+                .iter()
+                .for_each(|directive| Self::argument_usages(directive.arguments(), usages));
+
+            match selection {
+                Selection::Field(field) => {
+                    Self::argument_usages(field.arguments(), usages);
+                    if let Some(selection_set) = field.selection_set() {
+                        self.selection_set_usages(selection_set, usages);
+                    }
+                }
+                Selection::InlineFragment(inline_fragment) => {
+                    self.selection_set_usages(inline_fragment.selection_set(), usages);
+                }
+                Selection::FragmentSpread(fragment_spread) => {
+                    let fragment_name = fragment_spread.name().as_ref();
+                    if !usages.fragments.insert(fragment_name) {
+                        continue;
+                    }
+                    let fragment_definition = self
+                        .document
+                        .fragment_definitions()
+                        .iter()
+                        .find(|fd| fd.name().as_ref() == fragment_name);
+                    if let Some(fragment_definition) = fragment_definition {
+                        fragment_definition
+                            .directives()
+                            .iter()
+                            .for_each(|directive| {
+                                Self::argument_usages(directive.arguments(), usages)
+                            });
+                        self.selection_set_usages(fragment_definition.selection_set(), usages);
+                    }
+                }
+            }
+        }
+    }
+
+    fn argument_usages(arguments: Option<&'a VariableArguments<'a>>, usages: &mut Usages<'a>) {
+        arguments
+            .into_iter()
+            .flat_map(AsIter::iter)
+            .for_each(|argument| Self::value_usages(argument.value(), usages));
+    }
+
+    fn value_usages(value: &'a VariableValue<'a>, usages: &mut Usages<'a>) {
+        match value.as_ref() {
+            ValueReference::Variable(var) => {
+                usages.variables.insert(var.name());
+            }
+            ValueReference::List(l) => l.iter().for_each(|item| Self::value_usages(item, usages)),
+            ValueReference::Object(o) => o
+                .iter()
+                .for_each(|(_, value)| Self::value_usages(value, usages)),
+            _ => {}
+        }
+    }
+}
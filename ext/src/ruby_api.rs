@@ -1,9 +1,15 @@
+use crate::analysis::OperationNormalizer;
+use crate::codegen::SchemaClassesGenerator;
+use crate::helpers::without_gvl;
+use crate::printing::{ExecutableDocumentPrinter, PrintOptions};
 use magnus::{define_module, function, memoize, Error, Module, RModule};
 
+mod absent_behavior;
 mod arguments_definition;
 mod coerce_input;
 mod coercion_error;
 mod custom_scalar_type_definition;
+mod deprecated_usage;
 mod directive;
 mod directive_definition;
 mod directive_location;
@@ -24,12 +30,15 @@ mod interface_implementation;
 mod interface_implementations;
 mod interface_type_definition;
 mod introspection;
+mod lookahead;
+mod native_scalar;
 mod object_type_definition;
 mod output_type;
 mod r_result;
 mod scalar;
 mod schema_definition;
 mod type_definition;
+mod type_system_error;
 mod union_member_type;
 mod union_member_types;
 mod union_type_definition;
@@ -37,10 +46,12 @@ mod validation_error;
 mod visibility;
 mod wrapped_value;
 
+pub use absent_behavior::AbsentBehavior;
 pub use arguments_definition::ArgumentsDefinition;
 pub use coerce_input::CoerceInput;
-pub use coercion_error::CoercionError;
+pub use coercion_error::{CoercionError, ReceivedValue};
 pub use custom_scalar_type_definition::CustomScalarTypeDefinition;
+pub use deprecated_usage::{DeprecatedUsage, DeprecatedUsageKind};
 pub use directive::Directive;
 pub use directive_definition::DirectiveDefinition;
 pub use directive_location::DirectiveLocation;
@@ -48,10 +59,10 @@ pub use directives::Directives;
 pub use enum_type_definition::EnumTypeDefinition;
 pub use enum_value_definition::EnumValueDefinition;
 pub use enum_value_definitions::EnumValueDefinitions;
-pub use errors::{base_error, non_unique_definition_name_error};
-pub use execution_error::ExecutionError;
+pub use errors::{base_error, non_unique_definition_name_error, type_system_validation_error};
+pub use execution_error::{ExecutionError, ExtensionValue};
 pub use execution_result::ExecutionResult;
-pub use field_definition::{ExtraResolverArg, FieldDefinition};
+pub use field_definition::{ExtraResolverArg, FieldDefinition, FieldResolver};
 pub use fields_definition::FieldsDefinition;
 pub use input_fields_definition::InputFieldsDefinition;
 pub use input_object_type_definition::InputObjectTypeDefinition;
@@ -60,12 +71,15 @@ pub use input_value_definition::InputValueDefinition;
 pub use interface_implementation::InterfaceImplementation;
 pub use interface_implementations::InterfaceImplementations;
 pub use interface_type_definition::InterfaceTypeDefinition;
+pub use lookahead::Lookahead;
+pub use native_scalar::NativeScalar;
 pub use object_type_definition::ObjectTypeDefinition;
 pub use output_type::{BaseOutputType, OutputType};
 pub use r_result::RResult;
 pub use scalar::Scalar;
 pub use schema_definition::SchemaDefinition;
 pub use type_definition::TypeDefinition;
+pub use type_system_error::{TypeSystemError, TypeSystemErrorKind};
 pub use union_member_type::UnionMemberType;
 pub use union_member_types::UnionMemberTypes;
 pub use union_type_definition::UnionTypeDefinition;
@@ -90,6 +104,7 @@ pub fn init() -> Result<(), Error> {
 
     coercion_error::init()?;
     custom_scalar_type_definition::init()?;
+    deprecated_usage::init()?;
     directive_definition::init()?;
     directive_location::init()?;
     enum_type_definition::init()?;
@@ -102,27 +117,72 @@ pub fn init() -> Result<(), Error> {
     input_value_definition::init()?;
     interface_implementation::init()?;
     interface_type_definition::init()?;
+    lookahead::init()?;
+    native_scalar::init()?;
     object_type_definition::init()?;
     output_type::init()?;
     r_result::init()?;
     scalar::init()?;
     schema_definition::init()?;
+    type_system_error::init()?;
     union_member_type::init()?;
     union_type_definition::init()?;
     validation_error::init()?;
+    r.define_module_function(
+        "generate_schema_classes",
+        function!(
+            |sdl: String, namespace: Option<String>| {
+                SchemaClassesGenerator::generate(sdl.as_str(), namespace.as_deref())
+            },
+            2
+        ),
+    )?;
     r.define_module_function(
         "parse",
         function!(
             |s: String| {
-                bluejay_parser::ast::executable::ExecutableDocument::parse(s.as_str())
-                    .map(|doc| {
-                        (doc.operation_definitions().len() + doc.fragment_definitions().len()) > 0
-                    })
-                    .unwrap_or(false)
+                without_gvl(|| {
+                    bluejay_parser::ast::executable::ExecutableDocument::parse(s.as_str())
+                        .map(|doc| {
+                            (doc.operation_definitions().len() + doc.fragment_definitions().len())
+                                > 0
+                        })
+                        .unwrap_or(false)
+                })
             },
             1
         ),
     )?;
+    r.define_module_function(
+        "operation_signature",
+        function!(
+            |query: String, operation_name: Option<String>| {
+                OperationNormalizer::signature(query.as_str(), operation_name.as_deref())
+            },
+            2
+        ),
+    )?;
+    r.define_module_function(
+        "print_executable_document",
+        function!(
+            |query: String,
+             minify: bool,
+             removed_operations: Vec<String>,
+             inline_fragments: bool,
+             remove_unused: bool| {
+                ExecutableDocumentPrinter::print(
+                    query.as_str(),
+                    &PrintOptions {
+                        minify,
+                        removed_operations,
+                        inline_fragments,
+                        remove_unused,
+                    },
+                )
+            },
+            5
+        ),
+    )?;
 
     Ok(())
 }
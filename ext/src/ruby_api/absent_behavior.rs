@@ -0,0 +1,51 @@
+use crate::ruby_api::root;
+use magnus::{exception, memoize, Error, Module, RHash, RModule, Symbol, TryConvert, Value, QNIL};
+
+/// Controls what a resolver or input object constructor receives for an optional
+/// input value that the client did not provide at all, as opposed to an explicit `null`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum AbsentBehavior {
+    #[default]
+    Nil,
+    Omit,
+    Undefined,
+}
+
+impl AbsentBehavior {
+    pub(crate) fn apply(self, args: RHash, key: Symbol) -> Result<(), Error> {
+        match self {
+            Self::Nil => args.aset(key, *QNIL),
+            Self::Omit => Ok(()),
+            Self::Undefined => args.aset(key, undefined()),
+        }
+    }
+
+    pub(crate) fn to_symbol(self) -> Symbol {
+        match self {
+            Self::Nil => *memoize!(Symbol: Symbol::new("nil")),
+            Self::Omit => *memoize!(Symbol: Symbol::new("omit")),
+            Self::Undefined => *memoize!(Symbol: Symbol::new("undefined")),
+        }
+    }
+}
+
+impl TryConvert for AbsentBehavior {
+    fn try_convert(val: Value) -> Result<Self, Error> {
+        let symbol: Symbol = val.try_convert()?;
+        match symbol.name()?.as_ref() {
+            "nil" => Ok(Self::Nil),
+            "omit" => Ok(Self::Omit),
+            "undefined" => Ok(Self::Undefined),
+            other => Err(Error::new(
+                exception::arg_error(),
+                format!(
+                    "Unknown absent behavior `{other}`, expected one of :nil, :omit or :undefined"
+                ),
+            )),
+        }
+    }
+}
+
+fn undefined() -> RModule {
+    *memoize!(RModule: root().const_get("Undefined").unwrap())
+}
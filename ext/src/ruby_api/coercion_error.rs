@@ -1,21 +1,154 @@
-use crate::ruby_api::{root, ExecutionError};
+use crate::helpers::{rhash_with_capacity, Variables};
+use crate::ruby_api::{root, ExecutionError, ExtensionValue};
+use bluejay_core::{AsIter, ObjectValue, Value as CoreValue, ValueReference, Variable};
 use magnus::{
-    function, method,
+    function, gc, method,
     rb_sys::AsRawValue,
+    scan_args::{get_kwargs, scan_args, KwArgs},
     typed_data::{self, Obj},
-    Error, Module, Object, RArray,
+    DataTypeFunctions, Error, Module, Object, RArray, RHash, RString, TypedData, Value, QNIL,
 };
 
-#[derive(Clone, Debug, PartialEq, Eq)]
-#[magnus::wrap(class = "Bluejay::CoercionError", mark)]
+/// The value received by the input position an error describes. Values from Ruby are owned by
+/// the input being coerced, while literals from a document are kept as Rust values until they
+/// are read, so an error does not hold Ruby objects that nothing else keeps alive.
+#[derive(Clone, Debug)]
+pub enum ReceivedValue {
+    Ruby(Value),
+    Null,
+    Boolean(bool),
+    Integer(i32),
+    Float(f64),
+    String(String),
+    Enum(String),
+    List(Vec<ReceivedValue>),
+    Object(Vec<(String, ReceivedValue)>),
+}
+
+impl ReceivedValue {
+    pub fn from_core_value<const CONST: bool>(
+        value: &impl CoreValue<CONST>,
+        variables: &impl Variables<CONST>,
+    ) -> Self {
+        match value.as_ref() {
+            ValueReference::Boolean(b) => Self::Boolean(b),
+            ValueReference::Enum(e) => Self::Enum(e.to_owned()),
+            ValueReference::Float(f) => Self::Float(f),
+            ValueReference::Integer(i) => Self::Integer(i),
+            ValueReference::Null => Self::Null,
+            ValueReference::String(s) => Self::String(s.to_owned()),
+            ValueReference::Variable(var) => {
+                variables.get(var.name()).map_or(Self::Null, Self::Ruby)
+            }
+            ValueReference::List(l) => Self::List(
+                l.iter()
+                    .map(|v| Self::from_core_value(v, variables))
+                    .collect(),
+            ),
+            ValueReference::Object(o) => Self::Object(
+                o.iter()
+                    .map(|(k, v)| (k.as_ref().to_owned(), Self::from_core_value(v, variables)))
+                    .collect(),
+            ),
+        }
+    }
+
+    fn to_value(&self) -> Value {
+        match self {
+            Self::Ruby(value) => *value,
+            Self::Null => *QNIL,
+            Self::Boolean(b) => (*b).into(),
+            Self::Integer(i) => (*i).into(),
+            Self::Float(f) => (*f).into(),
+            Self::String(s) | Self::Enum(s) => *RString::new(s),
+            Self::List(l) => *RArray::from_iter(l.iter().map(Self::to_value)),
+            Self::Object(o) => *RHash::from_iter(o.iter().map(|(k, v)| (k.as_str(), v.to_value()))),
+        }
+    }
+
+    fn mark(&self) {
+        match self {
+            Self::Ruby(value) => gc::mark(*value),
+            Self::List(l) => l.iter().for_each(Self::mark),
+            Self::Object(o) => o.iter().for_each(|(_, v)| v.mark()),
+            _ => {}
+        }
+    }
+}
+
+#[derive(Clone, Debug, TypedData)]
+#[magnus(class = "Bluejay::CoercionError", mark)]
 pub struct CoercionError {
     message: String,
     path: Vec<String>,
+    expected_type: Option<String>,
+    value: Option<ReceivedValue>,
+    field_path: Option<Vec<String>>,
+    argument_name: Option<String>,
+    sealed: bool,
 }
 
 impl CoercionError {
     pub fn new(message: String, path: Vec<String>) -> Self {
-        Self { message, path }
+        Self {
+            message,
+            path,
+            expected_type: None,
+            value: None,
+            field_path: None,
+            argument_name: None,
+            sealed: false,
+        }
+    }
+
+    fn rb_new(args: &[Value]) -> Result<Self, Error> {
+        let args = scan_args::<(String, Vec<String>), (), (), (), Option<RHash>, ()>(args)?;
+        let (message, path) = args.required;
+        let kwargs: KwArgs<(), (Option<Option<String>>, Option<Value>), ()> = get_kwargs(
+            args.keywords.unwrap_or_else(RHash::new),
+            &[],
+            &["expected_type", "value"],
+        )?;
+        let (expected_type, value) = kwargs.optional;
+        Ok(Self {
+            expected_type: expected_type.flatten(),
+            value: value.map(ReceivedValue::Ruby),
+            ..Self::new(message, path)
+        })
+    }
+
+    /// Records the expected type and the received value. Every wrapper of an input type annotates
+    /// again, so the outermost one wins and errors carry the full type of the variable, argument
+    /// or input field they belong to, e.g. `[Int!]!` rather than `Int!`.
+    pub(crate) fn annotate(
+        errors: &mut [Self],
+        expected_type: impl Fn() -> String,
+        value: impl Fn() -> ReceivedValue,
+    ) {
+        errors
+            .iter_mut()
+            .filter(|error| !error.sealed)
+            .for_each(|error| {
+                error.expected_type = Some(expected_type());
+                error.value = Some(value());
+            });
+    }
+
+    /// Stops enclosing input positions from replacing the annotation, used once the errors of an
+    /// input object field have been annotated with the field's type.
+    pub(crate) fn seal(self) -> Self {
+        Self {
+            sealed: true,
+            ..self
+        }
+    }
+
+    pub(crate) fn with_argument(self, field_path: Vec<String>, argument_name: &str) -> Self {
+        Self {
+            field_path: Some(field_path),
+            argument_name: Some(argument_name.to_owned()),
+            ..self
+        }
     }
 
     pub fn message(&self) -> &str {
@@ -26,30 +159,98 @@ impl CoercionError {
         RArray::from_iter(self.path.iter().map(|s| s.as_str()))
     }
 
+    pub fn expected_type(&self) -> Option<&str> {
+        self.expected_type.as_deref()
+    }
+
+    pub fn value(&self) -> Value {
+        self.value.as_ref().map_or(*QNIL, ReceivedValue::to_value)
+    }
+
+    fn extension_entries(&self) -> Vec<(&'static str, ExtensionValue)> {
+        let mut entries = Vec::with_capacity(3);
+        if let Some(expected_type) = &self.expected_type {
+            entries.push((
+                "expectedType",
+                ExtensionValue::String(expected_type.clone()),
+            ));
+        }
+        if let Some(field_path) = &self.field_path {
+            entries.push(("fieldPath", ExtensionValue::List(field_path.clone())));
+        }
+        if let Some(argument_name) = &self.argument_name {
+            entries.push((
+                "argumentName",
+                ExtensionValue::String(argument_name.clone()),
+            ));
+        }
+        entries
+    }
+
+    fn extensions(&self) -> Result<RHash, Error> {
+        let entries = self.extension_entries();
+        let ruby_h = rhash_with_capacity(entries.len());
+        entries
+            .into_iter()
+            .try_for_each(|(key, value)| ruby_h.aset(key, value.to_value()))?;
+        Ok(ruby_h)
+    }
+
     fn inspect(rb_self: Obj<Self>) -> Result<String, Error> {
         let rs_self = rb_self.get();
 
         Ok(format!(
-            "#<Bluejay::CoercionError:0x{:016x} @message={:?} @path={:?}>",
+            "#<Bluejay::CoercionError:0x{:016x} @message={:?} @path={:?} @expected_type={:?}>",
             rb_self.as_raw(),
             rs_self.message,
             rs_self.path,
+            rs_self.expected_type,
         ))
     }
 }
 
+impl PartialEq for CoercionError {
+    fn eq(&self, other: &Self) -> bool {
+        self.message == other.message
+            && self.path == other.path
+            && self.expected_type == other.expected_type
+            // compared as Ruby values, so a literal equals the same value given from Ruby
+            && self.value().eql(&other.value()).unwrap_or(false)
+            && self.field_path == other.field_path
+            && self.argument_name == other.argument_name
+    }
+}
+
+impl Eq for CoercionError {}
+
+impl DataTypeFunctions for CoercionError {
+    fn mark(&self) {
+        if let Some(value) = &self.value {
+            value.mark();
+        }
+    }
+}
+
 impl From<CoercionError> for ExecutionError {
     fn from(val: CoercionError) -> Self {
-        ExecutionError::new(val.message, None)
+        let entries = val.extension_entries();
+        if entries.is_empty() {
+            ExecutionError::new(val.message, None)
+        } else {
+            ExecutionError::new(val.message, None).with_extensions(entries)
+        }
     }
 }
 
 pub fn init() -> Result<(), Error> {
     let class = root().define_class("CoercionError", Default::default())?;
 
-    class.define_singleton_method("new", function!(CoercionError::new, 2))?;
+    class.define_singleton_method("new", function!(CoercionError::rb_new, -1))?;
     class.define_method("message", method!(CoercionError::message, 0))?;
     class.define_method("path", method!(CoercionError::path, 0))?;
+    class.define_method("expected_type", method!(CoercionError::expected_type, 0))?;
+    class.define_method("value", method!(CoercionError::value, 0))?;
+    class.define_method("extensions", method!(CoercionError::extensions, 0))?;
     class.define_method(
         "==",
         method!(<CoercionError as typed_data::IsEql>::is_eql, 1),
@@ -1,9 +1,9 @@
-use crate::execution::{CoerceResult, FieldError};
+use crate::execution::{CoerceResult, FieldError, ResultCoercionOptions};
 use crate::helpers::{value_from_core_value, HasDefinitionWrapper, NewInstanceKw, Variables};
 use crate::ruby_api::{
     base, introspection, root, wrapped_value::value_inner_from_ruby_const_value, CoerceInput,
-    CoercionError, DirectiveDefinition, Directives, HasVisibility, RResult, Visibility,
-    WrappedValue,
+    CoercionError, DirectiveDefinition, Directives, HasVisibility, NativeScalar, RResult,
+    Visibility, WrappedValue,
 };
 use crate::visibility_scoped::ScopedScalarTypeDefinition;
 use bluejay_parser::ast::Value as ParserValue;
@@ -25,12 +25,24 @@ pub struct CustomScalarTypeDefinition {
     internal_representation_sorbet_type_name: String,
     input_coercion_method_signature: CoercionMethodSignature,
     result_coercion_method_signature: CoercionMethodSignature,
+    native_scalar: Option<NativeScalar>,
     visibility: Option<Visibility>,
 }
 
 impl CustomScalarTypeDefinition {
     fn new(kw: RHash) -> Result<Self, Error> {
-        let args: KwArgs<_, (), ()> = get_kwargs(
+        type RequiredArgs = (
+            String,
+            Option<String>,
+            RArray,
+            Option<String>,
+            RClass,
+            String,
+            Obj<CoercionMethodSignature>,
+            Obj<CoercionMethodSignature>,
+            Option<Visibility>,
+        );
+        let args: KwArgs<RequiredArgs, (Option<Option<NativeScalar>>,), ()> = get_kwargs(
             kw,
             &[
                 "name",
@@ -43,19 +55,8 @@ impl CustomScalarTypeDefinition {
                 "result_coercion_method_signature",
                 "visibility",
             ],
-            &[],
+            &["native_scalar"],
         )?;
-        type RequiredArgs = (
-            String,
-            Option<String>,
-            RArray,
-            Option<String>,
-            RClass,
-            String,
-            Obj<CoercionMethodSignature>,
-            Obj<CoercionMethodSignature>,
-            Option<Visibility>,
-        );
         let (
             name,
             description,
@@ -66,7 +67,8 @@ impl CustomScalarTypeDefinition {
             input_coercion_method_signature,
             result_coercion_method_signature,
             visibility,
-        ): RequiredArgs = args.required;
+        ) = args.required;
+        let (native_scalar,) = args.optional;
         if let Some(specified_by_url) = specified_by_url.as_deref() {
             let directive_definition = DirectiveDefinition::specified_by();
             let args = RHash::from_iter([(
@@ -91,6 +93,7 @@ impl CustomScalarTypeDefinition {
             internal_representation_sorbet_type_name,
             input_coercion_method_signature: input_coercion_method_signature.get().clone(),
             result_coercion_method_signature: result_coercion_method_signature.get().clone(),
+            native_scalar: native_scalar.flatten(),
             visibility,
         })
     }
@@ -115,11 +118,37 @@ impl CustomScalarTypeDefinition {
         self.specified_by_url.as_deref()
     }
 
+    pub(crate) fn ruby_class_name(&self) -> String {
+        self.ruby_class.to_string()
+    }
+
+    /// Whether `coerce_input` returns a `Result` rather than raising
+    pub(crate) fn coerce_input_returns_result(&self) -> bool {
+        matches!(
+            self.input_coercion_method_signature,
+            CoercionMethodSignature::Result
+        )
+    }
+
+    /// Whether `coerce_result` returns a `Result` rather than raising
+    pub(crate) fn coerce_result_returns_result(&self) -> bool {
+        matches!(
+            self.result_coercion_method_signature,
+            CoercionMethodSignature::Result
+        )
+    }
+
     fn coerce_input(
         &self,
         value: Value,
         path: Path,
     ) -> Result<Result<Value, Vec<CoercionError>>, Error> {
+        if let Some(native_scalar) = self.native_scalar {
+            return native_scalar.coerce_input(value).map(|coerced_result| {
+                coerced_result.map_err(|message| vec![CoercionError::new(message, path.to_vec())])
+            });
+        }
+
         match self
             .ruby_class
             .funcall::<_, _, Value>(*memoize!(Id: Id::new("coerce_input")), (value,))
@@ -136,9 +165,7 @@ impl CustomScalarTypeDefinition {
                 }
                 CoercionMethodSignature::Exception(_) => Ok(Ok(value)),
             },
-            Err(error) if matches!(self.input_coercion_method_signature, CoercionMethodSignature::Exception(cls) if error.is_kind_of(cls)) =>
-            {
-                // TODO: ensure proper message formatting
+            Err(error) if self.input_coercion_method_signature.rescues(&error) => {
                 Ok(Err(vec![CoercionError::new(
                     error.to_string(),
                     path.to_vec(),
@@ -153,6 +180,8 @@ impl DataTypeFunctions for CustomScalarTypeDefinition {
     fn mark(&self) {
         self.directives.mark();
         gc::mark(self.ruby_class);
+        self.input_coercion_method_signature.mark();
+        self.result_coercion_method_signature.mark();
         self.visibility.as_ref().map(Visibility::mark);
     }
 }
@@ -199,30 +228,41 @@ impl<'a> CoerceInput for ScopedScalarTypeDefinition<'a> {
 }
 
 impl<'a> CoerceResult for ScopedScalarTypeDefinition<'a> {
-    fn coerce_result(&self, value: Value) -> Result<Value, FieldError> {
-        match self
-            .inner()
+    fn coerce_result(&self, value: Value, _: &ResultCoercionOptions) -> Result<Value, FieldError> {
+        let custom_scalar_type_definition = self.inner();
+        let coercion_error = |message| FieldError::CannotCoerceResultToCustomScalar {
+            scalar_name: custom_scalar_type_definition.name.clone(),
+            message,
+        };
+
+        if let Some(native_scalar) = custom_scalar_type_definition.native_scalar {
+            return native_scalar
+                .coerce_result(value)
+                .map_err(|error| FieldError::ApplicationError(error.to_string()))?
+                .map_err(coercion_error);
+        }
+
+        match custom_scalar_type_definition
             .ruby_class
             .funcall::<_, _, Value>(*memoize!(Id: Id::new("coerce_result")), (value,))
         {
-            Ok(value) => match self.inner().result_coercion_method_signature {
+            Ok(value) => match custom_scalar_type_definition.result_coercion_method_signature {
                 CoercionMethodSignature::Result => {
                     let coerced_result: Result<Value, String> = value
                         .try_convert()
                         .and_then(|r_result: Obj<RResult>| Result::try_from(r_result.get()))
                         .map_err(|error| FieldError::ApplicationError(error.to_string()))?;
 
-                    coerced_result
-                        .map_err(|message| FieldError::CannotCoerceResultToCustomScalar { message })
+                    coerced_result.map_err(coercion_error)
                 }
                 CoercionMethodSignature::Exception(_) => Ok(value),
             },
-            Err(error) if matches!(self.inner().result_coercion_method_signature, CoercionMethodSignature::Exception(cls) if error.is_kind_of(cls)) =>
+            Err(error)
+                if custom_scalar_type_definition
+                    .result_coercion_method_signature
+                    .rescues(&error) =>
             {
-                // TODO: ensure proper message formatting
-                Err(FieldError::CannotCoerceResultToCustomScalar {
-                    message: error.to_string(),
-                })
+                Err(coercion_error(error.to_string()))
             }
             Err(error) => Err(FieldError::ApplicationError(error.to_string())),
         }
@@ -282,6 +322,18 @@ impl CoercionMethodSignature {
     fn exception(exception_class: ExceptionClass) -> Self {
         Self::Exception(exception_class)
     }
+
+    /// Whether `error` raised by a coercion method is the expected way of signalling that a value
+    /// cannot be coerced, as opposed to an unexpected error
+    fn rescues(&self, error: &Error) -> bool {
+        matches!(self, Self::Exception(exception_class) if error.is_kind_of(*exception_class))
+    }
+
+    fn mark(&self) {
+        if let Self::Exception(exception_class) = self {
+            gc::mark(*exception_class);
+        }
+    }
 }
 
 pub fn init() -> Result<(), Error> {
@@ -0,0 +1,132 @@
+use super::root;
+use crate::helpers::rhash_with_capacity;
+use magnus::{
+    method,
+    rb_sys::AsRawValue,
+    typed_data::{self, Obj},
+    Error, Module, RHash, Symbol,
+};
+use strum::IntoStaticStr;
+
+#[derive(IntoStaticStr, Clone, Copy, Debug, PartialEq, Eq)]
+#[strum(serialize_all = "snake_case")]
+pub enum DeprecatedUsageKind {
+    Field,
+    Argument,
+    InputField,
+    EnumValue,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[magnus::wrap(class = "Bluejay::DeprecatedUsage")]
+pub struct DeprecatedUsage {
+    kind: DeprecatedUsageKind,
+    coordinate: String,
+    reason: String,
+    path: Vec<String>,
+    argument_path: Option<Vec<String>>,
+    line: usize,
+    column: usize,
+}
+
+impl DeprecatedUsage {
+    pub fn new(
+        kind: DeprecatedUsageKind,
+        coordinate: String,
+        reason: String,
+        path: Vec<String>,
+        argument_path: Option<Vec<String>>,
+        line: usize,
+        column: usize,
+    ) -> Self {
+        Self {
+            kind,
+            coordinate,
+            reason,
+            path,
+            argument_path,
+            line,
+            column,
+        }
+    }
+
+    fn kind(&self) -> Symbol {
+        let kind: &'static str = self.kind.into();
+        Symbol::new(kind)
+    }
+
+    fn coordinate(&self) -> &str {
+        self.coordinate.as_str()
+    }
+
+    fn reason(&self) -> &str {
+        self.reason.as_str()
+    }
+
+    fn path(&self) -> Vec<String> {
+        self.path.clone()
+    }
+
+    fn argument_path(&self) -> Option<Vec<String>> {
+        self.argument_path.clone()
+    }
+
+    fn line(&self) -> usize {
+        self.line
+    }
+
+    fn column(&self) -> usize {
+        self.column
+    }
+
+    fn to_h(&self) -> Result<RHash, Error> {
+        let location = rhash_with_capacity(2);
+        location.aset("line", self.line)?;
+        location.aset("column", self.column)?;
+
+        let ruby_h = rhash_with_capacity(6);
+        ruby_h.aset("kind", self.kind())?;
+        ruby_h.aset("coordinate", self.coordinate())?;
+        ruby_h.aset("reason", self.reason())?;
+        ruby_h.aset("path", self.path())?;
+        ruby_h.aset("argument_path", self.argument_path())?;
+        ruby_h.aset("location", location)?;
+        Ok(ruby_h)
+    }
+
+    fn inspect(rb_self: Obj<Self>) -> Result<String, Error> {
+        let rs_self = rb_self.get();
+
+        Ok(format!(
+            "#<Bluejay::DeprecatedUsage:0x{:016x} @kind={:?} @coordinate={:?} @reason={:?} @path={:?} @argument_path={:?} @line={} @column={}>",
+            rb_self.as_raw(),
+            rs_self.kind(),
+            rs_self.coordinate,
+            rs_self.reason,
+            rs_self.path,
+            rs_self.argument_path,
+            rs_self.line,
+            rs_self.column,
+        ))
+    }
+}
+
+pub fn init() -> Result<(), Error> {
+    let class = root().define_class("DeprecatedUsage", Default::default())?;
+
+    class.define_method("kind", method!(DeprecatedUsage::kind, 0))?;
+    class.define_method("coordinate", method!(DeprecatedUsage::coordinate, 0))?;
+    class.define_method("reason", method!(DeprecatedUsage::reason, 0))?;
+    class.define_method("path", method!(DeprecatedUsage::path, 0))?;
+    class.define_method("argument_path", method!(DeprecatedUsage::argument_path, 0))?;
+    class.define_method("line", method!(DeprecatedUsage::line, 0))?;
+    class.define_method("column", method!(DeprecatedUsage::column, 0))?;
+    class.define_method(
+        "==",
+        method!(<DeprecatedUsage as typed_data::IsEql>::is_eql, 1),
+    )?;
+    class.define_method("inspect", method!(DeprecatedUsage::inspect, 0))?;
+    class.define_method("to_h", method!(DeprecatedUsage::to_h, 0))?;
+
+    Ok(())
+}
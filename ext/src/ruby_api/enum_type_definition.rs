@@ -1,8 +1,12 @@
-use crate::execution::{CoerceResult, FieldError};
+use crate::execution::{CoerceResult, FieldError, ResultCoercionOptions};
 use crate::helpers::{public_name, HasDefinitionWrapper, Variables};
 use crate::ruby_api::{
-    base, coerce_input::CoerceInput, coercion_error::CoercionError,
-    enum_value_definitions::EnumValueDefinitions, introspection, root, wrapped_value::ValueInner,
+    base,
+    coerce_input::CoerceInput,
+    coercion_error::{CoercionError, ReceivedValue},
+    enum_value_definitions::EnumValueDefinitions,
+    introspection, root,
+    wrapped_value::ValueInner,
     Directives, HasVisibility, Visibility, WrappedValue,
 };
 use crate::visibility_scoped::ScopedEnumTypeDefinition;
@@ -104,6 +108,19 @@ fn coerce_from_name(
     }
 }
 
+/// Enum errors are annotated where they are created, so they carry the enum's name and the
+/// received value even when coerced outside of a wrapping input type.
+fn annotate<T>(
+    etd: &ScopedEnumTypeDefinition,
+    result: Result<T, Vec<CoercionError>>,
+    value: impl Fn() -> ReceivedValue,
+) -> Result<T, Vec<CoercionError>> {
+    result.map_err(|mut errors| {
+        CoercionError::annotate(&mut errors, || etd.name().to_owned(), value);
+        errors
+    })
+}
+
 impl<'a> CoerceInput for ScopedEnumTypeDefinition<'a> {
     fn coerced_ruby_value_to_wrapped_value(
         &self,
@@ -111,7 +128,7 @@ impl<'a> CoerceInput for ScopedEnumTypeDefinition<'a> {
         path: Path,
     ) -> Result<Result<WrappedValue, Vec<CoercionError>>, Error> {
         let s: Result<String, _> = value.try_convert();
-        match s {
+        let result = match s {
             Ok(s) => {
                 if self
                     .enum_value_definitions()
@@ -119,39 +136,45 @@ impl<'a> CoerceInput for ScopedEnumTypeDefinition<'a> {
                     .any(|evd| evd.name() == s.as_str())
                 {
                     let inner = ValueInner::Enum(s);
-                    Ok(Ok((value, inner).into()))
+                    Ok((value, inner).into())
                 } else {
-                    Ok(Err(vec![CoercionError::new(
+                    Err(vec![CoercionError::new(
                         format!("No member `{}` on {}", s.as_str(), self.name()),
                         path.to_vec(),
-                    )]))
+                    )])
                 }
             }
-            Err(_) => Ok(Err(vec![CoercionError::new(
+            Err(_) => Err(vec![CoercionError::new(
                 format!(
                     "No implicit conversion of {} to {}",
                     public_name(value),
                     self.name()
                 ),
                 path.to_vec(),
-            )])),
-        }
+            )]),
+        };
+
+        Ok(annotate(self, result, || ReceivedValue::Ruby(value)))
     }
 
     fn coerce_parser_value<const CONST: bool>(
         &self,
         value: &ParserValue<CONST>,
         path: Path,
-        _: &impl Variables<CONST>,
+        variables: &impl Variables<CONST>,
     ) -> Result<Result<Value, Vec<CoercionError>>, Error> {
-        if let ParserValue::Enum(e) = value {
-            Ok(coerce_from_name(self, e.as_str(), path))
+        let result = if let ParserValue::Enum(e) = value {
+            coerce_from_name(self, e.as_str(), path)
         } else {
-            Ok(Err(vec![CoercionError::new(
+            Err(vec![CoercionError::new(
                 format!("No implicit conversion of {} to {}", value, self.name()),
                 path.to_vec(),
-            )]))
-        }
+            )])
+        };
+
+        Ok(annotate(self, result, || {
+            ReceivedValue::from_core_value(value, variables)
+        }))
     }
 
     fn coerce_ruby_const_value(
@@ -160,17 +183,19 @@ impl<'a> CoerceInput for ScopedEnumTypeDefinition<'a> {
         path: Path,
     ) -> Result<Result<Value, Vec<CoercionError>>, Error> {
         let s: Result<String, _> = value.try_convert();
-        match s {
-            Ok(s) => Ok(coerce_from_name(self, s.as_str(), path)),
-            Err(_) => Ok(Err(vec![CoercionError::new(
+        let result = match s {
+            Ok(s) => coerce_from_name(self, s.as_str(), path),
+            Err(_) => Err(vec![CoercionError::new(
                 format!(
                     "No implicit conversion of {} to {}",
                     public_name(value),
                     self.name()
                 ),
                 path.to_vec(),
-            )])),
-        }
+            )]),
+        };
+
+        Ok(annotate(self, result, || ReceivedValue::Ruby(value)))
     }
 }
 
@@ -208,7 +233,7 @@ impl bluejay_core::definition::EnumTypeDefinition for EnumTypeDefinition {
 }
 
 impl<'a> CoerceResult for ScopedEnumTypeDefinition<'a> {
-    fn coerce_result(&self, value: Value) -> Result<Value, FieldError> {
+    fn coerce_result(&self, value: Value, _: &ResultCoercionOptions) -> Result<Value, FieldError> {
         if value
             .try_convert()
             .ok()
@@ -12,3 +12,7 @@ pub fn non_unique_definition_name_error() -> ExceptionClass {
 pub fn default_value_error() -> ExceptionClass {
     *memoize!(ExceptionClass: errors().define_error("DefaultValueError", base_error()).unwrap())
 }
+
+pub fn type_system_validation_error() -> ExceptionClass {
+    *memoize!(ExceptionClass: errors().define_error("TypeSystemValidationError", base_error()).unwrap())
+}
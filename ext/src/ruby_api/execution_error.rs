@@ -2,19 +2,44 @@ use crate::helpers::rhash_with_capacity;
 
 use super::root;
 use magnus::{
-    function, method,
+    function, gc, method,
     rb_sys::AsRawValue,
     scan_args::scan_args,
     typed_data::{self, Obj},
-    Error, Module, Object, RHash, Value,
+    DataTypeFunctions, Error, Module, Object, RArray, RHash, RString, TypedData, Value,
 };
 use std::borrow::Cow;
 
-#[derive(Clone, Debug, PartialEq, Eq)]
-#[magnus::wrap(class = "Bluejay::ExecutionError")]
+#[derive(Clone, Debug, TypedData)]
+#[magnus(class = "Bluejay::ExecutionError", mark)]
 pub struct ExecutionError {
     message: Cow<'static, str>,
     path: Option<Vec<String>>,
+    locations: Option<Vec<(usize, usize)>>,
+    extensions: Option<Extensions>,
+}
+
+#[derive(Clone, Debug)]
+enum Extensions {
+    /// Extensions given from Ruby, kept alive by the wrapping object
+    Ruby(RHash),
+    /// Extensions built by Bluejay, only converted to a hash when read
+    Native(Vec<(&'static str, ExtensionValue)>),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ExtensionValue {
+    String(String),
+    List(Vec<String>),
+}
+
+impl ExtensionValue {
+    pub(crate) fn to_value(&self) -> Value {
+        match self {
+            Self::String(s) => *RString::new(s),
+            Self::List(l) => *RArray::from_iter(l.iter().map(|s| s.as_str())),
+        }
+    }
 }
 
 impl ExecutionError {
@@ -22,14 +47,35 @@ impl ExecutionError {
         Self {
             message: message.into(),
             path,
+            locations: None,
+            extensions: None,
+        }
+    }
+
+    /// `locations` are 1-based line and column pairs
+    pub fn with_locations(self, locations: Vec<(usize, usize)>) -> Self {
+        Self {
+            locations: Some(locations),
+            ..self
+        }
+    }
+
+    pub fn with_extensions(self, extensions: Vec<(&'static str, ExtensionValue)>) -> Self {
+        Self {
+            extensions: Some(Extensions::Native(extensions)),
+            ..self
         }
     }
 
     fn rb_new(args: &[Value]) -> Result<Self, Error> {
-        let args = scan_args::<(String,), (Option<Vec<String>>,), (), (), (), ()>(args)?;
+        let args =
+            scan_args::<(String,), (Option<Vec<String>>, Option<RHash>), (), (), (), ()>(args)?;
         let (message,) = args.required;
-        let (path,) = args.optional;
-        Ok(Self::new(message, path))
+        let (path, extensions) = args.optional;
+        Ok(Self {
+            extensions: extensions.map(Extensions::Ruby),
+            ..Self::new(message, path)
+        })
     }
 
     pub fn message(&self) -> &str {
@@ -41,10 +87,44 @@ impl ExecutionError {
         self.path.clone()
     }
 
+    pub fn locations(&self) -> Option<&[(usize, usize)]> {
+        self.locations.as_deref()
+    }
+
+    pub fn extensions(&self) -> Option<RHash> {
+        self.extensions.as_ref().map(|extensions| match extensions {
+            Extensions::Ruby(extensions) => *extensions,
+            Extensions::Native(entries) => {
+                let extensions = rhash_with_capacity(entries.len());
+                entries.iter().for_each(|(key, value)| {
+                    extensions.aset(*key, value.to_value()).unwrap();
+                });
+                extensions
+            }
+        })
+    }
+
+    fn rb_locations(&self) -> Option<RArray> {
+        self.locations.as_ref().map(|locations| {
+            RArray::from_iter(locations.iter().map(|&(line, column)| {
+                let location = rhash_with_capacity(2);
+                location.aset("line", line).unwrap();
+                location.aset("column", column).unwrap();
+                location
+            }))
+        })
+    }
+
     fn to_h(&self) -> Result<RHash, Error> {
-        let ruby_h = rhash_with_capacity(2);
+        let ruby_h = rhash_with_capacity(4);
         ruby_h.aset("path", self.path())?;
         ruby_h.aset("message", self.message())?;
+        if let Some(locations) = self.rb_locations() {
+            ruby_h.aset("locations", locations)?;
+        }
+        if let Some(extensions) = self.extensions() {
+            ruby_h.aset("extensions", extensions)?;
+        }
         Ok(ruby_h)
     }
 
@@ -60,12 +140,30 @@ impl ExecutionError {
     }
 }
 
+impl PartialEq for ExecutionError {
+    fn eq(&self, other: &Self) -> bool {
+        self.message == other.message && self.path == other.path
+    }
+}
+
+impl Eq for ExecutionError {}
+
+impl DataTypeFunctions for ExecutionError {
+    fn mark(&self) {
+        if let Some(Extensions::Ruby(extensions)) = &self.extensions {
+            gc::mark(*extensions);
+        }
+    }
+}
+
 pub fn init() -> Result<(), Error> {
     let class = root().define_class("ExecutionError", Default::default())?;
 
     class.define_singleton_method("new", function!(ExecutionError::rb_new, -1))?;
     class.define_method("message", method!(ExecutionError::message, 0))?;
     class.define_method("path", method!(ExecutionError::path, 0))?;
+    class.define_method("locations", method!(ExecutionError::rb_locations, 0))?;
+    class.define_method("extensions", method!(ExecutionError::extensions, 0))?;
     class.define_method(
         "==",
         method!(<ExecutionError as typed_data::IsEql>::is_eql, 1),
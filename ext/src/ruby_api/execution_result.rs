@@ -1,21 +1,43 @@
 use super::root;
 use super::ExecutionError;
-use crate::helpers::TypedFrozenRArray;
+use crate::helpers::{write_json_string, write_json_value, TypedFrozenRArray};
 use magnus::{
-    gc, method, typed_data::Obj, DataTypeFunctions, Error, Module, RArray, TypedData, Value,
+    function, gc, method, typed_data::Obj, DataTypeFunctions, Error, Module, Object, RArray, RHash,
+    TypedData, Value,
 };
+use std::fmt::Write;
 
 #[derive(Clone, Debug, TypedData)]
 #[magnus(class = "Bluejay::ExecutionResult", mark)]
 pub struct ExecutionResult {
     value: Value,
     errors: TypedFrozenRArray<Obj<ExecutionError>>,
+    /// `false` when the request failed before execution started, in which case the response
+    /// has no `data` entry
+    has_data: bool,
+    extensions: Option<RHash>,
 }
 
 impl ExecutionResult {
     pub fn new(value: Value, errors: impl IntoIterator<Item = impl Into<ExecutionError>>) -> Self {
         let errors = TypedFrozenRArray::from_iter(errors.into_iter().map(Into::into));
-        Self { value, errors }
+        Self {
+            value,
+            errors,
+            has_data: true,
+            extensions: None,
+        }
+    }
+
+    pub fn request_error(errors: impl IntoIterator<Item = impl Into<ExecutionError>>) -> Self {
+        Self {
+            has_data: false,
+            ..Self::new(Default::default(), errors)
+        }
+    }
+
+    fn rb_request_error(errors: Vec<Obj<ExecutionError>>) -> Self {
+        Self::request_error(errors.into_iter().map(|error| error.get().clone()))
     }
 
     fn value(&self) -> Value {
@@ -25,19 +47,124 @@ impl ExecutionResult {
     fn errors(&self) -> RArray {
         self.errors.into()
     }
+
+    fn extensions(&self) -> Option<RHash> {
+        self.extensions
+    }
+
+    fn with_extensions(&self, extensions: RHash) -> Self {
+        Self {
+            extensions: Some(extensions),
+            ..self.clone()
+        }
+    }
+
+    /// Serializes the result as a response following the
+    /// [specification](https://spec.graphql.org/October2021/#sec-Response-Format). Any arguments
+    /// are ignored, so that it can be called by `JSON.generate`.
+    fn to_json(&self, _args: &[Value]) -> Result<String, Error> {
+        let mut buf = String::new();
+        buf.push('{');
+
+        let mut has_entries = false;
+        if self.has_data {
+            buf.push_str("\"data\":");
+            write_json_value(&mut buf, self.value)?;
+            has_entries = true;
+        }
+
+        if !self.errors.is_empty() {
+            if has_entries {
+                buf.push(',');
+            }
+            buf.push_str("\"errors\":[");
+            for (idx, error) in self.errors.iter().enumerate() {
+                if idx > 0 {
+                    buf.push(',');
+                }
+                Self::write_error(&mut buf, error)?;
+            }
+            buf.push(']');
+            has_entries = true;
+        }
+
+        if let Some(extensions) = self.extensions {
+            if has_entries {
+                buf.push(',');
+            }
+            buf.push_str("\"extensions\":");
+            write_json_value(&mut buf, *extensions)?;
+        }
+
+        buf.push('}');
+        Ok(buf)
+    }
+
+    fn write_error(buf: &mut String, error: &ExecutionError) -> Result<(), Error> {
+        buf.push_str("{\"message\":");
+        write_json_string(buf, error.message());
+
+        if let Some(locations) = error.locations() {
+            buf.push_str(",\"locations\":[");
+            for (idx, (line, column)) in locations.iter().enumerate() {
+                if idx > 0 {
+                    buf.push(',');
+                }
+                write!(buf, "{{\"line\":{line},\"column\":{column}}}").unwrap();
+            }
+            buf.push(']');
+        }
+
+        if let Some(path) = error.path() {
+            buf.push_str(",\"path\":[");
+            for (idx, segment) in path.iter().enumerate() {
+                if idx > 0 {
+                    buf.push(',');
+                }
+                // names cannot start with a digit, so a segment of only digits is a list index
+                if !segment.is_empty() && segment.bytes().all(|b| b.is_ascii_digit()) {
+                    buf.push_str(segment);
+                } else {
+                    write_json_string(buf, segment);
+                }
+            }
+            buf.push(']');
+        }
+
+        if let Some(extensions) = error.extensions() {
+            buf.push_str(",\"extensions\":");
+            write_json_value(buf, *extensions)?;
+        }
+
+        buf.push('}');
+        Ok(())
+    }
 }
 
 impl DataTypeFunctions for ExecutionResult {
     fn mark(&self) {
         gc::mark(&self.value);
         gc::mark(self.errors);
+        if let Some(extensions) = self.extensions {
+            gc::mark(extensions);
+        }
     }
 }
 
 pub fn init() -> Result<(), Error> {
     let class = root().define_class("ExecutionResult", Default::default())?;
+    class.define_singleton_method(
+        "request_error",
+        function!(ExecutionResult::rb_request_error, 1),
+    )?;
     class.define_method("value", method!(ExecutionResult::value, 0))?;
     class.define_method("errors", method!(ExecutionResult::errors, 0))?;
+    class.define_method("extensions", method!(ExecutionResult::extensions, 0))?;
+    class.define_method(
+        "with_extensions",
+        method!(ExecutionResult::with_extensions, 1),
+    )?;
+    class.define_method("to_json", method!(ExecutionResult::to_json, -1))?;
 
     Ok(())
 }
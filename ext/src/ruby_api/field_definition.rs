@@ -1,15 +1,16 @@
-use crate::helpers::NewInstanceKw;
+use crate::helpers::{FuncallKw, NewInstanceKw};
 use crate::ruby_api::{
     root, ArgumentsDefinition, DirectiveDefinition, Directives, HasVisibility, OutputType,
     Visibility,
 };
 use convert_case::{Case, Casing};
 use magnus::{
-    function, gc, memoize, method,
+    exception, function, gc, memoize, method,
     scan_args::{get_kwargs, KwArgs},
     typed_data::Obj,
     value::Id,
-    DataTypeFunctions, Error, Module, Object, RArray, RHash, RString, Symbol, TypedData,
+    Class, DataTypeFunctions, Error, Module, Object, RArray, RClass, RHash, RString, Symbol,
+    TryConvert, TypedData, Value, QNIL,
 };
 
 #[derive(Debug, TypedData)]
@@ -23,6 +24,7 @@ pub struct FieldDefinition {
     is_builtin: bool,
     ruby_resolver_method_name: String,
     ruby_resolver_method_id: Id,
+    resolver: Option<FieldResolver>,
     name_r_string: RString,
     extra_resolver_args: Vec<ExtraResolverArg>,
     deprecation_reason: Option<String>,
@@ -39,6 +41,8 @@ impl FieldDefinition {
                 "description",
                 "directives",
                 "resolver_method_name",
+                "resolver",
+                "extra_resolver_args",
                 "deprecation_reason",
                 "visibility",
             ],
@@ -49,6 +53,8 @@ impl FieldDefinition {
             Option<Option<String>>,
             Option<RArray>,
             Option<Option<String>>,
+            Option<Option<FieldResolver>>,
+            Option<Vec<Symbol>>,
             Option<Option<String>>,
             Option<Option<Visibility>>,
         );
@@ -57,6 +63,8 @@ impl FieldDefinition {
             description,
             directives,
             resolver_method_name,
+            resolver,
+            extra_resolver_args,
             deprecation_reason,
             visibility,
         ): OptionalArgs = args.optional;
@@ -88,7 +96,11 @@ impl FieldDefinition {
         let ruby_resolver_method_id = Id::new(ruby_resolver_method_name.as_str());
         let extra_resolver_args = match name.as_str() {
             "__schema" | "__type" => vec![ExtraResolverArg::SchemaClass],
-            _ => vec![],
+            _ => extra_resolver_args
+                .unwrap_or_default()
+                .into_iter()
+                .map(ExtraResolverArg::from_symbol)
+                .collect::<Result<_, _>>()?,
         };
         Ok(Self {
             name,
@@ -99,6 +111,7 @@ impl FieldDefinition {
             is_builtin,
             ruby_resolver_method_name,
             ruby_resolver_method_id,
+            resolver: resolver.flatten(),
             name_r_string,
             extra_resolver_args,
             deprecation_reason,
@@ -114,6 +127,10 @@ impl FieldDefinition {
         self.ruby_resolver_method_id
     }
 
+    pub(crate) fn resolver(&self) -> Option<&FieldResolver> {
+        self.resolver.as_ref()
+    }
+
     pub(crate) fn name_r_string(&self) -> RString {
         self.name_r_string
     }
@@ -161,6 +178,7 @@ impl DataTypeFunctions for FieldDefinition {
         gc::mark(self.r#type);
         self.directives.mark();
         gc::mark(self.name_r_string);
+        self.resolver.as_ref().map(FieldResolver::mark);
         self.visibility.as_ref().map(Visibility::mark);
     }
 }
@@ -201,15 +219,115 @@ impl HasVisibility for FieldDefinition {
     }
 }
 
-#[derive(Debug)]
+/// Resolves the value of a field from its parent object, instead of calling the field's resolver method on the parent
+#[derive(Clone, Copy, Debug)]
+pub enum FieldResolver {
+    /// Instantiated with the parent object every time the field is resolved, and then `resolve` is
+    /// called on the instance with the arguments
+    Class(RClass),
+    /// Called with the parent object and the arguments
+    Callable(Value),
+}
+
+impl FieldResolver {
+    pub(crate) fn resolve(
+        &self,
+        object_value: Value,
+        kwargs: Option<RHash>,
+    ) -> Result<Value, Error> {
+        match self {
+            Self::Class(class) => {
+                let resolver: Value = class.new_instance((object_value,))?;
+                let method = *memoize!(Id: Id::new("resolve"));
+                match kwargs {
+                    Some(kwargs) => resolver.funcall_kw(method, kwargs),
+                    None => resolver.funcall(method, ()),
+                }
+            }
+            Self::Callable(callable) => {
+                let method = *memoize!(Id: Id::new("call"));
+                match kwargs {
+                    Some(kwargs) => callable.funcall_with_kw(method, &[object_value], kwargs),
+                    None => callable.funcall(method, (object_value,)),
+                }
+            }
+        }
+    }
+
+    fn value(&self) -> Value {
+        match self {
+            Self::Class(class) => **class,
+            Self::Callable(callable) => *callable,
+        }
+    }
+
+    fn mark(&self) {
+        gc::mark(self.value());
+    }
+}
+
+impl TryConvert for FieldResolver {
+    fn try_convert(val: Value) -> Result<Self, Error> {
+        if let Some(class) = RClass::from_value(val) {
+            Ok(Self::Class(class))
+        } else if val.respond_to("call", false)? {
+            Ok(Self::Callable(val))
+        } else {
+            Err(Error::new(
+                exception::type_error(),
+                format!(
+                    "expected a class or an object responding to `call`, got {}",
+                    val.class()
+                ),
+            ))
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+#[magnus::wrap(class = "Bluejay::ExtraResolverArg")]
 pub enum ExtraResolverArg {
     SchemaClass,
+    Context,
+    Path,
+    FieldDefinition,
+    Lookahead,
 }
 
 impl ExtraResolverArg {
+    fn from_symbol(symbol: Symbol) -> Result<Self, Error> {
+        match symbol.name()?.as_ref() {
+            "schema_class" => Ok(Self::SchemaClass),
+            "context" => Ok(Self::Context),
+            "path" => Ok(Self::Path),
+            "field_definition" => Ok(Self::FieldDefinition),
+            "lookahead" => Ok(Self::Lookahead),
+            other => Err(Error::new(
+                exception::arg_error(),
+                format!(
+                    "Unknown extra resolver arg `{other}`, expected one of :schema_class, :context, :path, :field_definition or :lookahead"
+                ),
+            )),
+        }
+    }
+
     pub(crate) fn kwarg_name(&self) -> Symbol {
         match self {
             Self::SchemaClass => *memoize!(Symbol: Symbol::new("schema_class")),
+            Self::Context => *memoize!(Symbol: Symbol::new("context")),
+            Self::Path => *memoize!(Symbol: Symbol::new("path")),
+            Self::FieldDefinition => *memoize!(Symbol: Symbol::new("field_definition")),
+            Self::Lookahead => *memoize!(Symbol: Symbol::new("lookahead")),
+        }
+    }
+
+    fn sorbet_type(&self) -> &'static str {
+        match self {
+            Self::SchemaClass => "T.class_of(Bluejay::Schema)",
+            Self::Context => "T.untyped",
+            Self::Path => "T::Array[String]",
+            Self::FieldDefinition => "Bluejay::FieldDefinition",
+            Self::Lookahead => "Bluejay::Lookahead",
         }
     }
 }
@@ -223,6 +341,13 @@ pub fn init() -> Result<(), Error> {
         "resolver_method_name",
         method!(FieldDefinition::ruby_resolver_method_name, 0),
     )?;
+    class.define_method(
+        "resolver",
+        method!(
+            |fd: &FieldDefinition| fd.resolver.map_or(*QNIL, |resolver| resolver.value()),
+            0
+        ),
+    )?;
     class.define_method(
         "argument_definitions",
         method!(
@@ -245,6 +370,13 @@ pub fn init() -> Result<(), Error> {
             0
         ),
     )?;
+    class.define_method(
+        "extra_resolver_args",
+        method!(
+            |fd: &FieldDefinition| RArray::from_iter(fd.extra_resolver_args.iter().copied()),
+            0
+        ),
+    )?;
     class.define_method("deprecated?", method!(FieldDefinition::is_deprecated, 0))?;
     class.define_method(
         "deprecation_reason",
@@ -256,5 +388,12 @@ pub fn init() -> Result<(), Error> {
         method!(|_: &FieldDefinition| "__Field", 0),
     )?;
 
+    let extra_resolver_arg_class = root().define_class("ExtraResolverArg", Default::default())?;
+
+    extra_resolver_arg_class
+        .define_method("kwarg_name", method!(ExtraResolverArg::kwarg_name, 0))?;
+    extra_resolver_arg_class
+        .define_method("sorbet_type", method!(ExtraResolverArg::sorbet_type, 0))?;
+
     Ok(())
 }
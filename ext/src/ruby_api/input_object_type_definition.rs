@@ -2,9 +2,9 @@ use crate::helpers::{
     public_name, rhash_with_capacity, HasDefinitionWrapper, NewInstanceKw, Variables, Warden,
 };
 use crate::ruby_api::{
-    base, introspection, root, wrapped_value::value_inner_from_ruby_const_value, CoerceInput,
-    CoercionError, Directives, HasVisibility, InputFieldsDefinition, RResult, Visibility,
-    WrappedValue,
+    base, introspection, root, wrapped_value::value_inner_from_ruby_const_value, AbsentBehavior,
+    CoerceInput, CoercionError, Directives, HasVisibility, InputFieldsDefinition,
+    InputValueDefinition, RResult, ReceivedValue, Visibility, WrappedValue,
 };
 use crate::visibility_scoped::{ScopedInputObjectTypeDefinition, VisibilityCache};
 use bluejay_core::{definition::prelude::*, AsIter};
@@ -13,7 +13,6 @@ use bluejay_validator::Path;
 use magnus::{
     function, gc, memoize, method, r_hash::ForEach, scan_args::get_kwargs, scan_args::KwArgs,
     DataTypeFunctions, Error, Module, Object, RArray, RClass, RHash, RModule, TypedData, Value,
-    QNIL,
 };
 
 #[derive(Debug, TypedData)]
@@ -25,6 +24,7 @@ pub struct InputObjectTypeDefinition {
     directives: Directives,
     ruby_class: RClass,
     visibility: Option<Visibility>,
+    absent_field_behavior: AbsentBehavior,
 }
 
 impl InputObjectTypeDefinition {
@@ -38,16 +38,26 @@ impl InputObjectTypeDefinition {
                 "directives",
                 "ruby_class",
                 "visibility",
+                "absent_field_behavior",
             ],
             &[],
         )?;
-        let (name, input_fields_definition, description, directives, ruby_class, visibility): (
+        let (
+            name,
+            input_fields_definition,
+            description,
+            directives,
+            ruby_class,
+            visibility,
+            absent_field_behavior,
+        ): (
             String,
             InputFieldsDefinition,
             Option<String>,
             RArray,
             RClass,
             Option<Visibility>,
+            AbsentBehavior,
         ) = args.required;
         let directives = directives.try_into()?;
         Ok(Self {
@@ -57,6 +67,7 @@ impl InputObjectTypeDefinition {
             directives,
             ruby_class,
             visibility,
+            absent_field_behavior,
         })
     }
 
@@ -75,6 +86,10 @@ impl InputObjectTypeDefinition {
     pub fn directives(&self) -> &Directives {
         &self.directives
     }
+
+    fn absent_behavior_for(&self, ivd: &InputValueDefinition) -> AbsentBehavior {
+        ivd.absent_behavior().unwrap_or(self.absent_field_behavior)
+    }
 }
 
 impl DataTypeFunctions for InputObjectTypeDefinition {
@@ -136,21 +151,26 @@ impl<'a> CoerceInput for ScopedInputObjectTypeDefinition<'a> {
                         path.to_vec(),
                     ));
                 } else {
-                    match default_value {
-                        Some(default_value) if value.is_none() => {
+                    match (value, default_value) {
+                        (None, Some(default_value)) => {
                             args.aset(key, default_value.to_value()).unwrap();
                         }
-                        _ => {
+                        (None, None) => {
+                            self.inner()
+                                .absent_behavior_for(ivd.inner())
+                                .apply(args, key)?;
+                        }
+                        (Some(value), _) => {
                             let inner_path = path.push(ivd.name());
-                            match ivd.r#type().coerced_ruby_value_to_wrapped_value(
-                                value.unwrap_or(*QNIL),
-                                inner_path,
-                            )? {
+                            match ivd
+                                .r#type()
+                                .coerced_ruby_value_to_wrapped_value(value, inner_path)?
+                            {
                                 Ok(coerced_value) => {
                                     args.aset(key, coerced_value.to_value()).unwrap();
                                 }
                                 Err(errs) => {
-                                    errors.extend(errs);
+                                    errors.extend(errs.into_iter().map(CoercionError::seal));
                                 }
                             }
                         }
@@ -209,7 +229,12 @@ impl<'a> CoerceInput for ScopedInputObjectTypeDefinition<'a> {
                 let value = o
                     .iter()
                     .find(|(name, _)| ivd.name() == name.as_str())
-                    .map(|(_, value)| value);
+                    .map(|(_, value)| value)
+                    // a variable without a value is treated the same as an absent field
+                    .filter(|value| match value {
+                        ParserValue::Variable(var) => variables.get(var.name()).is_some(),
+                        _ => true,
+                    });
                 let required = ivd.is_required();
                 let default_value = ivd.inner().default_value();
 
@@ -220,6 +245,10 @@ impl<'a> CoerceInput for ScopedInputObjectTypeDefinition<'a> {
                                 format!("No value for required field {}", ivd.name()),
                                 path.to_vec(),
                             ));
+                        } else {
+                            self.inner()
+                                .absent_behavior_for(ivd.inner())
+                                .apply(args, key)?;
                         }
                     }
                     (None, Some(default_value)) => {
@@ -234,7 +263,7 @@ impl<'a> CoerceInput for ScopedInputObjectTypeDefinition<'a> {
                             Ok(coerced_value) => {
                                 args.aset(key, coerced_value).unwrap();
                             }
-                            Err(errs) => errors.extend(errs),
+                            Err(errs) => errors.extend(errs.into_iter().map(CoercionError::seal)),
                         }
                     }
                 }
@@ -289,21 +318,26 @@ impl<'a> CoerceInput for ScopedInputObjectTypeDefinition<'a> {
                         path.to_vec(),
                     ));
                 } else {
-                    match default_value {
-                        Some(default_value) if value.is_none() => {
+                    match (value, default_value) {
+                        (None, Some(default_value)) => {
                             args.aset(key, default_value.to_value()).unwrap();
                         }
-                        _ => {
+                        (None, None) => {
+                            self.inner()
+                                .absent_behavior_for(ivd.inner())
+                                .apply(args, key)?;
+                        }
+                        (Some(value), _) => {
                             let inner_path = path.push(ivd.name());
-                            match ivd.r#type().coerced_ruby_value_to_wrapped_value(
-                                value.unwrap_or(*QNIL),
-                                inner_path,
-                            )? {
+                            match ivd
+                                .r#type()
+                                .coerced_ruby_value_to_wrapped_value(value, inner_path)?
+                            {
                                 Ok(coerced_value) => {
                                     args.aset(key, coerced_value.to_value()).unwrap();
                                 }
                                 Err(errs) => {
-                                    errors.extend(errs);
+                                    errors.extend(errs.into_iter().map(CoercionError::seal));
                                 }
                             }
                         }
@@ -389,7 +423,12 @@ pub fn init() -> Result<(), Error> {
                     .and_then(|result| visibility_cache.warden().to_result().map(|_| result))
                     .map(|result| {
                         result
-                            .map_err(|errors| {
+                            .map_err(|mut errors| {
+                                CoercionError::annotate(
+                                    &mut errors,
+                                    || itd.name().to_owned(),
+                                    || ReceivedValue::Ruby(input),
+                                );
                                 let arr = RArray::from_iter(errors);
                                 let _ = arr.len();
                                 arr
@@ -407,6 +446,13 @@ pub fn init() -> Result<(), Error> {
             0
         ),
     )?;
+    class.define_method(
+        "absent_field_behavior",
+        method!(
+            |itd: &InputObjectTypeDefinition| itd.absent_field_behavior.to_symbol(),
+            0
+        ),
+    )?;
     introspection::implement_type!(InputObjectTypeDefinition, class);
 
     Ok(())
@@ -2,7 +2,7 @@ use crate::helpers::{public_name, RArrayIter, Variables, WrappedDefinition};
 use crate::ruby_api::{
     introspection, root, wrapped_value::ValueInner, CoerceInput, CoercionError,
     CustomScalarTypeDefinition, EnumTypeDefinition, InputFieldsDefinition,
-    InputObjectTypeDefinition, Scalar, WrappedValue,
+    InputObjectTypeDefinition, ReceivedValue, Scalar, WrappedValue,
 };
 use crate::visibility_scoped::{ScopedBaseInputType, ScopedInputType};
 use bluejay_core::definition::{
@@ -448,7 +448,7 @@ impl InputType {
         allow_implicit_list: bool,
     ) -> Result<Result<Value, Vec<CoercionError>>, Error> {
         let required = scoped_self.as_ref().is_required();
-        match value {
+        let result = match value {
             ParserValue::Null(_) if required => Ok(Err(vec![CoercionError::new(
                 "Got null when a non-null value was expected".to_owned(),
                 path.to_vec(),
@@ -466,6 +466,14 @@ impl InputType {
                         path.to_vec(),
                     )])),
                     Some(value) => Ok(Ok(value)),
+                    None if required => Ok(Err(vec![CoercionError::new(
+                        format!(
+                            "No value was provided for ${}, which is invalid for {}",
+                            var.name(),
+                            scoped_self.as_ref().display_name(),
+                        ),
+                        path.to_vec(),
+                    )])),
                     None => Ok(Ok(*QNIL)),
                 }
             }
@@ -511,7 +519,11 @@ impl InputType {
                     }
                 }
             },
-        }
+        };
+
+        Self::annotate_errors(result, scoped_self, || {
+            ReceivedValue::from_core_value(value, variables)
+        })
     }
 
     fn coerce_ruby_const_value(
@@ -520,7 +532,7 @@ impl InputType {
         path: Path,
         allow_implicit_list: bool,
     ) -> Result<Result<Value, Vec<CoercionError>>, Error> {
-        match scoped_self {
+        let result = match scoped_self {
             ScopedInputType::Base(inner, required) => Self::coerce_required_ruby(
                 value,
                 *required,
@@ -571,7 +583,22 @@ impl InputType {
                 },
                 || Ok(*QNIL),
             ),
-        }
+        };
+
+        Self::annotate_errors(result, scoped_self, || ReceivedValue::Ruby(value))
+    }
+
+    fn annotate_errors<T>(
+        result: Result<Result<T, Vec<CoercionError>>, Error>,
+        scoped_self: &ScopedInputType,
+        value: impl Fn() -> ReceivedValue,
+    ) -> Result<Result<T, Vec<CoercionError>>, Error> {
+        result.map(|result| {
+            result.map_err(|mut errors| {
+                CoercionError::annotate(&mut errors, || scoped_self.as_ref().display_name(), value);
+                errors
+            })
+        })
     }
 }
 
@@ -581,7 +608,7 @@ impl<'a> CoerceInput for ScopedInputType<'a> {
         value: Value,
         path: Path,
     ) -> Result<Result<WrappedValue, Vec<CoercionError>>, Error> {
-        match self {
+        let result = match self {
             Self::Base(inner, required) => InputType::coerce_required_ruby(
                 value,
                 *required,
@@ -634,7 +661,9 @@ impl<'a> CoerceInput for ScopedInputType<'a> {
                 },
                 || (*QNIL).try_into(),
             ),
-        }
+        };
+
+        InputType::annotate_errors(result, self, || ReceivedValue::Ruby(value))
     }
 
     fn coerce_parser_value<const CONST: bool>(
@@ -1,7 +1,7 @@
 use crate::helpers::NewInstanceKw;
 use crate::ruby_api::{
-    errors, root, wrapped_value::ValueInner, CoerceInput, DirectiveDefinition, Directives,
-    HasVisibility, InputType, Visibility, WrappedValue,
+    errors, root, wrapped_value::ValueInner, AbsentBehavior, CoerceInput, DirectiveDefinition,
+    Directives, HasVisibility, InputType, Visibility, WrappedValue,
 };
 use crate::visibility_scoped::{ScopedInputType, VisibilityCache};
 use bluejay_printer::value::ValuePrinter;
@@ -25,6 +25,7 @@ pub struct InputValueDefinition {
     name_r_string: RString,
     deprecation_reason: Option<String>,
     visibility: Option<Visibility>,
+    absent_behavior: Option<AbsentBehavior>,
 }
 
 impl InputValueDefinition {
@@ -39,6 +40,7 @@ impl InputValueDefinition {
                 "default_value",
                 "deprecation_reason",
                 "visibility",
+                "absent_behavior",
             ],
         )?;
         let (name, r#type): (String, Obj<InputType>) = args.required;
@@ -49,9 +51,17 @@ impl InputValueDefinition {
             Option<Option<Value>>,
             Option<Option<String>>,
             Option<Option<Visibility>>,
+            Option<Option<AbsentBehavior>>,
         );
-        let (description, directives, ruby_name, default_value, deprecation_reason, visibility): OptionalArgs =
-            args.optional;
+        let (
+            description,
+            directives,
+            ruby_name,
+            default_value,
+            deprecation_reason,
+            visibility,
+            absent_behavior,
+        ): OptionalArgs = args.optional;
         let description = description.unwrap_or_default();
         let directives = directives.unwrap_or_else(RArray::new);
         let deprecation_reason = deprecation_reason.flatten();
@@ -83,6 +93,7 @@ impl InputValueDefinition {
             name_r_string,
             deprecation_reason,
             visibility: visibility.flatten(),
+            absent_behavior: absent_behavior.flatten(),
         })
     }
 
@@ -124,6 +135,14 @@ impl InputValueDefinition {
         &self.directives
     }
 
+    pub fn deprecation_reason(&self) -> Option<&str> {
+        self.deprecation_reason.as_deref()
+    }
+
+    pub fn absent_behavior(&self) -> Option<AbsentBehavior> {
+        self.absent_behavior
+    }
+
     pub fn validate_default_value<'a>(
         &'a self,
         visibility_cache: &'a VisibilityCache<'a>,
@@ -232,8 +251,12 @@ pub fn init() -> Result<(), Error> {
     )?;
     class.define_method(
         "deprecation_reason",
+        method!(|ivd: &InputValueDefinition| ivd.deprecation_reason(), 0),
+    )?;
+    class.define_method(
+        "absent_behavior",
         method!(
-            |ivd: &InputValueDefinition| ivd.deprecation_reason.as_deref(),
+            |ivd: &InputValueDefinition| ivd.absent_behavior().map(AbsentBehavior::to_symbol),
             0
         ),
     )?;
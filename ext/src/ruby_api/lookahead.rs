@@ -0,0 +1,105 @@
+use super::{base_error, root};
+use crate::helpers::RArrayIter;
+use convert_case::{Case, Casing};
+use magnus::{
+    gc, method, typed_data::Obj, DataTypeFunctions, Error, Module, RArray, RHash, Symbol,
+    TryConvert, TypedData, Value,
+};
+
+/// The selections made on a field, with fragments resolved against the field's output type and
+/// the fields with the same response key merged
+#[derive(Debug, TypedData)]
+#[magnus(class = "Bluejay::Lookahead", mark)]
+pub struct Lookahead {
+    name: String,
+    response_key: String,
+    /// The coerced arguments, or the messages of the errors coercing them
+    arguments: Result<RHash, Vec<String>>,
+    /// A frozen array of the `Lookahead`s of the selections
+    selections: RArray,
+}
+
+impl Lookahead {
+    /// `selections` must only contain `Lookahead`s, and is frozen
+    pub(crate) fn new(
+        name: String,
+        response_key: String,
+        arguments: Result<RHash, Vec<String>>,
+        selections: RArray,
+    ) -> Self {
+        selections.freeze();
+        Self {
+            name,
+            response_key,
+            arguments,
+            selections,
+        }
+    }
+
+    fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    fn response_key(&self) -> &str {
+        self.response_key.as_str()
+    }
+
+    /// Raises if the arguments could not be coerced, in which case the errors are also reported
+    /// for the field if it is executed
+    fn arguments(&self) -> Result<RHash, Error> {
+        self.arguments.as_ref().copied().map_err(|messages| {
+            Error::new(
+                base_error(),
+                format!(
+                    "Invalid arguments for field `{}`: {}",
+                    self.name,
+                    messages.join(", ")
+                ),
+            )
+        })
+    }
+
+    fn selections(&self) -> RArray {
+        self.selections
+    }
+
+    /// The first selection of the field named `name`, which can be either the name of the field or
+    /// the snake case version of it
+    fn selection(&self, name: Value) -> Result<Option<Obj<Lookahead>>, Error> {
+        let name = match Symbol::from_value(name) {
+            Some(symbol) => symbol.name()?.into_owned(),
+            None => String::try_convert(name)?,
+        };
+        Ok(
+            RArrayIter::<Obj<Lookahead>>::from(&self.selections).find(|selection| {
+                selection.name == name || selection.name.to_case(Case::Snake) == name
+            }),
+        )
+    }
+
+    fn selects(&self, name: Value) -> Result<bool, Error> {
+        self.selection(name).map(|selection| selection.is_some())
+    }
+}
+
+impl DataTypeFunctions for Lookahead {
+    fn mark(&self) {
+        if let Ok(arguments) = self.arguments {
+            gc::mark(arguments);
+        }
+        gc::mark(self.selections);
+    }
+}
+
+pub fn init() -> Result<(), Error> {
+    let class = root().define_class("Lookahead", Default::default())?;
+
+    class.define_method("name", method!(Lookahead::name, 0))?;
+    class.define_method("response_key", method!(Lookahead::response_key, 0))?;
+    class.define_method("arguments", method!(Lookahead::arguments, 0))?;
+    class.define_method("selections", method!(Lookahead::selections, 0))?;
+    class.define_method("selection", method!(Lookahead::selection, 1))?;
+    class.define_method("selects?", method!(Lookahead::selects, 1))?;
+
+    Ok(())
+}
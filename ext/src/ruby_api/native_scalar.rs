@@ -0,0 +1,472 @@
+use crate::helpers::RArrayIter;
+use crate::ruby_api::{root, RResult};
+use magnus::{
+    class, exception, function, module,
+    r_hash::ForEach,
+    rb_sys::{protect, AsRawValue, FromRawValue},
+    Error, Float, Integer, Module, Object, RArray, RClass, RHash, RString, Symbol, TryConvert,
+    Value,
+};
+use rb_sys::{
+    rb_str_to_inum, rb_time_timespec, rb_time_timespec_new, rb_time_utc_offset, timespec,
+};
+use std::os::raw::c_int;
+use std::str::FromStr;
+use strum::EnumString;
+
+/// Custom scalars shipped with the gem whose input and result coercion are implemented natively,
+/// instead of by calling `coerce_input` and `coerce_result` on the Ruby class
+#[derive(Clone, Copy, Debug, PartialEq, Eq, EnumString)]
+#[strum(serialize_all = "snake_case")]
+pub enum NativeScalar {
+    DateTime,
+    Date,
+    Json,
+    BigInt,
+    Url,
+    Uuid,
+    Decimal,
+}
+
+/// Passed as the offset to `rb_time_timespec_new` to create a UTC time
+const UTC_OFFSET: c_int = c_int::MAX - 1;
+
+/// Days between 0000-03-01 and 1970-01-01 in the proleptic Gregorian calendar
+const UNIX_EPOCH_DAYS_FROM_CIVIL_EPOCH: i64 = 719_468;
+
+/// The Julian day number of 1970-01-01
+const UNIX_EPOCH_JULIAN_DAY: i64 = 2_440_588;
+
+const MAX_JSON_DEPTH: usize = 256;
+
+impl NativeScalar {
+    pub(crate) fn coerce_input(self, value: Value) -> Result<Result<Value, String>, Error> {
+        match self {
+            Self::DateTime => Ok(
+                match string_value(value)?.as_deref().and_then(parse_date_time) {
+                    Some((seconds, nanos, utc_offset)) => Ok(new_time(seconds, nanos, utc_offset)?),
+                    None => Err("Expected an RFC 3339 date-time string".to_owned()),
+                },
+            ),
+            Self::Date => Ok(
+                match string_value(value)?.as_deref().and_then(parse_full_date) {
+                    Some((year, month, day)) => {
+                        // `coerce_result` formats dates in the proleptic Gregorian calendar, so build
+                        // them in the same calendar instead of the default Julian/Gregorian switch
+                        let date_class = date_class()?;
+                        let gregorian: Value = date_class.const_get("GREGORIAN")?;
+                        Ok(date_class.funcall("new", (year, month, day, gregorian))?)
+                    }
+                    None => Err("Expected an RFC 3339 full-date string".to_owned()),
+                },
+            ),
+            Self::Json => Ok(Ok(value)),
+            Self::BigInt => {
+                if Integer::from_value(value).is_some() {
+                    return Ok(Ok(value));
+                }
+                match RString::from_value(value) {
+                    Some(s) if is_integer_literal(&s.to_string()?) => {
+                        let integer = unsafe { protect(|| rb_str_to_inum(s.as_raw(), 10, 1)) }?;
+                        Ok(Ok(unsafe { Value::from_raw(integer) }))
+                    }
+                    _ => Ok(Err(
+                        "Expected an integer or a string encoding one".to_owned()
+                    )),
+                }
+            }
+            Self::Url => Ok(match string_value(value)? {
+                Some(s) if is_absolute_url(&s) => Ok(value),
+                _ => Err("Expected an absolute URL string".to_owned()),
+            }),
+            Self::Uuid => Ok(match string_value(value)? {
+                Some(s) if is_uuid(&s) => Ok(*RString::new(&s.to_ascii_lowercase())),
+                _ => Err("Expected a UUID string".to_owned()),
+            }),
+            Self::Decimal => {
+                let literal = if Integer::from_value(value).is_some() {
+                    Some(value.to_r_string()?.to_string()?)
+                } else if let Some(float) = Float::from_value(value) {
+                    Some(float.to_f64())
+                        .filter(|f| f.is_finite())
+                        .map(|f| f.to_string())
+                } else {
+                    string_value(value)?.filter(|s| is_decimal_literal(s))
+                };
+                Ok(match literal {
+                    Some(literal) => Ok(module::kernel().funcall("BigDecimal", (literal,))?),
+                    None => Err("Expected a decimal number or a string encoding one".to_owned()),
+                })
+            }
+        }
+    }
+
+    pub(crate) fn coerce_result(self, value: Value) -> Result<Result<Value, String>, Error> {
+        match self {
+            Self::DateTime => {
+                if !value.is_kind_of(class::time()) {
+                    return Ok(Err("Expected a Time".to_owned()));
+                }
+                let time_spec = unsafe { rb_time_timespec(value.as_raw()) };
+                let utc_offset: i64 =
+                    unsafe { Value::from_raw(rb_time_utc_offset(value.as_raw())) }.try_convert()?;
+                if utc_offset % 60 != 0 {
+                    return Ok(Err(
+                        "Expected a Time with a UTC offset in whole minutes".to_owned()
+                    ));
+                }
+                Ok(Ok(*RString::new(&format_date_time(
+                    time_spec.tv_sec as i64,
+                    time_spec.tv_nsec as u32,
+                    utc_offset,
+                ))))
+            }
+            Self::Date => {
+                if !value.is_kind_of(date_class()?) {
+                    return Ok(Err("Expected a Date".to_owned()));
+                }
+                let julian_day: i64 = value.funcall("jd", ())?;
+                let (year, month, day) = civil_from_days(julian_day - UNIX_EPOCH_JULIAN_DAY);
+                Ok(Ok(*RString::new(&format!("{year:04}-{month:02}-{day:02}"))))
+            }
+            Self::Json => Ok(if is_json_value(value, 0)? {
+                Ok(value)
+            } else {
+                Err("Expected a JSON-compatible value".to_owned())
+            }),
+            Self::BigInt => Ok(match Integer::from_value(value) {
+                Some(integer) => Ok(match integer.to_i64() {
+                    Ok(i) => *RString::new(&i.to_string()),
+                    Err(_) => *integer.to_r_string()?,
+                }),
+                None => Err("Expected an Integer".to_owned()),
+            }),
+            Self::Url => Ok(match string_value(value)? {
+                Some(s) if is_absolute_url(&s) => Ok(value),
+                _ => Err("Expected an absolute URL string".to_owned()),
+            }),
+            Self::Uuid => Ok(match string_value(value)? {
+                Some(s) if is_uuid(&s) => Ok(*RString::new(&s.to_ascii_lowercase())),
+                _ => Err("Expected a UUID string".to_owned()),
+            }),
+            Self::Decimal => {
+                if Integer::from_value(value).is_some() {
+                    return Ok(Ok(*value.to_r_string()?));
+                }
+                if value.is_kind_of(big_decimal_class()?)
+                    && value.funcall::<_, _, bool>("finite?", ())?
+                {
+                    return Ok(Ok(value.funcall("to_s", ("F",))?));
+                }
+                Ok(Err("Expected a finite BigDecimal or an Integer".to_owned()))
+            }
+        }
+    }
+}
+
+impl TryConvert for NativeScalar {
+    fn try_convert(val: Value) -> Result<Self, Error> {
+        let symbol: Symbol = val.try_convert()?;
+        let name = symbol.name()?;
+        Self::from_str(name.as_ref()).map_err(|_| {
+            Error::new(
+                exception::arg_error(),
+                format!(
+                    "Unknown native scalar `{name}`, expected one of :date_time, :date, :json, :big_int, :url, :uuid or :decimal"
+                ),
+            )
+        })
+    }
+}
+
+fn string_value(value: Value) -> Result<Option<String>, Error> {
+    RString::from_value(value)
+        .map(|s| s.to_string())
+        .transpose()
+}
+
+fn date_class() -> Result<RClass, Error> {
+    class::object().const_get("Date")
+}
+
+fn big_decimal_class() -> Result<RClass, Error> {
+    class::object().const_get("BigDecimal")
+}
+
+fn new_time(seconds: i64, nanos: u32, utc_offset: Option<i32>) -> Result<Value, Error> {
+    let time_spec = timespec {
+        tv_sec: seconds as _,
+        tv_nsec: nanos as _,
+    };
+    let time =
+        unsafe { protect(|| rb_time_timespec_new(&time_spec, utc_offset.unwrap_or(UTC_OFFSET))) }?;
+    Ok(unsafe { Value::from_raw(time) })
+}
+
+fn digits(bytes: &[u8]) -> Option<u32> {
+    if bytes.is_empty() || !bytes.iter().all(u8::is_ascii_digit) {
+        return None;
+    }
+    Some(
+        bytes
+            .iter()
+            .fold(0, |acc, digit| acc * 10 + u32::from(digit - b'0')),
+    )
+}
+
+fn is_leap_year(year: i64) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+fn days_in_month(year: i64, month: u32) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Parses a `full-date` as defined by RFC 3339, e.g. `2023-01-31`
+fn parse_full_date(s: &str) -> Option<(i64, u32, u32)> {
+    let bytes = s.as_bytes();
+    if bytes.len() != 10 || bytes[4] != b'-' || bytes[7] != b'-' {
+        return None;
+    }
+    let year = i64::from(digits(&bytes[0..4])?);
+    let month = digits(&bytes[5..7])?;
+    let day = digits(&bytes[8..10])?;
+    ((1..=12).contains(&month) && (1..=days_in_month(year, month)).contains(&day))
+        .then_some((year, month, day))
+}
+
+/// Parses a `date-time` as defined by RFC 3339 into seconds since the Unix epoch, nanoseconds and
+/// the UTC offset in seconds, which is `None` for times in UTC
+fn parse_date_time(s: &str) -> Option<(i64, u32, Option<i32>)> {
+    let bytes = s.as_bytes();
+    if bytes.len() < 20
+        || !matches!(bytes[10], b'T' | b't')
+        || bytes[13] != b':'
+        || bytes[16] != b':'
+    {
+        return None;
+    }
+    let (year, month, day) = parse_full_date(&s[0..10])?;
+    let hour = digits(&bytes[11..13])?;
+    let minute = digits(&bytes[14..16])?;
+    let second = digits(&bytes[17..19])?;
+    if hour > 23 || minute > 59 || second > 59 {
+        return None;
+    }
+
+    let mut rest = &bytes[19..];
+    let mut nanos = 0;
+    if let Some(fraction) = rest.strip_prefix(b".") {
+        let len = fraction.iter().take_while(|b| b.is_ascii_digit()).count();
+        if len == 0 {
+            return None;
+        }
+        nanos = fraction[..len]
+            .iter()
+            .chain(std::iter::repeat(&b'0'))
+            .take(9)
+            .fold(0, |acc, digit| acc * 10 + u32::from(digit - b'0'));
+        rest = &fraction[len..];
+    }
+
+    let utc_offset = match rest {
+        [b'Z' | b'z'] => None,
+        [sign @ (b'+' | b'-'), offset_hour @ .., b':', m1, m2] if offset_hour.len() == 2 => {
+            let offset_hour = digits(offset_hour)?;
+            let offset_minute = digits(&[*m1, *m2])?;
+            if offset_hour > 23 || offset_minute > 59 {
+                return None;
+            }
+            let offset = (offset_hour * 3600 + offset_minute * 60) as i32;
+            Some(if *sign == b'-' { -offset } else { offset })
+        }
+        _ => return None,
+    };
+
+    let local_seconds =
+        days_from_civil(year, month, day) * 86_400 + i64::from(hour * 3600 + minute * 60 + second);
+    Some((
+        local_seconds - i64::from(utc_offset.unwrap_or(0)),
+        nanos,
+        utc_offset,
+    ))
+}
+
+/// Formats as an RFC 3339 date-time, `utc_offset` must be a whole number of minutes since
+/// RFC 3339 offsets have no seconds component
+fn format_date_time(seconds: i64, nanos: u32, utc_offset: i64) -> String {
+    let local_seconds = seconds + utc_offset;
+    let (year, month, day) = civil_from_days(local_seconds.div_euclid(86_400));
+    let second_of_day = local_seconds.rem_euclid(86_400);
+    let mut formatted = format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}",
+        second_of_day / 3600,
+        second_of_day % 3600 / 60,
+        second_of_day % 60,
+    );
+    if nanos != 0 {
+        formatted.push('.');
+        formatted.push_str(format!("{nanos:09}").trim_end_matches('0'));
+    }
+    if utc_offset == 0 {
+        formatted.push('Z');
+    } else {
+        let sign = if utc_offset < 0 { '-' } else { '+' };
+        let utc_offset = utc_offset.abs();
+        formatted.push_str(&format!(
+            "{sign}{:02}:{:02}",
+            utc_offset / 3600,
+            utc_offset % 3600 / 60
+        ));
+    }
+    formatted
+}
+
+/// Days since the Unix epoch of a date in the proleptic Gregorian calendar
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = (if year >= 0 { year } else { year - 399 }) / 400;
+    let year_of_era = year - era * 400;
+    let month = i64::from(month);
+    let day_of_year =
+        (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - UNIX_EPOCH_DAYS_FROM_CIVIL_EPOCH
+}
+
+/// The date in the proleptic Gregorian calendar of a number of days since the Unix epoch
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + UNIX_EPOCH_DAYS_FROM_CIVIL_EPOCH;
+    let era = (if days >= 0 { days } else { days - 146_096 }) / 146_097;
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * shifted_month + 2) / 5 + 1) as u32;
+    let month = (if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    }) as u32;
+    let year = year_of_era + era * 400;
+    (if month <= 2 { year + 1 } else { year }, month, day)
+}
+
+fn is_integer_literal(s: &str) -> bool {
+    let unsigned = s.strip_prefix('-').unwrap_or(s);
+    !unsigned.is_empty() && unsigned.bytes().all(|b| b.is_ascii_digit())
+}
+
+fn is_decimal_literal(s: &str) -> bool {
+    let (mantissa, exponent) = match s.find(['e', 'E']) {
+        Some(index) => (&s[..index], Some(&s[index + 1..])),
+        None => (s, None),
+    };
+    let mantissa = mantissa.strip_prefix('-').unwrap_or(mantissa);
+    let valid_mantissa = match mantissa.split_once('.') {
+        Some((integral, fractional)) => {
+            is_integer_literal(integral) && is_integer_literal(fractional)
+        }
+        None => is_integer_literal(mantissa),
+    };
+    valid_mantissa
+        && exponent.map_or(true, |exponent| {
+            is_integer_literal(exponent.strip_prefix('+').unwrap_or(exponent))
+        })
+}
+
+/// Whether `s` is an absolute URL, i.e. a scheme as defined by RFC 3986 followed by a
+/// non-empty remainder, without any whitespace or control characters
+fn is_absolute_url(s: &str) -> bool {
+    match s.split_once(':') {
+        Some((scheme, remainder)) => {
+            let mut scheme_chars = scheme.chars();
+            scheme_chars
+                .next()
+                .map_or(false, |c| c.is_ascii_alphabetic())
+                && scheme_chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+                && !remainder.is_empty()
+                && !s.chars().any(|c| c.is_whitespace() || c.is_control())
+        }
+        None => false,
+    }
+}
+
+/// Whether `s` is a UUID in its canonical textual representation, in either case
+fn is_uuid(s: &str) -> bool {
+    s.len() == 36
+        && s.bytes().enumerate().all(|(index, b)| match index {
+            8 | 13 | 18 | 23 => b == b'-',
+            _ => b.is_ascii_hexdigit(),
+        })
+}
+
+fn is_json_value(value: Value, depth: usize) -> Result<bool, Error> {
+    if depth > MAX_JSON_DEPTH {
+        return Ok(false);
+    }
+    if value.is_nil()
+        || value.is_kind_of(class::true_class())
+        || value.is_kind_of(class::false_class())
+        || value.is_kind_of(class::string())
+        || Integer::from_value(value).is_some()
+    {
+        return Ok(true);
+    }
+    if let Some(float) = Float::from_value(value) {
+        return Ok(float.to_f64().is_finite());
+    }
+    if let Some(array) = RArray::from_value(value) {
+        for item in RArrayIter::from(&array) {
+            if !is_json_value(item, depth + 1)? {
+                return Ok(false);
+            }
+        }
+        return Ok(true);
+    }
+    if let Some(hash) = RHash::from_value(value) {
+        let mut valid = true;
+        hash.foreach(|key: Value, value: Value| {
+            valid = (key.is_kind_of(class::string()) || Symbol::from_value(key).is_some())
+                && is_json_value(value, depth + 1)?;
+            Ok(if valid {
+                ForEach::Continue
+            } else {
+                ForEach::Stop
+            })
+        })?;
+        return Ok(valid);
+    }
+    Ok(false)
+}
+
+pub fn init() -> Result<(), Error> {
+    let module = root().define_module("NativeScalars")?;
+
+    module.define_singleton_method(
+        "coerce_input",
+        function!(
+            |native_scalar: NativeScalar, value: Value| {
+                native_scalar.coerce_input(value).map(RResult::from)
+            },
+            2
+        ),
+    )?;
+    module.define_singleton_method(
+        "coerce_result",
+        function!(
+            |native_scalar: NativeScalar, value: Value| {
+                native_scalar.coerce_result(value).map(RResult::from)
+            },
+            2
+        ),
+    )?;
+
+    Ok(())
+}
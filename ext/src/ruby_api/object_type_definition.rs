@@ -1,6 +1,6 @@
 use crate::helpers::HasDefinitionWrapper;
 use crate::ruby_api::{
-    base, introspection, root, Directives, FieldDefinition, FieldsDefinition, HasVisibility,
+    base, introspection, root, Directives, FieldsDefinition, HasVisibility,
     InterfaceImplementations, Visibility,
 };
 use crate::visibility_scoped::{ScopedInterfaceTypeDefinition, ScopedObjectTypeDefinition};
@@ -19,6 +19,7 @@ pub struct ObjectTypeDefinition {
     directives: Directives,
     interface_implementations: InterfaceImplementations,
     is_builtin: bool,
+    interface_module: Option<RModule>,
     visibility: Option<Visibility>,
 }
 
@@ -56,6 +57,14 @@ impl ObjectTypeDefinition {
         ) = args.required;
         let directives = directives.try_into()?;
         let is_builtin = unsafe { ruby_class.name() }.starts_with("Bluejay::Builtin::ObjectTypes");
+        // only the class's own `Interface`, since a subclass of an object type is not of that type
+        let has_interface_module: bool =
+            ruby_class.funcall("const_defined?", ("Interface", false))?;
+        let interface_module: Option<RModule> = if has_interface_module {
+            Some(ruby_class.funcall("const_get", ("Interface", false))?)
+        } else {
+            None
+        };
         Ok(Self {
             name,
             description,
@@ -63,6 +72,7 @@ impl ObjectTypeDefinition {
             directives,
             interface_implementations,
             is_builtin,
+            interface_module,
             visibility,
         })
     }
@@ -73,6 +83,9 @@ impl DataTypeFunctions for ObjectTypeDefinition {
         gc::mark(self.fields_definition);
         gc::mark(self.interface_implementations);
         self.directives.mark();
+        if let Some(interface_module) = self.interface_module {
+            gc::mark(interface_module);
+        }
         self.visibility.as_ref().map(Visibility::mark);
     }
 }
@@ -115,13 +128,14 @@ impl ObjectTypeDefinition {
             })
     }
 
-    pub fn field_definition(&self, name: &str) -> Option<&FieldDefinition> {
-        self.fields_definition.iter().find(|fd| fd.name() == name)
-    }
-
     pub fn directives(&self) -> &Directives {
         &self.directives
     }
+
+    /// The `Interface` module generated for the object type, which values of the type include
+    pub fn interface_module(&self) -> Option<RModule> {
+        self.interface_module
+    }
 }
 
 impl bluejay_core::definition::ObjectTypeDefinition for ObjectTypeDefinition {
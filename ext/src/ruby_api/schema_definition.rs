@@ -1,13 +1,18 @@
-use crate::execution::Engine as ExecutionEngine;
-use crate::helpers::{Warden, WrappedDefinition};
+use crate::analysis::DeprecatedUsageCollector;
+use crate::codegen::OperationTypesGenerator;
+use crate::execution::{Engine as ExecutionEngine, ResultCoercionOptions};
+use crate::helpers::{without_gvl, Warden, WrappedDefinition};
 use crate::ruby_api::{
-    base, root, ArgumentsDefinition, BaseInputType, BaseOutputType, CustomScalarTypeDefinition,
-    DirectiveDefinition, Directives, EnumTypeDefinition, EnumValueDefinition, EnumValueDefinitions,
-    ExecutionResult, FieldDefinition, FieldsDefinition, InputFieldsDefinition,
-    InputObjectTypeDefinition, InputType, InputValueDefinition, InterfaceImplementation,
-    InterfaceImplementations, InterfaceTypeDefinition, ObjectTypeDefinition, OutputType,
-    TypeDefinition, UnionMemberType, UnionMemberTypes, UnionTypeDefinition, ValidationError,
+    base, root, type_system_validation_error, ArgumentsDefinition, BaseInputType, BaseOutputType,
+    CustomScalarTypeDefinition, DirectiveDefinition, Directives, EnumTypeDefinition,
+    EnumValueDefinition, EnumValueDefinitions, ExecutionResult, FieldDefinition, FieldsDefinition,
+    HasVisibility, InputFieldsDefinition, InputObjectTypeDefinition, InputType,
+    InputValueDefinition, InterfaceImplementation, InterfaceImplementations,
+    InterfaceTypeDefinition, ObjectTypeDefinition, OutputType, RResult, TypeDefinition,
+    UnionMemberType, UnionMemberTypes, UnionTypeDefinition, ValidationError,
 };
+use crate::schema_index::SchemaIndex;
+use crate::validation::{SchemaSnapshot, TypeSystemValidator};
 use crate::visibility_scoped::{ScopedSchemaDefinition, VisibilityCache};
 use bluejay_core::definition::{
     InputType as CoreInputType, OutputType as CoreOutputType,
@@ -19,8 +24,8 @@ use bluejay_printer::definition::SchemaDefinitionPrinter;
 use bluejay_validator::executable::{BuiltinRulesValidator, Cache as ValidationCache};
 use magnus::{
     exception, function, gc, memoize, method, scan_args::get_kwargs, scan_args::KwArgs,
-    typed_data::Obj, DataTypeFunctions, Error, Module, Object, RArray, RClass, RHash, RModule,
-    TypedData, Value,
+    typed_data::Obj, Class, DataTypeFunctions, Error, Module, Object, RArray, RClass, RHash,
+    RModule, TypedData, Value,
 };
 use std::collections::{
     btree_map::{Entry, Values},
@@ -38,11 +43,24 @@ pub struct SchemaDefinition {
     contained_directives: BTreeMap<String, WrappedDefinition<DirectiveDefinition>>,
     interface_implementors: HashMap<String, Vec<WrappedDefinition<ObjectTypeDefinition>>>,
     ruby_class: RClass,
+    result_coercion_options: ResultCoercionOptions,
+    index: SchemaIndex,
+    /// Ruby-free copy used to validate queries without holding the GVL, which is only built when
+    /// nothing in the schema has a visibility, as evaluating one requires calling into Ruby
+    snapshot: Option<SchemaSnapshot>,
 }
 
 impl SchemaDefinition {
     pub fn new(kw: RHash) -> Result<Self, Error> {
-        let args: KwArgs<_, (), ()> = get_kwargs(
+        type RequiredArgs = (
+            Option<String>,
+            WrappedDefinition<ObjectTypeDefinition>,
+            Option<WrappedDefinition<ObjectTypeDefinition>>,
+            RArray,
+            RClass,
+        );
+        type OptionalArgs = (Option<bool>, Option<bool>, Option<bool>);
+        let args: KwArgs<RequiredArgs, OptionalArgs, ()> = get_kwargs(
             kw,
             &[
                 "description",
@@ -51,15 +69,20 @@ impl SchemaDefinition {
                 "directives",
                 "ruby_class",
             ],
-            &[],
+            &[
+                "strict_result_coercion",
+                "coerce_integral_floats_to_int",
+                "wide_integer_ids",
+            ],
         )?;
-        let (description, query, mutation, directives, ruby_class): (
-            Option<String>,
-            WrappedDefinition<ObjectTypeDefinition>,
-            Option<WrappedDefinition<ObjectTypeDefinition>>,
-            RArray,
-            RClass,
-        ) = args.required;
+        let (description, query, mutation, directives, ruby_class) = args.required;
+        let (strict_result_coercion, coerce_integral_floats_to_int, wide_integer_ids) =
+            args.optional;
+        let result_coercion_options = ResultCoercionOptions {
+            strict: strict_result_coercion.unwrap_or_default(),
+            coerce_integral_floats_to_int: coerce_integral_floats_to_int.unwrap_or_default(),
+            wide_integer_ids: wide_integer_ids.unwrap_or_default(),
+        };
         if !query.wrapper().is_kind_of(Self::query_root_module()) {
             return Err(Error::new(
                 exception::type_error(),
@@ -78,10 +101,15 @@ impl SchemaDefinition {
                 &directives,
             )?;
         let interface_implementors = Self::interface_implementors(&contained_types);
+        let index = SchemaIndex::new(
+            &contained_types,
+            Self::uses_visibility(&contained_types, &contained_directives),
+        );
 
+        Self::validate_type_system(&contained_types, &contained_directives, &directives, &index)?;
         Self::validate_default_values(&contained_types)?;
 
-        Ok(Self {
+        let mut schema_definition = Self {
             description,
             query,
             mutation,
@@ -90,7 +118,17 @@ impl SchemaDefinition {
             contained_directives,
             interface_implementors,
             ruby_class,
-        })
+            result_coercion_options,
+            index,
+            snapshot: None,
+        };
+        schema_definition.snapshot = schema_definition.build_snapshot()?;
+
+        Ok(schema_definition)
+    }
+
+    pub fn result_coercion_options(&self) -> ResultCoercionOptions {
+        self.result_coercion_options
     }
 
     pub fn query(&self) -> Obj<ObjectTypeDefinition> {
@@ -113,6 +151,10 @@ impl SchemaDefinition {
         self.ruby_class
     }
 
+    pub fn index(&self) -> &SchemaIndex {
+        &self.index
+    }
+
     fn execute(
         &self,
         query: String,
@@ -120,6 +162,7 @@ impl SchemaDefinition {
         variable_values: RHash,
         initial_value: Value,
         context: Value,
+        response_class: Option<RClass>,
     ) -> Result<ExecutionResult, Error> {
         ExecutionEngine::execute_request(
             self,
@@ -128,10 +171,25 @@ impl SchemaDefinition {
             variable_values,
             initial_value,
             context,
+            response_class,
         )
     }
 
+    fn execute_batch(
+        &self,
+        requests: Vec<(String, Option<String>, RHash)>,
+        initial_value: Value,
+        context: Value,
+    ) -> Result<RArray, Error> {
+        ExecutionEngine::execute_batch(self, &requests, initial_value, context)
+    }
+
     fn validate_query(&self, query: String, context: Value) -> Result<RArray, Error> {
+        if let Some(snapshot) = self.snapshot.as_ref() {
+            let errors = without_gvl(|| snapshot.validate_query(query.as_str()));
+            return Ok(RArray::from_iter(errors.into_iter().map(Obj::wrap)));
+        }
+
         if let Ok(document) =
             bluejay_parser::ast::executable::ExecutableDocument::parse(query.as_str())
         {
@@ -153,6 +211,44 @@ impl SchemaDefinition {
         }
     }
 
+    fn coerce_variables(
+        &self,
+        query: String,
+        operation_name: Option<String>,
+        variable_values: RHash,
+        context: Value,
+    ) -> Result<RResult, Error> {
+        ExecutionEngine::coerce_variable_values(
+            self,
+            query.as_str(),
+            operation_name.as_deref(),
+            variable_values,
+            context,
+        )
+        .map(|result| result.map_err(RArray::from_iter).into())
+    }
+
+    fn deprecated_usages(
+        &self,
+        query: String,
+        operation_name: Option<String>,
+        variables: RHash,
+        context: Value,
+    ) -> Result<RArray, Error> {
+        DeprecatedUsageCollector::collect(
+            self,
+            query.as_str(),
+            operation_name.as_deref(),
+            variables,
+            context,
+        )
+        .map(RArray::from_iter)
+    }
+
+    fn generate_operation_types(&self, query: String, context: Value) -> Result<String, Error> {
+        OperationTypesGenerator::generate(self, query.as_str(), context)
+    }
+
     fn to_definition(&self, context: Value) -> Result<String, Error> {
         let warden = Warden::new(context);
         let cache = VisibilityCache::new(warden);
@@ -162,6 +258,103 @@ impl SchemaDefinition {
         cache.warden().to_result().map(|_| s)
     }
 
+    /// The names of the object types that implement each interface in the schema, keyed by the
+    /// Ruby class of the interface
+    fn interface_implementor_names(&self) -> Result<RHash, Error> {
+        let implementor_names = RHash::new();
+        self.contained_types
+            .values()
+            .try_for_each(|type_definition| -> Result<(), Error> {
+                if let TypeDefinition::Interface(itd) = type_definition {
+                    let names = RArray::from_iter(
+                        self.interface_implementors
+                            .get(itd.as_ref().name())
+                            .into_iter()
+                            .flatten()
+                            .map(|otd| otd.as_ref().name().to_owned()),
+                    );
+                    implementor_names.aset(itd.wrapper(), names)?;
+                }
+                Ok(())
+            })?;
+        Ok(implementor_names)
+    }
+
+    /// Prints the SDL of the schema and parses it again, which only happens once when the schema
+    /// definition is built, see `SchemaSnapshot`
+    fn build_snapshot(&self) -> Result<Option<SchemaSnapshot>, Error> {
+        if self.index.has_visibility() {
+            return Ok(None);
+        }
+
+        self.to_definition(*magnus::QNIL).map(SchemaSnapshot::new)
+    }
+
+    fn uses_visibility(
+        type_definitions: &BTreeMap<String, TypeDefinition>,
+        directive_definitions: &BTreeMap<String, WrappedDefinition<DirectiveDefinition>>,
+    ) -> bool {
+        let input_values_use_visibility = |input_values: &InputFieldsDefinition| {
+            input_values.iter().any(|ivd| ivd.visibility().is_some())
+        };
+        let fields_use_visibility = |fields_definition: &FieldsDefinition| {
+            fields_definition.iter().any(|fd| {
+                fd.visibility().is_some() || input_values_use_visibility(fd.argument_definitions())
+            })
+        };
+        let interface_implementations_use_visibility =
+            |interface_implementations: &InterfaceImplementations| {
+                interface_implementations
+                    .iter()
+                    .any(|ii| ii.visibility().is_some())
+            };
+
+        type_definitions
+            .values()
+            .any(|type_definition| match type_definition {
+                TypeDefinition::BuiltinScalar(_) => false,
+                TypeDefinition::CustomScalar(cstd) => cstd.as_ref().visibility().is_some(),
+                TypeDefinition::Enum(etd) => {
+                    let etd = etd.as_ref();
+                    etd.visibility().is_some()
+                        || etd
+                            .enum_value_definitions()
+                            .iter()
+                            .any(|evd| evd.visibility().is_some())
+                }
+                TypeDefinition::Object(otd) => {
+                    let otd = otd.as_ref();
+                    otd.visibility().is_some()
+                        || fields_use_visibility(otd.fields_definition())
+                        || interface_implementations_use_visibility(otd.interface_implementations())
+                }
+                TypeDefinition::Interface(itd) => {
+                    let itd = itd.as_ref();
+                    itd.visibility().is_some()
+                        || fields_use_visibility(itd.fields_definition())
+                        || interface_implementations_use_visibility(itd.interface_implementations())
+                }
+                TypeDefinition::Union(utd) => {
+                    let utd = utd.as_ref();
+                    utd.visibility().is_some()
+                        || utd
+                            .member_types()
+                            .iter()
+                            .any(|member_type| member_type.visibility().is_some())
+                }
+                TypeDefinition::InputObject(iotd) => {
+                    let iotd = iotd.as_ref();
+                    iotd.visibility().is_some()
+                        || input_values_use_visibility(iotd.input_fields_definition())
+                }
+            })
+            || directive_definitions.values().any(|directive_definition| {
+                let directive_definition = directive_definition.as_ref();
+                directive_definition.visibility().is_some()
+                    || input_values_use_visibility(&directive_definition.arguments_definition())
+            })
+    }
+
     fn interface_implementors(
         type_definitions: &BTreeMap<String, TypeDefinition>,
     ) -> HashMap<String, Vec<WrappedDefinition<ObjectTypeDefinition>>> {
@@ -185,6 +378,29 @@ impl SchemaDefinition {
         )
     }
 
+    fn validate_type_system(
+        type_definitions: &BTreeMap<String, TypeDefinition>,
+        directive_definitions: &BTreeMap<String, WrappedDefinition<DirectiveDefinition>>,
+        schema_directives: &Directives,
+        index: &SchemaIndex,
+    ) -> Result<(), Error> {
+        let errors = TypeSystemValidator::validate(
+            type_definitions,
+            directive_definitions,
+            schema_directives,
+            index,
+        );
+        if errors.is_empty() {
+            return Ok(());
+        }
+
+        let errors = RArray::from_iter(errors.into_iter().map(Obj::wrap));
+        match type_system_validation_error().new_instance((errors,)) {
+            Ok(exception) => Err(Error::Exception(exception)),
+            Err(error) => Err(error),
+        }
+    }
+
     fn validate_default_values(
         type_definitions: &BTreeMap<String, TypeDefinition>,
     ) -> Result<(), Error> {
@@ -525,11 +741,24 @@ pub fn init() -> Result<(), Error> {
     let class = root().define_class("SchemaDefinition", Default::default())?;
 
     class.define_singleton_method("new", function!(SchemaDefinition::new, 1))?;
-    class.define_method("execute", method!(SchemaDefinition::execute, 5))?;
+    class.define_method("execute", method!(SchemaDefinition::execute, 6))?;
+    class.define_method("execute_batch", method!(SchemaDefinition::execute_batch, 3))?;
     class.define_method(
         "validate_query",
         method!(SchemaDefinition::validate_query, 2),
     )?;
+    class.define_method(
+        "coerce_variables",
+        method!(SchemaDefinition::coerce_variables, 4),
+    )?;
+    class.define_method(
+        "deprecated_usages",
+        method!(SchemaDefinition::deprecated_usages, 4),
+    )?;
+    class.define_method(
+        "generate_operation_types",
+        method!(SchemaDefinition::generate_operation_types, 2),
+    )?;
     class.define_method("to_definition", method!(SchemaDefinition::to_definition, 1))?;
     class.define_method(
         "type",
@@ -556,6 +785,10 @@ pub fn init() -> Result<(), Error> {
             0
         ),
     )?;
+    class.define_method(
+        "interface_implementor_names",
+        method!(SchemaDefinition::interface_implementor_names, 0),
+    )?;
     class.define_method(
         "resolve_typename",
         method!(|_: &SchemaDefinition| "__Schema", 0),
@@ -0,0 +1,87 @@
+use super::root;
+use magnus::{
+    method,
+    rb_sys::AsRawValue,
+    typed_data::{self, Obj},
+    Error, Module, Symbol,
+};
+use strum::IntoStaticStr;
+
+#[derive(IntoStaticStr, Clone, Copy, Debug, PartialEq, Eq)]
+#[strum(serialize_all = "snake_case")]
+pub enum TypeSystemErrorKind {
+    InvalidName,
+    ReservedName,
+    MissingInterfaceField,
+    InvalidInterfaceFieldType,
+    MissingInterfaceFieldArgument,
+    InvalidInterfaceFieldArgumentType,
+    RequiredAdditionalArgument,
+    MissingTransitiveInterface,
+    SelfImplementingInterface,
+    EmptyUnion,
+    NonUniqueUnionMember,
+    NonNullInputObjectCycle,
+    MissingDirectiveLocations,
+    NonUniqueDirectiveLocation,
+    DirectiveInInvalidLocation,
+    NonRepeatableDirectiveRepeated,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[magnus::wrap(class = "Bluejay::TypeSystemError")]
+pub struct TypeSystemError {
+    kind: TypeSystemErrorKind,
+    coordinate: String,
+    message: String,
+}
+
+impl TypeSystemError {
+    pub fn new(kind: TypeSystemErrorKind, coordinate: String, message: String) -> Self {
+        Self {
+            kind,
+            coordinate,
+            message,
+        }
+    }
+
+    fn kind(&self) -> Symbol {
+        let kind: &'static str = self.kind.into();
+        Symbol::new(kind)
+    }
+
+    fn coordinate(&self) -> &str {
+        self.coordinate.as_str()
+    }
+
+    fn message(&self) -> &str {
+        self.message.as_str()
+    }
+
+    fn inspect(rb_self: Obj<Self>) -> Result<String, Error> {
+        let rs_self = rb_self.get();
+
+        Ok(format!(
+            "#<Bluejay::TypeSystemError:0x{:016x} @kind={:?} @coordinate={:?} @message={:?}>",
+            rb_self.as_raw(),
+            rs_self.kind(),
+            rs_self.coordinate,
+            rs_self.message,
+        ))
+    }
+}
+
+pub fn init() -> Result<(), Error> {
+    let class = root().define_class("TypeSystemError", Default::default())?;
+
+    class.define_method("kind", method!(TypeSystemError::kind, 0))?;
+    class.define_method("coordinate", method!(TypeSystemError::coordinate, 0))?;
+    class.define_method("message", method!(TypeSystemError::message, 0))?;
+    class.define_method(
+        "==",
+        method!(<TypeSystemError as typed_data::IsEql>::is_eql, 1),
+    )?;
+    class.define_method("inspect", method!(TypeSystemError::inspect, 0))?;
+
+    Ok(())
+}
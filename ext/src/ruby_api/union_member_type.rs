@@ -1,7 +1,7 @@
 use super::{object_type_definition::ObjectTypeDefinition, root, HasVisibility, Visibility};
 use crate::helpers::WrappedDefinition;
 use magnus::{
-    function,
+    function, method,
     scan_args::{get_kwargs, KwArgs},
     DataTypeFunctions, Error, Module, Object, RHash, TypedData, Value,
 };
@@ -55,6 +55,10 @@ pub fn init() -> Result<(), Error> {
     let class = root().define_class("UnionMemberType", Default::default())?;
 
     class.define_singleton_method("new", function!(UnionMemberType::new, 1))?;
+    class.define_method(
+        "type",
+        method!(|umt: &UnionMemberType| umt.r#type.wrapper(), 0),
+    )?;
 
     Ok(())
 }
@@ -81,15 +81,18 @@ impl UnionTypeDefinition {
     }
 
     pub fn sorbet_type(&self) -> String {
-        format!(
-            "T.any({})",
-            itertools::join(
-                self.member_types
-                    .iter()
-                    .map(|member_type| member_type.r#type().fully_qualified_name()),
-                ", ",
-            ),
-        )
+        let member_interfaces: Vec<String> = self
+            .member_types
+            .iter()
+            .map(|member_type| {
+                format!("{}::Interface", member_type.r#type().fully_qualified_name())
+            })
+            .collect();
+
+        match member_interfaces.as_slice() {
+            [member_interface] => member_interface.clone(),
+            _ => format!("T.any({})", member_interfaces.join(", ")),
+        }
     }
 }
 
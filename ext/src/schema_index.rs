@@ -0,0 +1,178 @@
+use crate::ruby_api::{FieldsDefinition, InterfaceImplementations, TypeDefinition};
+use bluejay_core::AsIter;
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+/// A name interned by a [`SchemaIndex`], which is cheap to copy, hash and compare
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Name(u32);
+
+#[derive(Debug, Default)]
+struct Interner {
+    names: HashMap<Box<str>, Name>,
+}
+
+impl Interner {
+    fn intern(&mut self, name: &str) -> Name {
+        if let Some(&interned) = self.names.get(name) {
+            return interned;
+        }
+        let interned = Name(self.names.len() as u32);
+        self.names.insert(name.into(), interned);
+        interned
+    }
+
+    fn get(&self, name: &str) -> Option<Name> {
+        self.names.get(name).copied()
+    }
+}
+
+#[derive(Debug, Default)]
+struct TypeEntry {
+    /// The position of each field in the fields definition of an object or interface type
+    fields: HashMap<Name, usize>,
+    /// The interfaces directly implemented by an object or interface type
+    interfaces: HashSet<Name>,
+    /// The member types of a union type
+    member_types: HashSet<Name>,
+}
+
+/// A precomputed index of the lookups that are made repeatedly during validation and execution,
+/// which would otherwise require linearly scanning the Ruby arrays that back a schema definition.
+/// It does not reference any Ruby objects, and is built once when a schema definition is created.
+#[derive(Debug)]
+pub struct SchemaIndex {
+    interner: Interner,
+    /// Indexed by type name, as type names are interned before any other name
+    types: Vec<TypeEntry>,
+    has_visibility: bool,
+}
+
+impl SchemaIndex {
+    pub fn new(type_definitions: &BTreeMap<String, TypeDefinition>, has_visibility: bool) -> Self {
+        let mut interner = Interner::default();
+        type_definitions.keys().for_each(|name| {
+            interner.intern(name);
+        });
+
+        let types = type_definitions
+            .values()
+            .map(|type_definition| match type_definition {
+                TypeDefinition::Object(otd) => {
+                    let otd = otd.as_ref();
+                    TypeEntry {
+                        fields: Self::fields(&mut interner, otd.fields_definition()),
+                        interfaces: Self::interfaces(&interner, otd.interface_implementations()),
+                        member_types: HashSet::new(),
+                    }
+                }
+                TypeDefinition::Interface(itd) => {
+                    let itd = itd.as_ref();
+                    TypeEntry {
+                        fields: Self::fields(&mut interner, itd.fields_definition()),
+                        interfaces: Self::interfaces(&interner, itd.interface_implementations()),
+                        member_types: HashSet::new(),
+                    }
+                }
+                TypeDefinition::Union(utd) => TypeEntry {
+                    member_types: utd
+                        .as_ref()
+                        .member_types()
+                        .iter()
+                        .filter_map(|member_type| {
+                            interner.get(member_type.r#type().as_ref().name())
+                        })
+                        .collect(),
+                    ..Default::default()
+                },
+                TypeDefinition::BuiltinScalar(_)
+                | TypeDefinition::CustomScalar(_)
+                | TypeDefinition::Enum(_)
+                | TypeDefinition::InputObject(_) => TypeEntry::default(),
+            })
+            .collect();
+
+        Self {
+            interner,
+            types,
+            has_visibility,
+        }
+    }
+
+    fn fields(
+        interner: &mut Interner,
+        fields_definition: &FieldsDefinition,
+    ) -> HashMap<Name, usize> {
+        let mut fields = HashMap::with_capacity(fields_definition.len());
+        fields_definition
+            .iter()
+            .enumerate()
+            .for_each(|(position, field_definition)| {
+                // keep the first field with a given name, matching a linear scan
+                fields
+                    .entry(interner.intern(field_definition.name()))
+                    .or_insert(position);
+            });
+        fields
+    }
+
+    fn interfaces(
+        interner: &Interner,
+        interface_implementations: &InterfaceImplementations,
+    ) -> HashSet<Name> {
+        interface_implementations
+            .iter()
+            .filter_map(|interface_implementation| {
+                interner.get(interface_implementation.interface().as_ref().name())
+            })
+            .collect()
+    }
+
+    /// Whether any definition in the schema has a visibility, in which case the visible parts of
+    /// the schema depend on the context and can only be determined by calling into Ruby
+    pub fn has_visibility(&self) -> bool {
+        self.has_visibility
+    }
+
+    pub fn name(&self, name: &str) -> Option<Name> {
+        self.interner.get(name)
+    }
+
+    fn type_entry(&self, type_name: &str) -> Option<&TypeEntry> {
+        self.name(type_name)
+            .and_then(|name| self.types.get(name.0 as usize))
+    }
+
+    /// The position of the field named `field_name` in the fields definition of the object or
+    /// interface type named `type_name`
+    pub fn field_position(&self, type_name: &str, field_name: &str) -> Option<usize> {
+        let type_entry = self.type_entry(type_name)?;
+        self.name(field_name)
+            .and_then(|name| type_entry.fields.get(&name))
+            .copied()
+    }
+
+    /// Whether the object or interface type named `type_name` directly implements the interface
+    /// named `interface_name`
+    pub fn implements(&self, type_name: &str, interface_name: &str) -> bool {
+        match (self.type_entry(type_name), self.name(interface_name)) {
+            (Some(type_entry), Some(interface_name)) => {
+                type_entry.interfaces.contains(&interface_name)
+            }
+            _ => false,
+        }
+    }
+
+    /// Whether the object type named `object_type_name` is a possible type of the type named
+    /// `type_name`, i.e. it is the type itself, a member of the union or an implementation of the
+    /// interface
+    pub fn is_possible_type(&self, type_name: &str, object_type_name: &str) -> bool {
+        type_name == object_type_name
+            || self.implements(object_type_name, type_name)
+            || match (self.type_entry(type_name), self.name(object_type_name)) {
+                (Some(type_entry), Some(object_type_name)) => {
+                    type_entry.member_types.contains(&object_type_name)
+                }
+                _ => false,
+            }
+    }
+}
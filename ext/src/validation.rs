@@ -0,0 +1,5 @@
+mod schema_snapshot;
+mod type_system_validator;
+
+pub use schema_snapshot::SchemaSnapshot;
+pub use type_system_validator::TypeSystemValidator;
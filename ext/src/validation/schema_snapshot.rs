@@ -0,0 +1,77 @@
+use crate::ruby_api::ValidationError;
+use bluejay_parser::ast::{
+    definition::{DefinitionDocument, SchemaDefinition},
+    executable::ExecutableDocument,
+};
+use bluejay_validator::executable::{BuiltinRulesValidator, Cache as ValidationCache};
+use self_cell::self_cell;
+use std::fmt;
+
+self_cell!(
+    /// The parsed SDL, which borrows from the source it owns
+    struct ParsedDocument {
+        owner: String,
+
+        #[covariant]
+        dependent: DefinitionDocument,
+    }
+);
+
+self_cell!(
+    /// The schema definition, which borrows from the document it owns
+    struct ParsedSchema {
+        owner: ParsedDocument,
+
+        #[not_covariant]
+        dependent: SchemaDefinition,
+    }
+);
+
+/// A copy of a schema definition that does not reference any Ruby objects, built by parsing the
+/// schema's SDL, so that queries can be validated against it while the GVL is released.
+///
+/// It is built once with the `SchemaDefinition` it belongs to and never invalidated, as a schema
+/// definition cannot change once built. Redefining a schema builds a new definition, which prints
+/// and parses its SDL again for a new snapshot.
+pub struct SchemaSnapshot {
+    schema: ParsedSchema,
+}
+
+impl SchemaSnapshot {
+    /// Returns `None` if `sdl` cannot be parsed into a valid schema definition
+    pub fn new(sdl: String) -> Option<Self> {
+        let document =
+            ParsedDocument::try_new(sdl, |sdl| DefinitionDocument::parse(sdl).map_err(|_| ()))
+                .ok()?;
+        let schema = ParsedSchema::try_new(document, |document| {
+            SchemaDefinition::try_from(document.borrow_dependent()).map_err(|_| ())
+        })
+        .ok()?;
+
+        Some(Self { schema })
+    }
+
+    /// Parses and validates `query`, without interacting with Ruby. Like
+    /// `SchemaDefinition#validate_query`, a query that cannot be parsed has no validation errors.
+    pub fn validate_query(&self, query: &str) -> Vec<ValidationError> {
+        let document = match ExecutableDocument::parse(query) {
+            Ok(document) => document,
+            Err(_) => return Vec::new(),
+        };
+        self.schema.with_dependent(|_, schema_definition| {
+            BuiltinRulesValidator::validate(
+                &document,
+                schema_definition,
+                &ValidationCache::new(&document, schema_definition),
+            )
+            .map(ValidationError::from)
+            .collect()
+        })
+    }
+}
+
+impl fmt::Debug for SchemaSnapshot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SchemaSnapshot").finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,525 @@
+use crate::helpers::WrappedDefinition;
+use crate::ruby_api::{
+    ArgumentsDefinition, BaseInputType, BaseOutputType, DirectiveDefinition, Directives,
+    FieldDefinition, FieldsDefinition, InputObjectTypeDefinition, InputType,
+    InterfaceImplementations, InterfaceTypeDefinition, OutputType, TypeDefinition, TypeSystemError,
+    TypeSystemErrorKind,
+};
+use crate::schema_index::SchemaIndex;
+use bluejay_core::definition::{
+    DirectiveDefinition as CoreDirectiveDefinition, DirectiveLocation,
+    EnumTypeDefinition as CoreEnumTypeDefinition, InputType as CoreInputType,
+    ObjectTypeDefinition as CoreObjectTypeDefinition, OutputType as CoreOutputType,
+};
+use bluejay_core::AsIter;
+use itertools::Itertools;
+use std::collections::{BTreeMap, HashSet};
+
+/// Introspection fields that are added to the types by Bluejay itself, and are therefore allowed
+/// to use the reserved `__` prefix
+const BUILTIN_FIELD_NAMES: [&str; 3] = ["__typename", "__schema", "__type"];
+
+/// Validates the type system of a schema against the rules in the
+/// [specification](https://spec.graphql.org/October2021/#sec-Type-System), collecting every
+/// error instead of stopping at the first one
+pub struct TypeSystemValidator<'a> {
+    index: &'a SchemaIndex,
+    errors: Vec<TypeSystemError>,
+}
+
+impl<'a> TypeSystemValidator<'a> {
+    pub fn validate(
+        type_definitions: &BTreeMap<String, TypeDefinition>,
+        directive_definitions: &BTreeMap<String, WrappedDefinition<DirectiveDefinition>>,
+        schema_directives: &Directives,
+        index: &'a SchemaIndex,
+    ) -> Vec<TypeSystemError> {
+        let mut validator = Self {
+            index,
+            errors: Vec::new(),
+        };
+        type_definitions
+            .values()
+            .for_each(|type_definition| validator.visit_type_definition(type_definition));
+        directive_definitions
+            .values()
+            .for_each(|directive_definition| {
+                validator.visit_directive_definition(directive_definition.as_ref())
+            });
+        validator.visit_directives(schema_directives, DirectiveLocation::Schema, "schema");
+        validator.errors
+    }
+
+    fn push(&mut self, kind: TypeSystemErrorKind, coordinate: &str, message: String) {
+        self.errors
+            .push(TypeSystemError::new(kind, coordinate.to_owned(), message));
+    }
+
+    fn visit_type_definition(&mut self, type_definition: &TypeDefinition) {
+        match type_definition {
+            TypeDefinition::BuiltinScalar(_) => {}
+            TypeDefinition::CustomScalar(cstd) => {
+                let cstd = cstd.as_ref();
+                self.validate_name(cstd.name(), cstd.name());
+                self.visit_directives(cstd.directives(), DirectiveLocation::Scalar, cstd.name());
+            }
+            TypeDefinition::Enum(etd) => {
+                let etd = etd.as_ref();
+                if etd.is_builtin() {
+                    return;
+                }
+                self.validate_name(etd.name(), etd.name());
+                etd.enum_value_definitions().iter().for_each(|evd| {
+                    let coordinate = format!("{}.{}", etd.name(), evd.name());
+                    if matches!(evd.name(), "true" | "false" | "null") {
+                        self.push(
+                            TypeSystemErrorKind::InvalidName,
+                            &coordinate,
+                            format!("`{}` cannot be used as an enum value", evd.name()),
+                        );
+                    } else {
+                        self.validate_name(evd.name(), &coordinate);
+                    }
+                    self.visit_directives(
+                        evd.directives(),
+                        DirectiveLocation::EnumValue,
+                        &coordinate,
+                    );
+                });
+                self.visit_directives(etd.directives(), DirectiveLocation::Enum, etd.name());
+            }
+            TypeDefinition::Object(otd) => {
+                let otd = otd.as_ref();
+                if otd.is_builtin() {
+                    return;
+                }
+                self.validate_name(otd.name(), otd.name());
+                self.visit_fields_definition(otd.name(), otd.fields_definition());
+                self.visit_interface_implementations(
+                    otd.name(),
+                    otd.fields_definition(),
+                    otd.interface_implementations(),
+                );
+                self.visit_directives(otd.directives(), DirectiveLocation::Object, otd.name());
+            }
+            TypeDefinition::Interface(itd) => {
+                let itd = itd.as_ref();
+                self.validate_name(itd.name(), itd.name());
+                self.visit_fields_definition(itd.name(), itd.fields_definition());
+                self.visit_interface_implementations(
+                    itd.name(),
+                    itd.fields_definition(),
+                    itd.interface_implementations(),
+                );
+                self.visit_directives(itd.directives(), DirectiveLocation::Interface, itd.name());
+            }
+            TypeDefinition::Union(utd) => {
+                let utd = utd.as_ref();
+                self.validate_name(utd.name(), utd.name());
+                let member_types = utd.member_types();
+                if member_types.is_empty() {
+                    self.push(
+                        TypeSystemErrorKind::EmptyUnion,
+                        utd.name(),
+                        format!("Union `{}` must have at least one member type", utd.name()),
+                    );
+                }
+                member_types
+                    .iter()
+                    .map(|member_type| member_type.r#type().as_ref().name().to_owned())
+                    .duplicates()
+                    .for_each(|member_type_name| {
+                        self.push(
+                            TypeSystemErrorKind::NonUniqueUnionMember,
+                            utd.name(),
+                            format!(
+                                "Union `{}` includes member type `{member_type_name}` more than once",
+                                utd.name(),
+                            ),
+                        )
+                    });
+                self.visit_directives(utd.directives(), DirectiveLocation::Union, utd.name());
+            }
+            TypeDefinition::InputObject(iotd) => {
+                let iotd = iotd.as_ref();
+                self.validate_name(iotd.name(), iotd.name());
+                iotd.input_fields_definition().iter().for_each(|ivd| {
+                    let coordinate = format!("{}.{}", iotd.name(), ivd.name());
+                    self.validate_name(ivd.name(), &coordinate);
+                    self.visit_directives(
+                        ivd.directives(),
+                        DirectiveLocation::InputFieldDefinition,
+                        &coordinate,
+                    );
+                });
+                self.validate_input_object_cycles(iotd);
+                self.visit_directives(
+                    iotd.directives(),
+                    DirectiveLocation::InputObject,
+                    iotd.name(),
+                );
+            }
+        }
+    }
+
+    fn visit_directive_definition(&mut self, directive_definition: &DirectiveDefinition) {
+        if directive_definition.is_builtin() {
+            return;
+        }
+        let coordinate = format!("@{}", directive_definition.name());
+        self.validate_name(directive_definition.name(), &coordinate);
+        self.visit_arguments_definition(&coordinate, &directive_definition.arguments_definition());
+        let locations = directive_definition.locations();
+        if locations.is_empty() {
+            self.push(
+                TypeSystemErrorKind::MissingDirectiveLocations,
+                &coordinate,
+                format!("Directive {coordinate} must have at least one location"),
+            );
+        }
+        locations
+            .iter()
+            .enumerate()
+            .filter(|(idx, location)| locations[..*idx].contains(location))
+            .map(|(_, location)| location)
+            .dedup()
+            .for_each(|location| {
+                self.push(
+                    TypeSystemErrorKind::NonUniqueDirectiveLocation,
+                    &coordinate,
+                    format!("Directive {coordinate} includes location {location} more than once"),
+                )
+            });
+    }
+
+    fn visit_fields_definition(&mut self, type_name: &str, fields_definition: &FieldsDefinition) {
+        fields_definition.iter().for_each(|field_definition| {
+            let coordinate = format!("{type_name}.{}", field_definition.name());
+            if !BUILTIN_FIELD_NAMES.contains(&field_definition.name()) {
+                self.validate_name(field_definition.name(), &coordinate);
+            }
+            self.visit_arguments_definition(&coordinate, field_definition.argument_definitions());
+            self.visit_directives(
+                field_definition.directives(),
+                DirectiveLocation::FieldDefinition,
+                &coordinate,
+            );
+        });
+    }
+
+    fn visit_arguments_definition(
+        &mut self,
+        parent_coordinate: &str,
+        arguments_definition: &ArgumentsDefinition,
+    ) {
+        arguments_definition.iter().for_each(|ivd| {
+            let coordinate = format!("{parent_coordinate}({}:)", ivd.name());
+            self.validate_name(ivd.name(), &coordinate);
+            self.visit_directives(
+                ivd.directives(),
+                DirectiveLocation::ArgumentDefinition,
+                &coordinate,
+            );
+        });
+    }
+
+    fn visit_directives(
+        &mut self,
+        directives: &Directives,
+        location: DirectiveLocation,
+        coordinate: &str,
+    ) {
+        let mut seen_names: HashSet<&str> = HashSet::new();
+        directives.iter().for_each(|directive| {
+            let directive_definition = directive.definition().as_ref();
+            if !directive_definition.locations().contains(&location) {
+                self.push(
+                    TypeSystemErrorKind::DirectiveInInvalidLocation,
+                    coordinate,
+                    format!(
+                        "Directive @{} cannot be used at location {location} on `{coordinate}`. It is only allowed at the following locations: {}",
+                        directive_definition.name(),
+                        directive_definition.locations().iter().join(", "),
+                    ),
+                );
+            }
+            if !directive_definition.is_repeatable()
+                && !seen_names.insert(directive_definition.name())
+            {
+                self.push(
+                    TypeSystemErrorKind::NonRepeatableDirectiveRepeated,
+                    coordinate,
+                    format!(
+                        "Directive @{} is not repeatable but was used multiple times on `{coordinate}`",
+                        directive_definition.name(),
+                    ),
+                );
+            }
+        });
+    }
+
+    fn visit_interface_implementations(
+        &mut self,
+        type_name: &str,
+        fields_definition: &FieldsDefinition,
+        interface_implementations: &InterfaceImplementations,
+    ) {
+        let interfaces: Vec<WrappedDefinition<InterfaceTypeDefinition>> = interface_implementations
+            .iter()
+            .map(|interface_implementation| interface_implementation.interface())
+            .collect();
+
+        interfaces.iter().for_each(|itd| {
+            let itd = itd.as_ref();
+            if itd.name() == type_name {
+                self.push(
+                    TypeSystemErrorKind::SelfImplementingInterface,
+                    type_name,
+                    format!("Interface `{type_name}` cannot implement itself"),
+                );
+                return;
+            }
+
+            itd.interface_implementations()
+                .iter()
+                .for_each(|transitive_interface_implementation| {
+                    let transitive_interface = transitive_interface_implementation.interface();
+                    let transitive_name = transitive_interface.as_ref().name();
+                    if transitive_name != type_name
+                        && !interfaces
+                            .iter()
+                            .any(|interface| interface.as_ref().name() == transitive_name)
+                    {
+                        self.push(
+                            TypeSystemErrorKind::MissingTransitiveInterface,
+                            type_name,
+                            format!(
+                                "`{type_name}` must implement `{transitive_name}` because it is implemented by `{}`",
+                                itd.name(),
+                            ),
+                        );
+                    }
+                });
+
+            itd.fields_definition()
+                .iter()
+                .for_each(|interface_field_definition| {
+                    match self
+                        .index
+                        .field_position(type_name, interface_field_definition.name())
+                        .and_then(|position| fields_definition.iter().nth(position))
+                    {
+                        Some(field_definition) => self.validate_implementation_field(
+                            type_name,
+                            itd.name(),
+                            field_definition,
+                            interface_field_definition,
+                        ),
+                        None => self.push(
+                            TypeSystemErrorKind::MissingInterfaceField,
+                            &format!("{type_name}.{}", interface_field_definition.name()),
+                            format!(
+                                "`{type_name}` must define field `{}` required by interface `{}`",
+                                interface_field_definition.name(),
+                                itd.name(),
+                            ),
+                        ),
+                    }
+                });
+        });
+    }
+
+    fn validate_implementation_field(
+        &mut self,
+        type_name: &str,
+        interface_name: &str,
+        field_definition: &FieldDefinition,
+        interface_field_definition: &FieldDefinition,
+    ) {
+        let coordinate = format!("{type_name}.{}", field_definition.name());
+
+        if !self.is_valid_implementation_field_type(
+            field_definition.r#type(),
+            interface_field_definition.r#type(),
+        ) {
+            self.push(
+                TypeSystemErrorKind::InvalidInterfaceFieldType,
+                &coordinate,
+                format!(
+                    "Field `{coordinate}` has type `{}`, which is not compatible with type `{}` defined by interface `{interface_name}`",
+                    field_definition.r#type().as_ref().display_name(),
+                    interface_field_definition.r#type().as_ref().display_name(),
+                ),
+            );
+        }
+
+        let arguments_definition = field_definition.argument_definitions();
+        let interface_arguments_definition = interface_field_definition.argument_definitions();
+
+        interface_arguments_definition
+            .iter()
+            .for_each(|interface_ivd| {
+                let argument_coordinate = format!("{coordinate}({}:)", interface_ivd.name());
+                match arguments_definition
+                    .iter()
+                    .find(|ivd| ivd.name() == interface_ivd.name())
+                {
+                    Some(ivd) => {
+                        let display_name = ivd.r#type().as_ref().display_name();
+                        let interface_display_name =
+                            interface_ivd.r#type().as_ref().display_name();
+                        if display_name != interface_display_name {
+                            self.push(
+                                TypeSystemErrorKind::InvalidInterfaceFieldArgumentType,
+                                &argument_coordinate,
+                                format!(
+                                    "Argument `{argument_coordinate}` has type `{display_name}`, but interface `{interface_name}` defines it with type `{interface_display_name}`",
+                                ),
+                            );
+                        }
+                    }
+                    None => self.push(
+                        TypeSystemErrorKind::MissingInterfaceFieldArgument,
+                        &argument_coordinate,
+                        format!(
+                            "Field `{coordinate}` must define argument `{}` required by interface `{interface_name}`",
+                            interface_ivd.name(),
+                        ),
+                    ),
+                }
+            });
+
+        arguments_definition
+            .iter()
+            .filter(|ivd| {
+                ivd.r#type().is_required()
+                    && !interface_arguments_definition
+                        .iter()
+                        .any(|interface_ivd| interface_ivd.name() == ivd.name())
+            })
+            .for_each(|ivd| {
+                let argument_coordinate = format!("{coordinate}({}:)", ivd.name());
+                self.push(
+                    TypeSystemErrorKind::RequiredAdditionalArgument,
+                    &argument_coordinate,
+                    format!(
+                        "Argument `{argument_coordinate}` is not defined by interface `{interface_name}` and must therefore be optional",
+                    ),
+                );
+            });
+    }
+
+    /// Whether `field_type` is a valid (covariant) implementation of `implemented_type`
+    fn is_valid_implementation_field_type(
+        &self,
+        field_type: &OutputType,
+        implemented_type: &OutputType,
+    ) -> bool {
+        if implemented_type.as_ref().is_required() && !field_type.as_ref().is_required() {
+            return false;
+        }
+
+        match (field_type, implemented_type) {
+            (OutputType::List(inner, _), OutputType::List(implemented_inner, _)) => {
+                self.is_valid_implementation_field_type(inner.get(), implemented_inner.get())
+            }
+            (OutputType::Base(base, _), OutputType::Base(implemented_base, _)) => {
+                self.is_subtype(base, implemented_base)
+            }
+            _ => false,
+        }
+    }
+
+    fn is_subtype(&self, base: &BaseOutputType, implemented_base: &BaseOutputType) -> bool {
+        if base.name() == implemented_base.name() {
+            return true;
+        }
+
+        match (base, implemented_base) {
+            (
+                BaseOutputType::Object(_),
+                BaseOutputType::Union(_) | BaseOutputType::Interface(_),
+            ) => self
+                .index
+                .is_possible_type(implemented_base.name(), base.name()),
+            (BaseOutputType::Interface(_), BaseOutputType::Interface(_)) => {
+                self.index.implements(base.name(), implemented_base.name())
+            }
+            _ => false,
+        }
+    }
+
+    fn validate_input_object_cycles(&mut self, iotd: &InputObjectTypeDefinition) {
+        let mut visited = HashSet::new();
+        let mut path = Vec::new();
+        if !Self::find_non_null_cycle(iotd.name(), iotd, &mut visited, &mut path) {
+            return;
+        }
+
+        // every input object in the cycle would find it, report it only from the first one
+        if path
+            .iter()
+            .any(|(type_name, _)| type_name.as_str() < iotd.name())
+        {
+            return;
+        }
+
+        self.push(
+            TypeSystemErrorKind::NonNullInputObjectCycle,
+            iotd.name(),
+            format!(
+                "Input object `{}` references itself through non-null fields that cannot be broken: {}",
+                iotd.name(),
+                path.iter()
+                    .map(|(type_name, field_name)| format!("`{type_name}.{field_name}`"))
+                    .join(" -> "),
+            ),
+        );
+    }
+
+    fn find_non_null_cycle(
+        root_name: &str,
+        iotd: &InputObjectTypeDefinition,
+        visited: &mut HashSet<String>,
+        path: &mut Vec<(String, String)>,
+    ) -> bool {
+        iotd.input_fields_definition().iter().any(|ivd| {
+            if let InputType::Base(BaseInputType::InputObject(field_iotd), true) = ivd.r#type() {
+                let field_iotd = field_iotd.as_ref();
+                path.push((iotd.name().to_owned(), ivd.name().to_owned()));
+                if field_iotd.name() == root_name
+                    || (visited.insert(field_iotd.name().to_owned())
+                        && Self::find_non_null_cycle(root_name, field_iotd, visited, path))
+                {
+                    return true;
+                }
+                path.pop();
+            }
+            false
+        })
+    }
+
+    fn validate_name(&mut self, name: &str, coordinate: &str) {
+        if !Self::is_valid_name(name) {
+            self.push(
+                TypeSystemErrorKind::InvalidName,
+                coordinate,
+                format!("`{name}` is not a valid GraphQL name"),
+            );
+        } else if name.starts_with("__") {
+            self.push(
+                TypeSystemErrorKind::ReservedName,
+                coordinate,
+                format!("`{name}` is invalid because names starting with `__` are reserved for introspection"),
+            );
+        }
+    }
+
+    fn is_valid_name(name: &str) -> bool {
+        let mut chars = name.chars();
+        chars
+            .next()
+            .map_or(false, |c| c == '_' || c.is_ascii_alphabetic())
+            && chars.all(|c| c == '_' || c.is_ascii_alphanumeric())
+    }
+}
@@ -1,5 +1,8 @@
 use crate::helpers::Warden;
 use crate::ruby_api::SchemaDefinition;
+use crate::schema_index::SchemaIndex;
+use bluejay_core::definition::{prelude::*, OutputType as CoreOutputType};
+use bluejay_core::AsIter;
 use bluejay_visibility::Cache;
 
 pub type VisibilityCache<'a> = Cache<'a, SchemaDefinition, Warden>;
@@ -18,6 +21,7 @@ scoped_types!(
     SchemaDefinition,
     ObjectTypeDefinition,
     FieldDefinition,
+    FieldsDefinition,
     OutputType,
     BaseOutputType,
     InputValueDefinition,
@@ -30,3 +34,65 @@ scoped_types!(
     InterfaceTypeDefinition,
     UnionTypeDefinition,
 );
+
+/// An object, interface or union type, i.e. a type that a selection set can be made on
+#[derive(Clone, Copy)]
+pub enum ScopedCompositeType<'a> {
+    Object(&'a ScopedObjectTypeDefinition<'a>),
+    Interface(&'a ScopedInterfaceTypeDefinition<'a>),
+    Union(&'a ScopedUnionTypeDefinition<'a>),
+}
+
+impl<'a> ScopedCompositeType<'a> {
+    pub fn from_output_type(output_type: &'a ScopedOutputType<'a>) -> Option<Self> {
+        match output_type.as_ref().base() {
+            ScopedBaseOutputType::Object(otd) => Some(Self::Object(otd)),
+            ScopedBaseOutputType::Interface(itd) => Some(Self::Interface(itd)),
+            ScopedBaseOutputType::Union(utd) => Some(Self::Union(utd)),
+            _ => None,
+        }
+    }
+
+    pub fn name(self) -> &'a str {
+        match self {
+            Self::Object(otd) => otd.name(),
+            Self::Interface(itd) => itd.name(),
+            Self::Union(utd) => utd.name(),
+        }
+    }
+
+    pub fn field_definition(
+        self,
+        name: &str,
+        index: &SchemaIndex,
+    ) -> Option<&'a ScopedFieldDefinition<'a>> {
+        match self {
+            Self::Object(otd) => {
+                indexed_field_definition(otd.name(), otd.fields_definition(), name, index)
+            }
+            Self::Interface(itd) => {
+                indexed_field_definition(itd.name(), itd.fields_definition(), name, index)
+            }
+            Self::Union(utd) => utd.fields_definition().get(name),
+        }
+    }
+}
+
+/// Looks up a field of an object or interface type using the schema index. When no definition in
+/// the schema has a visibility, the scoped fields definition contains every field in the same
+/// order as the underlying one, so the indexed position can be used directly. The scoped fields
+/// are stored contiguously, so indexing into them does not walk the fields before the position.
+pub fn indexed_field_definition<'a>(
+    type_name: &str,
+    fields_definition: &'a ScopedFieldsDefinition<'a>,
+    name: &str,
+    index: &SchemaIndex,
+) -> Option<&'a ScopedFieldDefinition<'a>> {
+    if index.has_visibility() {
+        fields_definition.get(name)
+    } else {
+        index
+            .field_position(type_name, name)
+            .and_then(|position| fields_definition.iter().as_slice().get(position))
+    }
+}
@@ -4,6 +4,7 @@ require_relative "bluejay/version"
 require "sorbet-runtime"
 require_relative "bluejay/finalize"
 require_relative "bluejay/name_from_class"
+require_relative "bluejay/typename_enum"
 require_relative "bluejay/visibility"
 require_relative "bluejay/base"
 require_relative "bluejay/base_input_type"
@@ -11,7 +12,9 @@ require_relative "bluejay/base_output_type"
 require_relative "bluejay/input_type_shorthands"
 require_relative "bluejay/output_type_shorthands"
 require_relative "bluejay/json_value"
+require_relative "bluejay/undefined"
 require_relative "bluejay/custom_scalar_type"
+require_relative "bluejay/native_scalar_type"
 require_relative "bluejay/directive"
 require_relative "bluejay/enum_type"
 require_relative "bluejay/interface_type"
@@ -21,6 +24,9 @@ require_relative "bluejay/query_root"
 require_relative "bluejay/schema"
 require_relative "bluejay/union_type"
 require_relative "bluejay/errors"
+require_relative "bluejay/persisted_queries"
+require_relative "bluejay/normalize_operation"
+require_relative "bluejay/print_query"
 require_relative "bluejay/builtin"
 require_relative "bluejay/builtin/directives/deprecated"
 require_relative "bluejay/builtin/directives/include"
@@ -0,0 +1,405 @@
+# typed: strict
+# frozen_string_literal: true
+
+require "graphql"
+require "bluejay"
+
+module Bluejay
+  module Compat
+    # Converts a graphql-ruby schema into an equivalent Bluejay schema, adapting the graphql-ruby resolvers, so that
+    # both can execute the same queries side by side (e.g. in shadow mode) and their results can be compared.
+    #
+    # Resolvers are called with the `context` passed to `execute` instead of a `GraphQL::Query::Context`, and
+    # graphql-ruby features that depend on the query (authorization, lazy resolution, argument loading, custom
+    # directives and extensions) are not supported.
+    class GraphQLRuby
+      extend(T::Sig)
+
+      BUILTIN_SCALARS = T.let(
+        {
+          "String" => Scalar::String,
+          "Int" => Scalar::Int,
+          "Float" => Scalar::Float,
+          "Boolean" => Scalar::Boolean,
+          "ID" => Scalar::ID,
+        }.freeze,
+        T::Hash[String, Scalar],
+      )
+
+      # Prefix of the resolver methods of the converted object types, to avoid clashing with the wrapper's methods
+      RESOLVER_METHOD_PREFIX = "graphql_ruby_"
+
+      # Wraps a value resolved by graphql-ruby with the Bluejay object type it was resolved to
+      class ObjectWrapper
+        extend(T::Sig)
+
+        sig { returns(T.untyped) }
+        attr_reader :object
+
+        sig { returns(T.untyped) }
+        attr_reader :context
+
+        sig { params(object: T.untyped, context: T.untyped).void }
+        def initialize(object, context)
+          @object = object
+          @context = context
+        end
+      end
+
+      class << self
+        extend(T::Sig)
+
+        sig { params(graphql_schema: T.class_of(GraphQL::Schema)).returns(GraphQLRuby) }
+        def convert(graphql_schema)
+          new(graphql_schema)
+        end
+      end
+
+      sig { returns(T.class_of(Schema)) }
+      attr_reader :schema
+
+      sig { params(graphql_schema: T.class_of(GraphQL::Schema)).void }
+      def initialize(graphql_schema)
+        @graphql_schema = graphql_schema
+        @types = T.let({}, T::Hash[String, T.any(BaseInputType, BaseOutputType)])
+        @wrappers = T.let({}, T::Hash[String, T.class_of(ObjectWrapper)])
+        @namespace = T.let(Module.new, Module)
+
+        graphql_schema.types.each_value do |type|
+          next if type.graphql_name.start_with?("__") || BUILTIN_SCALARS.key?(type.graphql_name)
+
+          @types[type.graphql_name] = convert_type(type)
+        end
+
+        @schema = T.let(build_schema, T.class_of(Schema))
+        @root_class = T.let(Struct.new(:query, :mutation), T.class_of(Struct))
+        @root_class.include(T.unsafe(@schema).const_get(:Root))
+      end
+
+      sig do
+        params(
+          query: String,
+          operation_name: T.nilable(String),
+          variables: T::Hash[String, T.untyped],
+          context: T.untyped,
+          root_value: T.untyped,
+        ).returns(ExecutionResult)
+      end
+      def execute(query:, operation_name: nil, variables: {}, context: nil, root_value: nil)
+        query_type = @graphql_schema.query
+        mutation_type = @graphql_schema.mutation
+        initial_value = @root_class.new(
+          wrap_object(query_type, root_value, context),
+          mutation_type && wrap_object(mutation_type, root_value, context),
+        )
+
+        @schema.execute(query:, operation_name:, variables:, context:, initial_value:)
+      end
+
+      private
+
+      sig { returns(T.class_of(Schema)) }
+      def build_schema
+        mutation_type = @graphql_schema.mutation
+        @namespace.const_set(:QueryRoot, @types.fetch(@graphql_schema.query.graphql_name))
+        @namespace.const_set(:MutationRoot, mutation_type && @types.fetch(mutation_type.graphql_name))
+        @namespace.const_set(:DESCRIPTION, @graphql_schema.description)
+        # the class keyword is needed for `Schema` to be finalized, which happens at the end of its definition
+        @namespace.module_eval(<<~RUBY, __FILE__, __LINE__ + 1)
+          class Schema < ::Bluejay::Schema
+            class << self
+              def description = DESCRIPTION
+              def query = QueryRoot
+              def mutation = MutationRoot
+            end
+          end
+        RUBY
+        @namespace.const_get(:Schema)
+      end
+
+      sig { params(type: T.untyped).returns(T.any(BaseInputType, BaseOutputType)) }
+      def convert_type(type)
+        kind = type.kind
+        if kind.object?
+          convert_object_type(type)
+        elsif kind.interface?
+          convert_interface_type(type)
+        elsif kind.union?
+          convert_union_type(type)
+        elsif kind.enum?
+          convert_enum_type(type)
+        elsif kind.input_object?
+          convert_input_object_type(type)
+        else
+          convert_custom_scalar_type(type)
+        end
+      end
+
+      sig { params(type: T.untyped).returns(T.class_of(ObjectType)) }
+      def convert_object_type(type)
+        converter = self
+        is_query_root = type == @graphql_schema.query
+        object_type = Class.new(is_query_root ? QueryRoot : ObjectType)
+        define_common_methods(object_type, type)
+        object_type.define_singleton_method(:field_definitions) { converter.send(:field_definitions, type) }
+        object_type.define_singleton_method(:interface_implementations) do
+          type.interfaces.map do |interface|
+            InterfaceImplementation.new(interface: converter.send(:bluejay_type, interface))
+          end
+        end
+        object_type
+      end
+
+      sig { params(type: T.untyped).returns(T.class_of(InterfaceType)) }
+      def convert_interface_type(type)
+        converter = self
+        interface_type = Class.new(InterfaceType)
+        define_common_methods(interface_type, type)
+        interface_type.define_singleton_method(:field_definitions) { converter.send(:field_definitions, type) }
+        interface_type.define_singleton_method(:interface_implementations) do
+          type.interfaces.map do |interface|
+            InterfaceImplementation.new(interface: converter.send(:bluejay_type, interface))
+          end
+        end
+        interface_type
+      end
+
+      sig { params(type: T.untyped).returns(T.class_of(UnionType)) }
+      def convert_union_type(type)
+        converter = self
+        union_type = Class.new(UnionType)
+        define_common_methods(union_type, type)
+        union_type.define_singleton_method(:member_types) do
+          type.possible_types.map { |member| UnionMemberType.new(type: converter.send(:bluejay_type, member)) }
+        end
+        union_type
+      end
+
+      sig { params(type: T.untyped).returns(T.class_of(EnumType)) }
+      def convert_enum_type(type)
+        enum_type = Class.new(EnumType)
+        define_common_methods(enum_type, type)
+        enum_type.define_singleton_method(:enum_value_definitions) do
+          type.values.each_value.map do |value|
+            EnumValueDefinition.new(
+              name: value.graphql_name,
+              description: value.description,
+              deprecation_reason: value.deprecation_reason,
+            )
+          end
+        end
+        enum_type
+      end
+
+      sig { params(type: T.untyped).returns(T.class_of(InputObjectType)) }
+      def convert_input_object_type(type)
+        converter = self
+        input_object_type = Class.new(InputObjectType)
+        define_common_methods(input_object_type, type)
+        input_object_type.define_singleton_method(:input_field_definitions) do
+          type.arguments.each_value.map { |argument| converter.send(:input_value_definition, argument) }
+        end
+        input_object_type
+      end
+
+      sig { params(type: T.untyped).returns(T.class_of(CustomScalarType)) }
+      def convert_custom_scalar_type(type)
+        custom_scalar_type = Class.new(CustomScalarType)
+        define_common_methods(custom_scalar_type, type)
+        custom_scalar_type.define_singleton_method(:specified_by_url) { type.specified_by_url }
+        custom_scalar_type.define_singleton_method(:coerce_input_exception_class) { GraphQL::CoercionError }
+        custom_scalar_type.define_singleton_method(:coerce_result_exception_class) { GraphQL::CoercionError }
+        custom_scalar_type.define_singleton_method(:coerce_input) do |value|
+          type.coerce_input(value, GraphQL::Query::NullContext)
+        end
+        custom_scalar_type.define_singleton_method(:coerce_result) do |value|
+          type.coerce_result(value, GraphQL::Query::NullContext)
+        end
+        custom_scalar_type
+      end
+
+      sig { params(bluejay_type: Module, type: T.untyped).void }
+      def define_common_methods(bluejay_type, type)
+        bluejay_type.define_singleton_method(:graphql_name) { type.graphql_name }
+        bluejay_type.define_singleton_method(:description) { type.description }
+      end
+
+      sig { params(type: T.untyped).returns(T::Array[FieldDefinition]) }
+      def field_definitions(type)
+        type.fields.each_value.map do |field|
+          FieldDefinition.new(
+            name: field.graphql_name,
+            type: output_type(field.type),
+            argument_definitions: field.arguments.each_value.map { |argument| input_value_definition(argument) },
+            description: field.description,
+            resolver_method_name: resolver_method_name(field),
+            deprecation_reason: field.deprecation_reason,
+          )
+        end
+      end
+
+      sig { params(argument: T.untyped).returns(InputValueDefinition) }
+      def input_value_definition(argument)
+        InputValueDefinition.new(
+          name: argument.graphql_name,
+          type: input_type(argument.type),
+          description: argument.description,
+          ruby_name: argument.keyword.to_s,
+          default_value: argument.default_value? ? default_value(argument.type, argument.default_value) : nil,
+          deprecation_reason: argument.deprecation_reason,
+          # graphql-ruby does not pass absent arguments to resolvers
+          absent_behavior: :omit,
+        )
+      end
+
+      sig { params(type: T.untyped).returns(OutputType) }
+      def output_type(type)
+        required = type.non_null?
+        type = type.of_type if required
+        if type.list?
+          OutputType.list(type: output_type(type.of_type), required:)
+        else
+          OutputType.new(type: T.cast(bluejay_type(type), BaseOutputType), required:)
+        end
+      end
+
+      sig { params(type: T.untyped).returns(InputType) }
+      def input_type(type)
+        required = type.non_null?
+        type = type.of_type if required
+        if type.list?
+          InputType.list(type: input_type(type.of_type), required:)
+        else
+          InputType.new(type: T.cast(bluejay_type(type), BaseInputType), required:)
+        end
+      end
+
+      sig { params(type: T.untyped).returns(T.any(BaseInputType, BaseOutputType)) }
+      def bluejay_type(type)
+        BUILTIN_SCALARS[type.graphql_name] || @types.fetch(type.graphql_name)
+      end
+
+      sig { params(field: T.untyped).returns(String) }
+      def resolver_method_name(field)
+        "#{RESOLVER_METHOD_PREFIX}#{field.graphql_name}"
+      end
+
+      # Converts a graphql-ruby default value, which is a Ruby value, to the GraphQL value Bluejay expects
+      sig { params(type: T.untyped, value: T.untyped).returns(T.untyped) }
+      def default_value(type, value)
+        type = type.of_type if type.non_null?
+        if value.nil?
+          nil
+        elsif type.list?
+          Array(value).map { |item| default_value(type.of_type, item) }
+        elsif type.kind.enum?
+          type.values.each_value.find { |enum_value| enum_value.value == value }&.graphql_name || value
+        elsif type.kind.input_object?
+          type.arguments.each_value.with_object({}) do |argument, hash|
+            key = [argument.keyword, argument.keyword.to_s, argument.graphql_name].find { |k| value.key?(k) }
+            hash[argument.graphql_name] = default_value(argument.type, value[key]) unless key.nil?
+          end
+        else
+          value
+        end
+      end
+
+      sig { params(type: T.untyped).returns(T.class_of(ObjectWrapper)) }
+      def build_wrapper(type)
+        converter = self
+        wrapper = Class.new(ObjectWrapper)
+        wrapper.include(T.unsafe(@types.fetch(type.graphql_name)).const_get(:Interface))
+        type.fields.each_value do |field|
+          wrapper.define_method(converter.send(:resolver_method_name, field)) do |**args|
+            converter.send(:resolve_field, type, field, object, context, args)
+          end
+        end
+        wrapper
+      end
+
+      sig do
+        params(
+          type: T.untyped,
+          field: T.untyped,
+          object: T.untyped,
+          context: T.untyped,
+          args: T::Hash[Symbol, T.untyped],
+        ).returns(T.untyped)
+      end
+      def resolve_field(type, field, object, context, args)
+        args = field.arguments.each_value.with_object({}) do |argument, converted|
+          next unless args.key?(argument.keyword)
+
+          converted[argument.keyword] = argument_value(argument.type, args[argument.keyword])
+        end
+        resolver_method = field.resolver_method
+        value = if (resolver = field.resolver)
+          resolver.new(object:, context:, field:).resolve(**args)
+        elsif type.method_defined?(resolver_method) && !GraphQL::Schema::Object.method_defined?(resolver_method)
+          type.send(:new, object, context).public_send(resolver_method, **args)
+        elsif object.is_a?(Hash)
+          key = field.hash_key || field.method_sym
+          object.key?(key) ? object[key] : object[key.to_s]
+        else
+          object.public_send(field.method_sym, **args)
+        end
+        result_value(field.type, value, context)
+      end
+
+      # Converts a value coerced by Bluejay to the value graphql-ruby passes to resolvers
+      sig { params(type: T.untyped, value: T.untyped).returns(T.untyped) }
+      def argument_value(type, value)
+        type = type.of_type if type.non_null?
+        if value.nil?
+          nil
+        elsif type.list?
+          value.map { |item| argument_value(type.of_type, item) }
+        elsif type.kind.enum?
+          type.values.fetch(value).value
+        elsif type.kind.input_object?
+          ruby_kwargs = type.arguments.each_value.with_object({}) do |argument, kwargs|
+            kwargs[argument.keyword] = argument_value(argument.type, value.public_send(argument.keyword))
+          end
+          type.new(nil, ruby_kwargs:, context: GraphQL::Query::NullContext, defaults_used: Set.new)
+        else
+          value
+        end
+      end
+
+      # Converts a value resolved by graphql-ruby to the value Bluejay expects for the type
+      sig { params(type: T.untyped, value: T.untyped, context: T.untyped).returns(T.untyped) }
+      def result_value(type, value, context)
+        type = type.of_type if type.non_null?
+        if value.nil?
+          nil
+        elsif type.list?
+          value.map { |item| result_value(type.of_type, item, context) }
+        elsif type.kind.enum?
+          type.values.each_value.find { |enum_value| enum_value.value == value }&.graphql_name
+        elsif type.kind.object?
+          wrap_object(type, value, context)
+        elsif type.kind.abstract?
+          object_type, value = resolve_type(type, value, context)
+          wrap_object(object_type, value, context)
+        else
+          value
+        end
+      end
+
+      sig { params(type: T.untyped, value: T.untyped, context: T.untyped).returns([T.untyped, T.untyped]) }
+      def resolve_type(type, value, context)
+        resolved = if type.respond_to?(:resolve_type)
+          type.resolve_type(value, context)
+        else
+          @graphql_schema.resolve_type(type, value, context)
+        end
+        resolved.is_a?(Array) ? [resolved[0], resolved[1]] : [resolved, value]
+      end
+
+      sig { params(type: T.untyped, value: T.untyped, context: T.untyped).returns(ObjectWrapper) }
+      def wrap_object(type, value, context)
+        (@wrappers[type.graphql_name] ||= build_wrapper(type)).new(value, context)
+      end
+    end
+  end
+end
@@ -35,14 +35,36 @@ module Bluejay
         nil
       end
 
-      sig { overridable.params(value: InternalRepresentation).returns(Result[T.untyped, String]) }
+      # When this returns an exception class, `coerce_input` returns the coerced value directly and raises an
+      # instance of the class when the value cannot be coerced, instead of returning a `Result`
+      sig { overridable.returns(T.nilable(T.class_of(StandardError))) }
+      def coerce_input_exception_class
+        nil
+      end
+
+      # The equivalent of `coerce_input_exception_class` for `coerce_result`
+      sig { overridable.returns(T.nilable(T.class_of(StandardError))) }
+      def coerce_result_exception_class
+        nil
+      end
+
+      sig { overridable.params(value: InternalRepresentation).returns(T.untyped) }
       def coerce_result(value)
-        Result.ok(value)
+        coerce_result_exception_class ? value : Result.ok(value)
       end
 
-      sig { abstract.params(value: T.untyped).returns(Result[InternalRepresentation, String]) }
+      sig do
+        abstract.params(value: T.untyped).returns(T.any(Result[InternalRepresentation, String], InternalRepresentation))
+      end
       def coerce_input(value); end
 
+      # When this returns the name of one of the scalars in `Bluejay::NativeScalars`, coercion is performed natively
+      # and `coerce_input` and `coerce_result` are not called
+      sig { overridable.returns(T.nilable(Symbol)) }
+      def native_scalar
+        nil
+      end
+
       sig { overridable.returns(String) }
       def internal_representation_sorbet_type_name
         const_get(:InternalRepresentation).name
@@ -60,13 +82,26 @@ module Bluejay
             specified_by_url:,
             ruby_class: self,
             internal_representation_sorbet_type_name:,
-            input_coercion_method_signature: CustomScalarTypeDefinition::CoercionMethodSignature::Result,
-            result_coercion_method_signature: CustomScalarTypeDefinition::CoercionMethodSignature::Result,
+            input_coercion_method_signature: coercion_method_signature(coerce_input_exception_class),
+            result_coercion_method_signature: coercion_method_signature(coerce_result_exception_class),
+            native_scalar:,
             visibility: nil,
           ),
           T.nilable(CustomScalarTypeDefinition),
         )
       end
+
+      sig do
+        params(exception_class: T.nilable(T.class_of(StandardError)))
+          .returns(CustomScalarTypeDefinition::CoercionMethodSignature)
+      end
+      def coercion_method_signature(exception_class)
+        if exception_class
+          CustomScalarTypeDefinition::CoercionMethodSignature.exception(exception_class)
+        else
+          CustomScalarTypeDefinition::CoercionMethodSignature::Result
+        end
+      end
     end
   end
 end
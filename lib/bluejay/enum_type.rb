@@ -3,6 +3,9 @@
 
 module Bluejay
   class EnumType
+    # Enum values with names matching this pattern are exposed as constants on the enum type
+    VALUE_CONSTANT_NAME = T.let(/\A[A-Z]\w*\z/, Regexp)
+
     class << self
       extend(T::Sig)
       extend(T::Helpers)
@@ -31,17 +34,30 @@ module Bluejay
 
       private
 
+      sig { params(name: Symbol).returns(T.untyped) }
+      def const_missing(name)
+        definition
+        const_defined?(name, false) ? const_get(name, false) : super
+      end
+
       sig(:final) { override.returns(EnumTypeDefinition) }
       def definition
         @definition ||= T.let(nil, T.nilable(EnumTypeDefinition))
-        @definition ||= EnumTypeDefinition.new(
-          name: graphql_name,
-          enum_value_definitions:,
-          description:,
-          directives:,
-          ruby_class: self,
-          visibility: nil,
-        )
+        @definition ||= begin
+          enum_value_definitions = self.enum_value_definitions
+          enum_value_definitions.each do |enum_value_definition|
+            value = enum_value_definition.name
+            const_set(value, value.freeze) if value.match?(VALUE_CONSTANT_NAME) && !const_defined?(value, false)
+          end
+          EnumTypeDefinition.new(
+            name: graphql_name,
+            enum_value_definitions:,
+            description:,
+            directives:,
+            ruby_class: self,
+            visibility: nil,
+          )
+        end
       end
     end
   end
@@ -17,5 +17,35 @@ module Bluejay
     end
 
     class NonUniqueDefinitionNameError < BaseError; end
+
+    class TypenameEnumError < BaseError; end
+
+    class TypeSystemValidationError < BaseError
+      extend(T::Sig)
+
+      sig { returns(T::Array[TypeSystemError]) }
+      attr_reader :errors
+
+      sig { params(errors: T::Array[TypeSystemError]).void }
+      def initialize(errors)
+        @errors = errors
+        super("Invalid type system. Errors:\n#{errors.map(&:message).join("\n")}")
+      end
+    end
+
+    class PersistedQueryValidationError < BaseError
+      extend(T::Sig)
+
+      sig { returns(T::Hash[String, T::Array[ValidationError]]) }
+      attr_reader :errors
+
+      sig { params(errors: T::Hash[String, T::Array[ValidationError]]).void }
+      def initialize(errors)
+        @errors = errors
+        super("Invalid persisted queries. Errors:\n" + errors.map do |id, document_errors|
+          "#{id}: #{document_errors.map(&:message).join(", ")}"
+        end.join("\n"))
+      end
+    end
   end
 end
@@ -30,6 +30,11 @@ module Bluejay
         []
       end
 
+      sig { overridable.returns(Symbol) }
+      def absent_field_behavior
+        :nil
+      end
+
       sig { params(value: T.untyped, context: T.untyped).returns(Result[T.untyped, T::Array[CoercionError]]) }
       def coerce_input(value, context: nil)
         definition.coerce_input(value, context)
@@ -48,6 +53,7 @@ module Bluejay
             input_field_definitions:,
             description:,
             directives:,
+            absent_field_behavior:,
             ruby_class: self,
             visibility: nil,
           )
@@ -55,17 +61,36 @@ module Bluejay
       end
     end
 
+    extend(T::Sig)
+
     define_method(:initialize) do |**kwargs|
-      self.class.send(:definition).input_field_definitions.each do |ivd|
-        arg = kwargs[ivd.ruby_name]
-        instance_variable_set("@#{ivd.ruby_name}", arg)
+      definition = self.class.send(:definition)
+      definition.input_field_definitions.each do |ivd|
+        if kwargs.key?(ivd.ruby_name)
+          instance_variable_set("@#{ivd.ruby_name}", kwargs[ivd.ruby_name])
+        else
+          case ivd.absent_behavior || definition.absent_field_behavior
+          when :undefined
+            instance_variable_set("@#{ivd.ruby_name}", Undefined)
+          when :nil
+            instance_variable_set("@#{ivd.ruby_name}", nil)
+          end
+          # fields with the `:omit` behavior are left unset, which `key?` tells apart from an explicit `nil`
+        end
       end
       freeze
     end
 
+    # Whether the field with `ruby_name` has a value, `nil` included. Absent fields only lack one when their absent
+    # behavior is `:omit` or `:undefined`.
+    sig { params(ruby_name: Symbol).returns(T::Boolean) }
+    def key?(ruby_name)
+      instance_variable_defined?("@#{ruby_name}") && !Undefined.equal?(instance_variable_get("@#{ruby_name}"))
+    end
+
     define_method(:==) do |other|
       self.class == other.class && self.class.send(:definition).input_field_definitions.all? do |ivd|
-        send(ivd.ruby_name) == other.send(ivd.ruby_name)
+        key?(ivd.ruby_name) == other.key?(ivd.ruby_name) && send(ivd.ruby_name) == other.send(ivd.ruby_name)
       end
     end
   end
@@ -9,6 +9,7 @@ module Bluejay
       include(OutputTypeShorthands)
       include(InputTypeShorthands)
       include(NameFromClass)
+      include(TypenameEnum)
       include(Base::InterfaceType)
 
       abstract!
@@ -36,6 +37,25 @@ module Bluejay
         []
       end
 
+      # Called when a schema containing the interface is defined, with the names of the object types that implement it
+      # in that schema. `Typename` is defined by the first such schema, so a later schema in which another object type
+      # implements the interface raises, as the enum cannot be extended.
+      sig { params(object_type_names: T::Array[String]).void }
+      def define_typename(object_type_names)
+        object_type_names = object_type_names.sort
+        if const_defined?(:Typename, false)
+          typename = T.cast(const_get(:Typename), T.class_of(T::Enum))
+          missing_names = object_type_names - typename.values.map(&:serialize)
+          unless missing_names.empty?
+            raise Errors::TypenameEnumError,
+              "`Typename` of `#{name}` was defined without #{missing_names.map { |n| "`#{n}`" }.join(", ")}, " \
+                "which implement it in another schema"
+          end
+        else
+          const_set(:Typename, typename_enum(object_type_names))
+        end
+      end
+
       private
 
       sig { params(name: Symbol).returns(T.untyped) }
@@ -43,6 +63,11 @@ module Bluejay
         if name == :Interface
           definition
           const_get(:Interface)
+        elsif name == :Typename
+          raise NameError.new(
+            "`Typename` of `#{self.name}` is only defined once a schema containing the interface is defined",
+            name,
+          )
         else
           super
         end
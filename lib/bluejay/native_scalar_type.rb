@@ -0,0 +1,28 @@
+# typed: strict
+# frozen_string_literal: true
+
+module Bluejay
+  # Base class for the custom scalars shipped with the gem, whose input and result coercion run natively
+  class NativeScalarType < CustomScalarType
+    extend(T::Generic)
+
+    InternalRepresentation = type_template
+
+    class << self
+      extend(T::Sig)
+      extend(T::Helpers)
+
+      abstract!
+
+      sig(:final) { override.params(value: T.untyped).returns(Result[InternalRepresentation, String]) }
+      def coerce_input(value)
+        NativeScalars.coerce_input(T.must(native_scalar), value)
+      end
+
+      sig(:final) { override.params(value: InternalRepresentation).returns(Result[T.untyped, String]) }
+      def coerce_result(value)
+        NativeScalars.coerce_result(T.must(native_scalar), value)
+      end
+    end
+  end
+end
@@ -0,0 +1,12 @@
+# typed: strict
+# frozen_string_literal: true
+
+# The native scalars are not loaded by `require "bluejay"`, since some of them depend on `bigdecimal` and `date`.
+# Require this file, or one of the files in `bluejay/native_scalars`, to use them.
+require_relative "native_scalars/big_int"
+require_relative "native_scalars/date"
+require_relative "native_scalars/date_time"
+require_relative "native_scalars/decimal"
+require_relative "native_scalars/json"
+require_relative "native_scalars/url"
+require_relative "native_scalars/uuid"
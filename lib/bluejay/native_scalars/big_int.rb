@@ -0,0 +1,29 @@
+# typed: strict
+# frozen_string_literal: true
+
+module Bluejay
+  module NativeScalars
+    class BigInt < NativeScalarType
+      InternalRepresentation = type_template { { fixed: Integer } }
+
+      class << self
+        extend(T::Sig)
+
+        sig { override.returns(String) }
+        def graphql_name
+          "BigInt"
+        end
+
+        sig { override.returns(T.nilable(String)) }
+        def specified_by_url
+          "https://www.rfc-editor.org/rfc/rfc8259#section-6"
+        end
+
+        sig { override.returns(Symbol) }
+        def native_scalar
+          :big_int
+        end
+      end
+    end
+  end
+end
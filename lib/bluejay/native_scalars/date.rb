@@ -0,0 +1,31 @@
+# typed: strict
+# frozen_string_literal: true
+
+require "date"
+
+module Bluejay
+  module NativeScalars
+    class Date < NativeScalarType
+      InternalRepresentation = type_template { { fixed: ::Date } }
+
+      class << self
+        extend(T::Sig)
+
+        sig { override.returns(String) }
+        def graphql_name
+          "Date"
+        end
+
+        sig { override.returns(T.nilable(String)) }
+        def specified_by_url
+          "https://www.rfc-editor.org/rfc/rfc3339#section-5.6"
+        end
+
+        sig { override.returns(Symbol) }
+        def native_scalar
+          :date
+        end
+      end
+    end
+  end
+end
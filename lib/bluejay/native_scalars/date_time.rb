@@ -0,0 +1,29 @@
+# typed: strict
+# frozen_string_literal: true
+
+module Bluejay
+  module NativeScalars
+    class DateTime < NativeScalarType
+      InternalRepresentation = type_template { { fixed: Time } }
+
+      class << self
+        extend(T::Sig)
+
+        sig { override.returns(String) }
+        def graphql_name
+          "DateTime"
+        end
+
+        sig { override.returns(T.nilable(String)) }
+        def specified_by_url
+          "https://scalars.graphql.org/andimarek/date-time"
+        end
+
+        sig { override.returns(Symbol) }
+        def native_scalar
+          :date_time
+        end
+      end
+    end
+  end
+end
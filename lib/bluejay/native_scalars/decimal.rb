@@ -0,0 +1,31 @@
+# typed: strict
+# frozen_string_literal: true
+
+require "bigdecimal"
+
+module Bluejay
+  module NativeScalars
+    class Decimal < NativeScalarType
+      InternalRepresentation = type_template { { fixed: BigDecimal } }
+
+      class << self
+        extend(T::Sig)
+
+        sig { override.returns(String) }
+        def graphql_name
+          "Decimal"
+        end
+
+        sig { override.returns(T.nilable(String)) }
+        def specified_by_url
+          "https://www.rfc-editor.org/rfc/rfc8259#section-6"
+        end
+
+        sig { override.returns(Symbol) }
+        def native_scalar
+          :decimal
+        end
+      end
+    end
+  end
+end
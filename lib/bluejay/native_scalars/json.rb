@@ -0,0 +1,34 @@
+# typed: strict
+# frozen_string_literal: true
+
+module Bluejay
+  module NativeScalars
+    class JSON < NativeScalarType
+      InternalRepresentation = type_template { { fixed: Bluejay::JsonValue } }
+
+      class << self
+        extend(T::Sig)
+
+        sig { override.returns(String) }
+        def graphql_name
+          "JSON"
+        end
+
+        sig { override.returns(T.nilable(String)) }
+        def specified_by_url
+          "https://www.rfc-editor.org/rfc/rfc8259"
+        end
+
+        sig { override.returns(Symbol) }
+        def native_scalar
+          :json
+        end
+
+        sig { override.returns(String) }
+        def internal_representation_sorbet_type_name
+          "Bluejay::JsonValue"
+        end
+      end
+    end
+  end
+end
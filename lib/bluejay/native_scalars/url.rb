@@ -0,0 +1,29 @@
+# typed: strict
+# frozen_string_literal: true
+
+module Bluejay
+  module NativeScalars
+    class URL < NativeScalarType
+      InternalRepresentation = type_template { { fixed: String } }
+
+      class << self
+        extend(T::Sig)
+
+        sig { override.returns(String) }
+        def graphql_name
+          "URL"
+        end
+
+        sig { override.returns(T.nilable(String)) }
+        def specified_by_url
+          "https://www.rfc-editor.org/rfc/rfc3986"
+        end
+
+        sig { override.returns(Symbol) }
+        def native_scalar
+          :url
+        end
+      end
+    end
+  end
+end
@@ -0,0 +1,18 @@
+# typed: strict
+# frozen_string_literal: true
+
+require "digest"
+
+module Bluejay
+  class << self
+    extend(T::Sig)
+
+    # Normalizes an operation so that operations differing only in their literal values, aliases, formatting, fragment
+    # usage or selection order share a signature. The hash is the SHA-256 hex digest of the signature.
+    sig { params(query: String, operation_name: T.nilable(String)).returns({ signature: String, hash: String }) }
+    def normalize_operation(query, operation_name: nil)
+      signature = operation_signature(query, operation_name)
+      { signature:, hash: Digest::SHA256.hexdigest(signature) }
+    end
+  end
+end
@@ -0,0 +1,84 @@
+# typed: strict
+# frozen_string_literal: true
+
+require "digest"
+
+module Bluejay
+  # A store of the documents that clients can execute by id instead of sending the query. Each
+  # document can be looked up by the id it was added with, and by the SHA-256 hex digest of its text.
+  class PersistedQueries
+    extend(T::Sig)
+
+    class << self
+      extend(T::Sig)
+
+      # Loads every `.graphql` file in `path`, using the file name without the extension as its id
+      sig { params(path: String).returns(PersistedQueries) }
+      def from_directory(path)
+        new.tap do |persisted_queries|
+          Dir.glob(File.join(path, "*.graphql")).sort.each do |file|
+            persisted_queries.add(File.read(file), id: File.basename(file, ".graphql"))
+          end
+        end
+      end
+
+      sig { params(query: String).returns(String) }
+      def hash_query(query)
+        Digest::SHA256.hexdigest(query)
+      end
+    end
+
+    sig { params(documents: T::Hash[String, String]).void }
+    def initialize(documents = {})
+      @documents = T.let({}, T::Hash[String, String])
+      @ids = T.let([], T::Array[String])
+      documents.each { |id, query| add(query, id:) }
+    end
+
+    # Adds `query` to the store, returning its id, which defaults to its SHA-256 hex digest
+    sig { params(query: String, id: T.nilable(String)).returns(String) }
+    def add(query, id: nil)
+      hash = self.class.hash_query(query)
+      id ||= hash
+      @ids << id unless @documents.key?(id)
+      @documents[id] = query
+      @documents[hash] = query
+      id
+    end
+
+    # The query with `id`, which can also be the SHA-256 hex digest of the query
+    sig { params(id: String).returns(T.nilable(String)) }
+    def [](id)
+      @documents[id]
+    end
+
+    sig { params(id: String).returns(T::Boolean) }
+    def key?(id)
+      @documents.key?(id)
+    end
+
+    # Yields each document with the id it was added with
+    sig { params(block: T.proc.params(id: String, query: String).void).void }
+    def each(&block)
+      @ids.each { |id| block.call(id, T.must(@documents[id])) }
+    end
+
+    # Validates every document against `schema`, raising an error listing all of the documents that
+    # are no longer valid. Meant to be called at boot, so that changes to the schema that break
+    # persisted documents fail fast.
+    sig { params(schema: T.class_of(Schema), context: T.untyped).void }
+    def validate!(schema, context: nil)
+      invalid_documents = T.let({}, T::Hash[String, T::Array[ValidationError]])
+      each do |id, query|
+        errors = if Bluejay.parse(query)
+          schema.validate_query(query:, context:)
+        else
+          [ValidationError.new("Document could not be parsed")]
+        end
+        invalid_documents[id] = errors unless errors.empty?
+      end
+
+      raise Errors::PersistedQueryValidationError, invalid_documents unless invalid_documents.empty?
+    end
+  end
+end
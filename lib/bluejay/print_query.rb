@@ -0,0 +1,36 @@
+# typed: strict
+# frozen_string_literal: true
+
+module Bluejay
+  class << self
+    extend(T::Sig)
+
+    # Prints an executable document in a standard format. The operations named in `removed_operations` are left out,
+    # `inline_fragments` replaces fragment spreads with inline fragments, and `remove_unused` leaves out the fragment
+    # and variable definitions that the printed operations do not use.
+    sig do
+      params(
+        query: String,
+        removed_operations: T::Array[String],
+        inline_fragments: T::Boolean,
+        remove_unused: T::Boolean,
+      ).returns(String)
+    end
+    def print_query(query, removed_operations: [], inline_fragments: false, remove_unused: false)
+      print_executable_document(query, false, removed_operations, inline_fragments, remove_unused)
+    end
+
+    # Like `print_query`, but without any whitespace that is not needed to separate tokens
+    sig do
+      params(
+        query: String,
+        removed_operations: T::Array[String],
+        inline_fragments: T::Boolean,
+        remove_unused: T::Boolean,
+      ).returns(String)
+    end
+    def minify_query(query, removed_operations: [], inline_fragments: false, remove_unused: false)
+      print_executable_document(query, true, removed_operations, inline_fragments, remove_unused)
+    end
+  end
+end
@@ -22,6 +22,8 @@ module Bluejay
           interface_implementations = self.interface_implementations
           interface = Module.new do |mod|
             field_definitions.each do |field_definition|
+              next if field_definition.resolver
+
               mod.define_method(field_definition.resolver_method_name) { graphql_name }
             end
 
@@ -29,17 +29,106 @@ module Bluejay
         []
       end
 
+      # When true, the errors for resolved values that cannot be coerced to a builtin scalar name the offending value
+      sig { overridable.returns(T::Boolean) }
+      def strict_result_coercion
+        false
+      end
+
+      # When true, a resolved `Float` with an integral value (e.g. `1.0`) is coerced to `Int` instead of rejected
+      sig { overridable.returns(T::Boolean) }
+      def coerce_integral_floats_to_int
+        false
+      end
+
+      # When true, any resolved `Integer` is accepted for `ID`, instead of only those that fit in 32 bits. Those beyond
+      # ±(2**53 - 1), which JSON clients may not read exactly, are serialized as strings.
+      sig { overridable.returns(T::Boolean) }
+      def wide_integer_ids
+        false
+      end
+
+      # The documents that can be executed by passing their id as `document_id` to `execute`
+      sig { overridable.returns(T.nilable(PersistedQueries)) }
+      def persisted_queries
+        nil
+      end
+
+      # When true, `execute` and `execute_batch` reject queries that are not persisted queries
+      sig { overridable.returns(T::Boolean) }
+      def only_persisted_queries
+        false
+      end
+
+      # Exactly one of `query` or `document_id` must be given, where `document_id` is the id or the SHA-256 hex digest
+      # of one of the `persisted_queries`
       sig do
         params(
-          query: String,
           initial_value: Object,
+          query: T.nilable(String),
+          document_id: T.nilable(String),
           operation_name: T.nilable(String),
           variables: T::Hash[String, T.untyped],
           context: T.untyped,
+          response_class: T.nilable(Class),
         ).returns(ExecutionResult)
       end
-      def execute(query:, initial_value:, operation_name: nil, variables: {}, context: nil)
-        definition.execute(query, operation_name, variables, initial_value, context)
+      def execute(
+        initial_value:,
+        query: nil,
+        document_id: nil,
+        operation_name: nil,
+        variables: {},
+        context: nil,
+        response_class: nil
+      )
+        resolved = query_or_request_error(query, document_id)
+        return resolved if resolved.is_a?(ExecutionResult)
+
+        definition.execute(resolved, operation_name, variables, initial_value, context, response_class)
+      end
+
+      # Validates all of the `persisted_queries` against the schema, raising `Errors::PersistedQueryValidationError`
+      # if any are invalid. Meant to be called at boot.
+      sig { params(context: T.untyped).void }
+      def validate_persisted_queries!(context: nil)
+        persisted_queries&.validate!(self, context:)
+      end
+
+      # Executes each request in turn, like `execute`, sharing parsed documents between requests with the same query.
+      # Each request takes exactly one of `query` or `document_id`.
+      sig do
+        params(
+          requests: T::Array[{
+            query: T.nilable(String),
+            document_id: T.nilable(String),
+            operation_name: T.nilable(String),
+            variables: T::Hash[String, T.untyped],
+          }],
+          initial_value: Object,
+          context: T.untyped,
+        ).returns(T::Array[ExecutionResult])
+      end
+      def execute_batch(requests:, initial_value:, context: nil)
+        results = T.let(Array.new(requests.length), T::Array[T.nilable(ExecutionResult)])
+        executed_indices = T.let([], T::Array[Integer])
+        executed_requests = T.let([], T::Array[[String, T.nilable(String), T::Hash[String, T.untyped]]])
+
+        requests.each_with_index do |request, index|
+          resolved = query_or_request_error(request[:query], request[:document_id])
+          if resolved.is_a?(ExecutionResult)
+            results[index] = resolved
+          else
+            executed_indices << index
+            executed_requests << [resolved, request[:operation_name], request.fetch(:variables, {})]
+          end
+        end
+
+        definition.execute_batch(executed_requests, initial_value, context).each_with_index do |result, index|
+          results[executed_indices.fetch(index)] = result
+        end
+
+        results.map { |result| T.must(result) }
       end
 
       sig { params(query: String, context: T.untyped).returns(T::Array[ValidationError]) }
@@ -47,6 +136,46 @@ module Bluejay
         definition.validate_query(query, context)
       end
 
+      # Errors are only returned for the variable values, an ArgumentError is raised when the query
+      # cannot be parsed or does not contain the operation to coerce the variables of
+      sig do
+        params(
+          query: String,
+          operation_name: T.nilable(String),
+          variables: T::Hash[String, T.untyped],
+          context: T.untyped,
+        ).returns(Result[T::Hash[String, T.untyped], T::Array[CoercionError]])
+      end
+      def coerce_variables(query:, operation_name: nil, variables: {}, context: nil)
+        definition.coerce_variables(query, operation_name, variables, context)
+      end
+
+      sig do
+        params(
+          query: String,
+          operation_name: T.nilable(String),
+          variables: T::Hash[String, T.untyped],
+          context: T.untyped,
+        ).returns(T::Array[DeprecatedUsage])
+      end
+      def deprecated_usages(query:, operation_name: nil, variables: {}, context: nil)
+        definition.deprecated_usages(query, operation_name, variables, context)
+      end
+
+      sig { params(query: String, context: T.untyped).returns(String) }
+      def generate_operation_types(query:, context: nil)
+        definition.generate_operation_types(query, context)
+      end
+
+      sig { params(paths: T::Array[String], context: T.untyped).returns(T::Array[String]) }
+      def generate_operation_type_files(paths:, context: nil)
+        paths.map do |path|
+          output_path = File.join(File.dirname(path), "#{File.basename(path, ".*")}.rb")
+          File.write(output_path, generate_operation_types(query: File.read(path), context:))
+          output_path
+        end
+      end
+
       sig { params(context: T.untyped).returns(String) }
       def to_definition(context: nil)
         definition.to_definition(context)
@@ -61,6 +190,24 @@ module Bluejay
 
       private
 
+      # Resolves the query to execute from exactly one of `query` or `document_id`, or the request error to return
+      # instead of executing it
+      sig { params(query: T.nilable(String), document_id: T.nilable(String)).returns(T.any(String, ExecutionResult)) }
+      def query_or_request_error(query, document_id)
+        if query.nil? == document_id.nil?
+          raise ArgumentError, "Exactly one of `query` or `document_id` must be given"
+        end
+
+        if document_id
+          persisted_queries&.[](document_id) ||
+            ExecutionResult.request_error([ExecutionError.new("No persisted query with id `#{document_id}`")])
+        elsif only_persisted_queries
+          ExecutionResult.request_error([ExecutionError.new("Only persisted queries can be executed")])
+        else
+          T.must(query)
+        end
+      end
+
       sig { params(name: Symbol).returns(T.untyped) }
       def const_missing(name)
         if name == :Root
@@ -83,7 +230,20 @@ module Bluejay
             end
           end
           const_set(:Root, interface)
-          SchemaDefinition.new(description:, query:, mutation:, directives:, ruby_class: self)
+          schema_definition = SchemaDefinition.new(
+            description:,
+            query:,
+            mutation:,
+            directives:,
+            ruby_class: self,
+            strict_result_coercion:,
+            coerce_integral_floats_to_int:,
+            wide_integer_ids:,
+          )
+          schema_definition.interface_implementor_names.each do |interface, object_type_names|
+            T.cast(interface, T.class_of(InterfaceType)).define_typename(object_type_names)
+          end
+          schema_definition
         end
       end
     end
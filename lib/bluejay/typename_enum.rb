@@ -0,0 +1,35 @@
+# typed: strict
+# frozen_string_literal: true
+
+module Bluejay
+  module TypenameEnum
+    extend(T::Sig)
+
+    private
+
+    # A `T::Enum` with a value for each object type name, which `resolve_typename` can return for abstract types. Raises
+    # when a name cannot be made into a constant, or when two names make the same constant.
+    sig { params(object_type_names: T::Array[String]).returns(T.class_of(T::Enum)) }
+    def typename_enum(object_type_names)
+      typename = Class.new(T::Enum)
+      typename.enums do
+        object_type_names.each do |object_type_name|
+          constant_name = object_type_name.sub(/\A[a-z]/, &:upcase)
+          unless constant_name.match?(/\A[A-Z]/)
+            raise Errors::TypenameEnumError,
+              "Object type name `#{object_type_name}` cannot be a value of a `Typename` enum, as it does not start " \
+                "with a letter"
+          end
+          if typename.const_defined?(constant_name, false)
+            raise Errors::TypenameEnumError,
+              "Object type names `#{typename.const_get(constant_name).serialize}` and `#{object_type_name}` cannot " \
+                "both be values of a `Typename` enum, as they are both `#{constant_name}` once capitalized"
+          end
+
+          typename.const_set(constant_name, typename.new(object_type_name))
+        end
+      end
+      typename
+    end
+  end
+end
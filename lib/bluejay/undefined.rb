@@ -0,0 +1,21 @@
+# typed: strict
+# frozen_string_literal: true
+
+module Bluejay
+  # Sentinel passed in place of an optional input value that was not provided,
+  # for input values configured with `absent_behavior: :undefined`
+  module Undefined
+    class << self
+      extend(T::Sig)
+
+      sig { returns(String) }
+      def inspect
+        "Bluejay::Undefined"
+      end
+
+      alias_method(:to_s, :inspect)
+    end
+
+    freeze
+  end
+end
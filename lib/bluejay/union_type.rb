@@ -7,6 +7,7 @@ module Bluejay
       extend(T::Sig)
       extend(T::Helpers)
       include(NameFromClass)
+      include(TypenameEnum)
       include(Base::UnionType)
 
       abstract!
@@ -31,9 +32,28 @@ module Bluejay
 
       private
 
+      sig { params(name: Symbol).returns(T.untyped) }
+      def const_missing(name)
+        if name == :Type || name == :Typename
+          definition
+          const_get(name)
+        else
+          super
+        end
+      end
+
       sig { override.returns(UnionTypeDefinition) }
       def definition
-        @definition ||= T.let(
+        @definition ||= T.let(nil, T.nilable(UnionTypeDefinition))
+        @definition ||= begin
+          member_types = self.member_types
+          member_object_types = member_types.map { |member_type| T.cast(member_type.type, T.class_of(ObjectType)) }
+          member_interfaces = member_object_types.map { |object_type| object_type.const_get(:Interface) }
+          const_set(
+            :Type,
+            member_interfaces.one? ? T::Utils.coerce(member_interfaces.first) : T::Types::Union.new(member_interfaces),
+          )
+          const_set(:Typename, typename_enum(member_object_types.map(&:graphql_name)))
           UnionTypeDefinition.new(
             name: graphql_name,
             description:,
@@ -41,9 +61,8 @@ module Bluejay
             directives:,
             field_definitions: [Builtin.typename_field_definition],
             visibility: nil,
-          ),
-          T.nilable(UnionTypeDefinition),
-        )
+          )
+        end
       end
     end
   end
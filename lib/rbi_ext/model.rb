@@ -17,13 +17,14 @@ module RBI
         comments: T::Array[RBI::Comment],
         is_final: T::Boolean,
         is_abstract: T::Boolean,
+        is_override: T::Boolean,
       ).void
     end
     def custom_create_method(name, return_type:, parameters: [], class_method: false, visibility: RBI::Public.new,
-      comments: [], is_final: false, is_abstract: false)
+      comments: [], is_final: false, is_abstract: false, is_override: false)
       return unless Tapioca::RBIHelper.valid_method_name?(name)
 
-      sig = RBI::Sig.new(return_type:, is_final:, is_abstract:)
+      sig = RBI::Sig.new(return_type:, is_final:, is_abstract:, is_override:)
       method = RBI::Method.new(
         name,
         sigs: [sig],
@@ -53,6 +54,18 @@ module RBI
       self << RBI::Include.new(name)
     end
 
+    sig { params(name: String, type: String).void }
+    def create_type_alias(name, type:)
+      self << RBI::Const.new(name, "T.type_alias { #{type} }")
+    end
+
+    sig { params(name: String, values: T::Array[String]).void }
+    def create_t_enum(name, values:)
+      self << RBI::TEnum.new(name) do |enum|
+        enum << RBI::TEnumBlock.new(values)
+      end
+    end
+
     private
 
     sig { params(name: String).void }
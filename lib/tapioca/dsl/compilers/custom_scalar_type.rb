@@ -0,0 +1,76 @@
+# typed: strict
+# frozen_string_literal: true
+
+require_relative "../../../rbi_ext/model"
+
+module Tapioca
+  module Compilers
+    class CustomScalarType < Tapioca::Dsl::Compiler
+      extend T::Sig
+
+      ConstantType = type_member { { fixed: T.class_of(Bluejay::CustomScalarType) } }
+
+      class << self
+        extend(T::Sig)
+
+        sig { override.returns(T::Enumerable[Module]) }
+        def gather_constants
+          # the coercion methods of native scalars are defined by `Bluejay::NativeScalarType`
+          all_classes.select { |c| c < Bluejay::CustomScalarType && !(c <= Bluejay::NativeScalarType) }
+        end
+      end
+
+      # narrows the coercion signatures of the base class to the exception classes of the scalar
+      sig { override.void }
+      def decorate
+        root.create_path(constant) do |klass|
+          internal_representation = constant.internal_representation_sorbet_type_name
+          input_exception_class = constant.coerce_input_exception_class
+          result_exception_class = constant.coerce_result_exception_class
+
+          klass.custom_create_method(
+            "coerce_input_exception_class",
+            return_type: exception_class_type(input_exception_class),
+            class_method: true,
+            is_override: true,
+          )
+
+          klass.custom_create_method(
+            "coerce_result_exception_class",
+            return_type: exception_class_type(result_exception_class),
+            class_method: true,
+            is_override: true,
+          )
+
+          klass.custom_create_method(
+            "coerce_input",
+            parameters: [create_param("value", type: "T.untyped")],
+            return_type: coercion_return_type(internal_representation, input_exception_class),
+            class_method: true,
+            is_override: true,
+          )
+
+          klass.custom_create_method(
+            "coerce_result",
+            parameters: [create_param("value", type: internal_representation)],
+            return_type: coercion_return_type("T.untyped", result_exception_class),
+            class_method: true,
+            is_override: true,
+          )
+        end
+      end
+
+      private
+
+      sig { params(coerced_type: String, exception_class: T.nilable(T.class_of(StandardError))).returns(String) }
+      def coercion_return_type(coerced_type, exception_class)
+        exception_class ? coerced_type : "Bluejay::Result[#{coerced_type}, String]"
+      end
+
+      sig { params(exception_class: T.nilable(T.class_of(StandardError))).returns(String) }
+      def exception_class_type(exception_class)
+        exception_class ? "T.class_of(#{exception_class.name})" : "NilClass"
+      end
+    end
+  end
+end
@@ -0,0 +1,35 @@
+# typed: strict
+# frozen_string_literal: true
+
+require_relative "../../../rbi_ext/model"
+
+module Tapioca
+  module Compilers
+    class EnumType < Tapioca::Dsl::Compiler
+      extend T::Sig
+
+      ConstantType = type_member { { fixed: T.class_of(Bluejay::EnumType) } }
+
+      class << self
+        extend(T::Sig)
+
+        sig { override.returns(T::Enumerable[Module]) }
+        def gather_constants
+          all_classes.select { |c| c < Bluejay::EnumType }
+        end
+      end
+
+      sig { override.void }
+      def decorate
+        root.create_path(constant) do |klass|
+          constant.enum_value_definitions.each do |enum_value_definition|
+            value = enum_value_definition.name
+            next unless value.match?(Bluejay::EnumType::VALUE_CONSTANT_NAME)
+
+            klass.create_constant(value, value: "T.let(T.unsafe(nil), String)")
+          end
+        end
+      end
+    end
+  end
+end
@@ -23,19 +23,40 @@ module Tapioca
       def decorate
         root.create_path(constant) do |klass|
           parameters = constant.input_field_definitions.map do |input_field_definition|
-            create_kw_param(input_field_definition.ruby_name, type: input_field_definition.type.sorbet_type)
+            sorbet_type = input_field_definition.type.sorbet_type
+            case absent_behavior(input_field_definition)
+            when :omit
+              create_kw_opt_param(input_field_definition.ruby_name, type: sorbet_type, default: "T.unsafe(nil)")
+            when :undefined
+              create_kw_opt_param(
+                input_field_definition.ruby_name,
+                type: "T.any(#{sorbet_type}, T.class_of(Bluejay::Undefined))",
+                default: "Bluejay::Undefined",
+              )
+            else
+              create_kw_param(input_field_definition.ruby_name, type: sorbet_type)
+            end
           end
 
           klass.custom_create_method("initialize", parameters:, return_type: nil)
 
           constant.input_field_definitions.each do |input_field_definition|
-            klass.custom_create_method(
-              input_field_definition.ruby_name,
-              return_type: input_field_definition.type.sorbet_type,
-            )
+            return_type = input_field_definition.type.sorbet_type
+            if absent_behavior(input_field_definition) == :undefined
+              return_type = "T.any(#{return_type}, T.class_of(Bluejay::Undefined))"
+            end
+
+            klass.custom_create_method(input_field_definition.ruby_name, return_type:)
           end
         end
       end
+
+      private
+
+      sig { params(input_field_definition: Bluejay::InputValueDefinition).returns(Symbol) }
+      def absent_behavior(input_field_definition)
+        input_field_definition.absent_behavior || constant.absent_field_behavior
+      end
     end
   end
 end
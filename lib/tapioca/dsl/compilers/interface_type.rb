@@ -21,7 +21,27 @@ module Tapioca
 
       sig { override.void }
       def decorate
+        # `Typename` is only defined once a schema containing the interface is defined
+        typename_values = if constant.const_defined?(:Typename, false)
+          T.cast(constant.const_get(:Typename), T.class_of(T::Enum)).constants(false).map(&:to_s)
+        else
+          []
+        end
+
+        root.create_path(constant) do |klass|
+          klass.create_t_enum("Typename", values: typename_values)
+        end
+
         root.create_path(constant.const_get(:Interface)) do |klass|
+          klass.mark_abstract
+
+          # values that do not include the `Interface` of an object type must say which implementing type they are
+          klass.custom_create_method(
+            "resolve_typename",
+            return_type: "T.any(String, #{constant.name}::Typename)",
+            is_abstract: true,
+          )
+
           constant.interface_implementations.each do |interface_implementation|
             interface = T.cast(interface_implementation.interface, T.class_of(InterfaceType)).const_get(:Interface)
             klass.create_include(interface.name)
@@ -22,7 +22,13 @@ module Tapioca
       sig { override.void }
       def decorate
         root.create_path(constant.const_get(:Interface)) do |klass|
-          klass.custom_create_method("resolve_typename", return_type: "String", is_final: true)
+          # implements the abstract `resolve_typename` of the interfaces' modules
+          klass.custom_create_method(
+            "resolve_typename",
+            return_type: "String",
+            is_final: true,
+            is_override: constant.interface_implementations.any?,
+          )
 
           klass.mark_abstract
 
@@ -32,21 +38,77 @@ module Tapioca
           end
 
           constant.field_definitions.each do |field_definition|
-            # TODO: add extra args
-            parameters = field_definition.argument_definitions.map do |argument_definition|
-              create_kw_param(argument_definition.ruby_name, type: argument_definition.type.sorbet_type)
-            end
-
-            return_type = field_definition.type.sorbet_type
+            next if field_definition.resolver
 
             klass.custom_create_method(
               field_definition.resolver_method_name,
-              parameters:,
-              return_type:,
+              parameters: resolver_parameters(field_definition),
+              return_type: field_definition.type.sorbet_type,
               is_abstract: true,
             )
           end
         end
+
+        constant.field_definitions.each do |field_definition|
+          resolver = field_definition.resolver
+          next unless resolver
+
+          create_resolver_methods(resolver, field_definition)
+        end
+      end
+
+      private
+
+      sig { params(resolver: T.untyped, field_definition: Bluejay::FieldDefinition).void }
+      def create_resolver_methods(resolver, field_definition)
+        object_type = constant.const_get(:Interface).name
+        parameters = resolver_parameters(field_definition)
+        return_type = field_definition.type.sorbet_type
+
+        if resolver.is_a?(Class)
+          # a resolver class is instantiated with the object every time the field is resolved
+          root.create_path(resolver) do |klass|
+            klass.custom_create_method(
+              "initialize",
+              parameters: [create_param("object", type: object_type)],
+              return_type: "void",
+            )
+            klass.custom_create_method("resolve", parameters:, return_type:)
+          end
+        elsif !resolver.is_a?(Proc)
+          # the signature of a proc cannot be declared in an RBI, so only other callables are typed
+          root.create_path(resolver.class) do |klass|
+            klass.custom_create_method(
+              "call",
+              parameters: [create_param("object", type: object_type), *parameters],
+              return_type:,
+            )
+          end
+        end
+      end
+
+      sig { params(field_definition: Bluejay::FieldDefinition).returns(T::Array[RBI::TypedParam]) }
+      def resolver_parameters(field_definition)
+        parameters = field_definition.argument_definitions.map do |argument_definition|
+          sorbet_type = argument_definition.type.sorbet_type
+          case argument_definition.absent_behavior
+          when :omit
+            create_kw_opt_param(argument_definition.ruby_name, type: sorbet_type, default: "T.unsafe(nil)")
+          when :undefined
+            create_kw_param(
+              argument_definition.ruby_name,
+              type: "T.any(#{sorbet_type}, T.class_of(Bluejay::Undefined))",
+            )
+          else
+            create_kw_param(argument_definition.ruby_name, type: sorbet_type)
+          end
+        end
+
+        field_definition.extra_resolver_args.each do |extra_resolver_arg|
+          parameters << create_kw_param(extra_resolver_arg.kwarg_name.to_s, type: extra_resolver_arg.sorbet_type)
+        end
+
+        parameters
       end
     end
   end
@@ -0,0 +1,39 @@
+# typed: strict
+# frozen_string_literal: true
+
+require_relative "../../../rbi_ext/model"
+
+module Tapioca
+  module Compilers
+    class UnionType < Tapioca::Dsl::Compiler
+      extend T::Sig
+
+      ConstantType = type_member { { fixed: T.class_of(Bluejay::UnionType) } }
+
+      class << self
+        extend(T::Sig)
+
+        sig { override.returns(T::Enumerable[Module]) }
+        def gather_constants
+          all_classes.select { |c| c < Bluejay::UnionType }
+        end
+      end
+
+      sig { override.void }
+      def decorate
+        root.create_path(constant) do |klass|
+          member_interfaces = constant.member_types.map do |member_type|
+            T.cast(member_type.type, T.class_of(Bluejay::ObjectType)).const_get(:Interface).name
+          end
+
+          type = member_interfaces.one? ? member_interfaces.first : "T.any(#{member_interfaces.join(", ")})"
+
+          klass.create_type_alias("Type", type:)
+
+          typename = T.cast(constant.const_get(:Typename), T.class_of(T::Enum))
+          klass.create_t_enum("Typename", values: typename.constants(false).map(&:to_s))
+        end
+      end
+    end
+  end
+end
@@ -0,0 +1,27 @@
+# typed: true
+
+# DO NOT EDIT MANUALLY
+# This is an autogenerated file for dynamic methods in `Bluejay::Builtin::EnumTypes::DirectiveLocation`.
+# Please instead update this file by running `bin/tapioca dsl Bluejay::Builtin::EnumTypes::DirectiveLocation`.
+
+class Bluejay::Builtin::EnumTypes::DirectiveLocation
+  ARGUMENT_DEFINITION = T.let(T.unsafe(nil), String)
+  ENUM = T.let(T.unsafe(nil), String)
+  ENUM_VALUE = T.let(T.unsafe(nil), String)
+  FIELD = T.let(T.unsafe(nil), String)
+  FIELD_DEFINITION = T.let(T.unsafe(nil), String)
+  FRAGMENT_DEFINITION = T.let(T.unsafe(nil), String)
+  FRAGMENT_SPREAD = T.let(T.unsafe(nil), String)
+  INLINE_FRAGMENT = T.let(T.unsafe(nil), String)
+  INPUT_FIELD_DEFINITION = T.let(T.unsafe(nil), String)
+  INPUT_OBJECT = T.let(T.unsafe(nil), String)
+  INTERFACE = T.let(T.unsafe(nil), String)
+  MUTATION = T.let(T.unsafe(nil), String)
+  OBJECT = T.let(T.unsafe(nil), String)
+  QUERY = T.let(T.unsafe(nil), String)
+  SCALAR = T.let(T.unsafe(nil), String)
+  SCHEMA = T.let(T.unsafe(nil), String)
+  SUBSCRIPTION = T.let(T.unsafe(nil), String)
+  UNION = T.let(T.unsafe(nil), String)
+  VARIABLE_DEFINITION = T.let(T.unsafe(nil), String)
+end
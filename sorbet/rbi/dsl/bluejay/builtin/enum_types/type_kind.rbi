@@ -0,0 +1,16 @@
+# typed: true
+
+# DO NOT EDIT MANUALLY
+# This is an autogenerated file for dynamic methods in `Bluejay::Builtin::EnumTypes::TypeKind`.
+# Please instead update this file by running `bin/tapioca dsl Bluejay::Builtin::EnumTypes::TypeKind`.
+
+class Bluejay::Builtin::EnumTypes::TypeKind
+  ENUM = T.let(T.unsafe(nil), String)
+  INPUT_OBJECT = T.let(T.unsafe(nil), String)
+  INTERFACE = T.let(T.unsafe(nil), String)
+  LIST = T.let(T.unsafe(nil), String)
+  NON_NULL = T.let(T.unsafe(nil), String)
+  OBJECT = T.let(T.unsafe(nil), String)
+  SCALAR = T.let(T.unsafe(nil), String)
+  UNION = T.let(T.unsafe(nil), String)
+end
@@ -2,8 +2,25 @@
 
 module Bluejay
   class << self
+    sig { params(sdl: String, namespace: T.nilable(String)).returns(T::Hash[String, String]) }
+    def generate_schema_classes(sdl, namespace); end
+
     sig { params(query: String).returns(T::Boolean) }
     def parse(query); end
+
+    sig { params(query: String, operation_name: T.nilable(String)).returns(String) }
+    def operation_signature(query, operation_name); end
+
+    sig do
+      params(
+        query: String,
+        minify: T::Boolean,
+        removed_operations: T::Array[String],
+        inline_fragments: T::Boolean,
+        remove_unused: T::Boolean,
+      ).returns(String)
+    end
+    def print_executable_document(query, minify, removed_operations, inline_fragments, remove_unused); end
   end
 end
   
\ No newline at end of file
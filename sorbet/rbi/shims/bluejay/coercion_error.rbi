@@ -2,8 +2,10 @@
 
 module Bluejay
   class CoercionError
-    sig { params(message: String, path: T::Array[String]).void }
-    def initialize(message, path); end
+    sig do
+      params(message: String, path: T::Array[String], expected_type: T.nilable(String), value: T.untyped).void
+    end
+    def initialize(message, path, expected_type: nil, value: nil); end
 
     sig { returns(String) }
     def message; end
@@ -11,6 +13,15 @@ module Bluejay
     sig { returns(T::Array[String]) }
     def path; end
 
+    sig { returns(T.nilable(String)) }
+    def expected_type; end
+
+    sig { returns(T.untyped) }
+    def value; end
+
+    sig { returns(T::Hash[String, T.untyped]) }
+    def extensions; end
+
     sig { params(other: T.untyped).returns(T::Boolean) }
     def ==(other); end
   end
@@ -14,10 +14,12 @@ module Bluejay
         input_coercion_method_signature: CoercionMethodSignature,
         result_coercion_method_signature: CoercionMethodSignature,
         visibility: T.nilable(Visibility),
+        native_scalar: T.nilable(Symbol),
       ).void
     end
     def initialize(name:, description:, directives:, specified_by_url:, ruby_class:,
-      internal_representation_sorbet_type_name:, input_coercion_method_signature:, result_coercion_method_signature:, visibility:)
+      internal_representation_sorbet_type_name:, input_coercion_method_signature:, result_coercion_method_signature:, visibility:,
+      native_scalar: nil)
     end
 
     class CoercionMethodSignature
@@ -0,0 +1,30 @@
+# typed: strict
+# frozen_string_literal: true
+
+module Bluejay
+  class DeprecatedUsage
+    sig { returns(Symbol) }
+    def kind; end
+
+    sig { returns(String) }
+    def coordinate; end
+
+    sig { returns(String) }
+    def reason; end
+
+    sig { returns(T::Array[String]) }
+    def path; end
+
+    sig { returns(T.nilable(T::Array[String])) }
+    def argument_path; end
+
+    sig { returns(Integer) }
+    def line; end
+
+    sig { returns(Integer) }
+    def column; end
+
+    sig { returns(T::Hash[String, T.untyped]) }
+    def to_h; end
+  end
+end
@@ -3,13 +3,25 @@
 
 module Bluejay
   class ExecutionError
-    sig { params(message: String, path: T.nilable(T::Array[String])).void }
-    def initialize(message, path = nil); end
+    sig do
+      params(
+        message: String,
+        path: T.nilable(T::Array[String]),
+        extensions: T.nilable(T::Hash[String, T.untyped]),
+      ).void
+    end
+    def initialize(message, path = nil, extensions = nil); end
 
     sig { returns(String) }
     def message; end
 
     sig { returns(T.nilable(T::Array[String])) }
     def path; end
+
+    sig { returns(T.nilable(T::Array[T::Hash[String, Integer]])) }
+    def locations; end
+
+    sig { returns(T.nilable(T::Hash[String, T.untyped])) }
+    def extensions; end
   end
 end
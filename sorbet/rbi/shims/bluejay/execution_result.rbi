@@ -2,10 +2,25 @@
 
 module Bluejay
   class ExecutionResult
+    class << self
+      # A result for a request that failed before execution started, which has no `data`
+      sig { params(errors: T::Array[ExecutionError]).returns(ExecutionResult) }
+      def request_error(errors); end
+    end
+
     sig { returns(T.nilable(T::Hash[String, T.untyped])) }
     def value; end
 
     sig { returns(T::Array[ExecutionError]) }
     def errors; end
+
+    sig { returns(T.nilable(T::Hash[String, T.untyped])) }
+    def extensions; end
+
+    sig { params(extensions: T::Hash[String, T.untyped]).returns(ExecutionResult) }
+    def with_extensions(extensions); end
+
+    sig { params(args: T.untyped).returns(String) }
+    def to_json(*args); end
   end
 end
@@ -0,0 +1,12 @@
+# typed: strict
+# frozen_string_literal: true
+
+module Bluejay
+  class ExtraResolverArg
+    sig { returns(Symbol) }
+    def kwarg_name; end
+
+    sig { returns(String) }
+    def sorbet_type; end
+  end
+end
@@ -3,6 +3,9 @@
 
 module Bluejay
   class FieldDefinition
+    # `resolver` is either a class, which is instantiated with the object every time the field is resolved and then
+    # has `resolve` called with the arguments, or an object responding to `call`, which is called with the object and
+    # the arguments. The arguments include the `extra_resolver_args`.
     sig do
       params(
         name: String,
@@ -11,12 +14,14 @@ module Bluejay
         description: T.nilable(String),
         directives: T::Array[Directive],
         resolver_method_name: T.nilable(String),
+        resolver: T.untyped,
+        extra_resolver_args: T::Array[Symbol],
         deprecation_reason: T.nilable(String),
         visibility: T.nilable(Visibility),
       ).void
     end
     def initialize(name:, type:, argument_definitions: [], description: nil, directives: [], resolver_method_name: nil,
-      deprecation_reason: nil, visibility: nil)
+      resolver: nil, extra_resolver_args: [], deprecation_reason: nil, visibility: nil)
     end
 
     sig { returns(String) }
@@ -25,6 +30,9 @@ module Bluejay
     sig { returns(String) }
     def resolver_method_name; end
 
+    sig { returns(T.untyped) }
+    def resolver; end
+
     sig { returns(T::Array[InputValueDefinition]) }
     def argument_definitions; end
 
@@ -33,5 +41,8 @@ module Bluejay
 
     sig { returns(T::Array[Directive]) }
     def directives; end
+
+    sig { returns(T::Array[ExtraResolverArg]) }
+    def extra_resolver_args; end
   end
 end
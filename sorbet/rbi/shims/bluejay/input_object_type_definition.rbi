@@ -9,16 +9,22 @@ module Bluejay
         input_field_definitions: T::Array[InputValueDefinition],
         description: T.nilable(String),
         directives: T::Array[Base::Directive::Instance],
+        absent_field_behavior: Symbol,
         ruby_class: Base::InputObjectType,
         visibility: T.nilable(Visibility),
       ).void
     end
-    def initialize(name:, input_field_definitions:, description:, directives:, ruby_class:, visibility:); end
+    def initialize(name:, input_field_definitions:, description:, directives:, absent_field_behavior:, ruby_class:,
+      visibility:)
+    end
 
     sig { params(value: T.untyped, context: T.untyped).returns(Result[T.untyped, T::Array[CoercionError]]) }
     def coerce_input(value, context); end
 
     sig { returns(T::Array[InputValueDefinition]) }
     def input_field_definitions; end
+
+    sig { returns(Symbol) }
+    def absent_field_behavior; end
   end
 end
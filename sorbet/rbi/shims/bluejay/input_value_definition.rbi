@@ -13,10 +13,11 @@ module Bluejay
         default_value: T.nilable(Object),
         deprecation_reason: T.nilable(String),
         visibility: T.nilable(Visibility),
+        absent_behavior: T.nilable(Symbol),
       ).void
     end
     def initialize(name:, type:, description: nil, directives: [], ruby_name: nil, default_value: nil,
-      deprecation_reason: nil, visibility: nil)
+      deprecation_reason: nil, visibility: nil, absent_behavior: nil)
     end
 
     sig { returns(String) }
@@ -27,5 +28,8 @@ module Bluejay
 
     sig { returns(String) }
     def ruby_name; end
+
+    sig { returns(T.nilable(Symbol)) }
+    def absent_behavior; end
   end
 end
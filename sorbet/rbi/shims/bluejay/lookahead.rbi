@@ -0,0 +1,24 @@
+# typed: strict
+# frozen_string_literal: true
+
+module Bluejay
+  class Lookahead
+    sig { returns(String) }
+    def name; end
+
+    sig { returns(String) }
+    def response_key; end
+
+    sig { returns(T::Hash[Symbol, T.untyped]) }
+    def arguments; end
+
+    sig { returns(T::Array[Lookahead]) }
+    def selections; end
+
+    sig { params(name: T.any(String, Symbol)).returns(T.nilable(Lookahead)) }
+    def selection(name); end
+
+    sig { params(name: T.any(String, Symbol)).returns(T::Boolean) }
+    def selects?(name); end
+  end
+end
@@ -0,0 +1,14 @@
+# typed: strict
+# frozen_string_literal: true
+
+module Bluejay
+  module NativeScalars
+    class << self
+      sig { params(native_scalar: Symbol, value: T.untyped).returns(Result[T.untyped, String]) }
+      def coerce_input(native_scalar, value); end
+
+      sig { params(native_scalar: Symbol, value: T.untyped).returns(Result[T.untyped, String]) }
+      def coerce_result(native_scalar, value); end
+    end
+  end
+end
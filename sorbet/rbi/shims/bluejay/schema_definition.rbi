@@ -10,9 +10,13 @@ module Bluejay
         mutation: T.nilable(Base::ObjectType),
         directives: T::Array[Base::Directive::Instance],
         ruby_class: Base::Schema,
+        strict_result_coercion: T::Boolean,
+        coerce_integral_floats_to_int: T::Boolean,
+        wide_integer_ids: T::Boolean,
       ).void
     end
-    def initialize(description:, query:, mutation:, directives:, ruby_class:); end
+    def initialize(description:, query:, mutation:, directives:, ruby_class:, strict_result_coercion: false,
+      coerce_integral_floats_to_int: false, wide_integer_ids: false); end
 
     sig do
       params(
@@ -21,13 +25,46 @@ module Bluejay
         variables: T::Hash[String, T.untyped],
         initial_value: Object,
         context: T.untyped,
+        response_class: T.nilable(Class),
       ).returns(ExecutionResult)
     end
-    def execute(query, operation_name, variables, initial_value, context); end
+    def execute(query, operation_name, variables, initial_value, context, response_class); end
+
+    sig do
+      params(
+        requests: T::Array[[String, T.nilable(String), T::Hash[String, T.untyped]]],
+        initial_value: Object,
+        context: T.untyped,
+      ).returns(T::Array[ExecutionResult])
+    end
+    def execute_batch(requests, initial_value, context); end
 
     sig { params(query: String, context: T.untyped).returns(T::Array[ValidationError]) }
     def validate_query(query, context); end
 
+    sig do
+      params(
+        query: String,
+        operation_name: T.nilable(String),
+        variables: T::Hash[String, T.untyped],
+        context: T.untyped,
+      ).returns(Result[T::Hash[String, T.untyped], T::Array[CoercionError]])
+    end
+    def coerce_variables(query, operation_name, variables, context); end
+
+    sig do
+      params(
+        query: String,
+        operation_name: T.nilable(String),
+        variables: T::Hash[String, T.untyped],
+        context: T.untyped,
+      ).returns(T::Array[DeprecatedUsage])
+    end
+    def deprecated_usages(query, operation_name, variables, context); end
+
+    sig { params(query: String, context: T.untyped).returns(String) }
+    def generate_operation_types(query, context); end
+
     sig { params(context: T.untyped).returns(String) }
     def to_definition(context); end
 
@@ -42,5 +79,8 @@ module Bluejay
       )))
     end
     def type(name); end
+
+    sig { returns(T::Hash[Base::InterfaceType, T::Array[String]]) }
+    def interface_implementor_names; end
   end
 end
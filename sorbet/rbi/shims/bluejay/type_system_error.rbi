@@ -0,0 +1,15 @@
+# typed: strict
+# frozen_string_literal: true
+
+module Bluejay
+  class TypeSystemError
+    sig { returns(Symbol) }
+    def kind; end
+
+    sig { returns(String) }
+    def coordinate; end
+
+    sig { returns(String) }
+    def message; end
+  end
+end
@@ -5,5 +5,8 @@ module Bluejay
   class UnionMemberType
     sig { params(type: Base::ObjectType, visibility: T.nilable(Visibility)).void }
     def initialize(type:, visibility: nil); end
+
+    sig { returns(Base::ObjectType) }
+    def type; end
   end
 end
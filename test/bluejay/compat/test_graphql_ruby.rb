@@ -0,0 +1,190 @@
+# typed: ignore
+# frozen_string_literal: true
+
+require "test_helper"
+require "bluejay/compat/graphql_ruby"
+
+module Bluejay
+  module Compat
+    class TestGraphQLRuby < Minitest::Test
+      Person = Struct.new(:first_name, :last_name, :role, :born_on, keyword_init: true)
+      Robot = Struct.new(:serial_number, keyword_init: true)
+
+      module Types
+        class Date < GraphQL::Schema::Scalar
+          specified_by_url("https://example.com/date")
+
+          def self.coerce_input(value, _ctx)
+            ::Date.iso8601(value)
+          rescue ArgumentError
+            raise GraphQL::CoercionError, "#{value.inspect} is not a date"
+          end
+
+          def self.coerce_result(value, _ctx)
+            value.iso8601
+          end
+        end
+
+        class Role < GraphQL::Schema::Enum
+          value("ADMIN", value: :admin)
+          value("MEMBER", value: :member, deprecation_reason: "Use ADMIN")
+        end
+
+        module Named
+          include(GraphQL::Schema::Interface)
+
+          field(:name, String, null: false)
+        end
+
+        class PersonType < GraphQL::Schema::Object
+          graphql_name("Person")
+          implements(Named)
+
+          field(:first_name, String, null: false)
+          field(:role, Role, null: false)
+          field(:born_on, Date, null: true)
+
+          def name
+            "#{object.first_name} #{object.last_name}"
+          end
+        end
+
+        class RobotType < GraphQL::Schema::Object
+          graphql_name("Robot")
+          implements(Named)
+
+          field(:serial_number, Integer, null: false)
+
+          def name
+            "RX-#{object.serial_number}"
+          end
+        end
+
+        class Entity < GraphQL::Schema::Union
+          possible_types(PersonType, RobotType)
+        end
+
+        class NameInput < GraphQL::Schema::InputObject
+          argument(:first_name, String, required: true)
+          argument(:last_name, String, required: false, default_value: "Doe")
+        end
+
+        class PersonCreate < GraphQL::Schema::Mutation
+          argument(:name, NameInput, required: true)
+          argument(:role, Role, required: false, default_value: :member)
+
+          field(:person, PersonType, null: false)
+
+          def resolve(name:, role:)
+            { person: Person.new(first_name: name.first_name, last_name: name[:last_name], role:) }
+          end
+        end
+
+        class QueryRoot < GraphQL::Schema::Object
+          field(:people, [PersonType], null: false)
+          field(:entities, [Entity], null: false)
+          field(:named, [Named], null: false) do
+            argument(:role, Role, required: false)
+          end
+          field(:settings, GraphQL::Types::JSON, null: false, hash_key: :config)
+          field(:born_after, [PersonType], null: false) do
+            argument(:date, Date, required: true)
+          end
+
+          def people = context[:people]
+
+          def entities = context[:people] + context[:robots]
+
+          def named(role: nil)
+            context[:people].select { |person| role.nil? || person.role == role } + context[:robots]
+          end
+
+          def born_after(date:)
+            context[:people].select { |person| person.born_on && person.born_on > date }
+          end
+        end
+
+        class MutationRoot < GraphQL::Schema::Object
+          field(:person_create, mutation: PersonCreate)
+        end
+      end
+
+      class GraphQLSchema < GraphQL::Schema
+        query(Types::QueryRoot)
+        mutation(Types::MutationRoot)
+
+        def self.resolve_type(_type, object, _ctx)
+          object.is_a?(Person) ? Types::PersonType : Types::RobotType
+        end
+      end
+
+      CONTEXT = {
+        people: [
+          Person.new(first_name: "Ada", last_name: "Lovelace", role: :admin, born_on: ::Date.new(1815, 12, 10)),
+          Person.new(first_name: "Alan", last_name: "Turing", role: :member, born_on: ::Date.new(1912, 6, 23)),
+        ],
+        robots: [Robot.new(serial_number: 42)],
+      }.freeze
+
+      def setup
+        super
+        @converted = GraphQLRuby.convert(GraphQLSchema)
+      end
+
+      def test_to_definition
+        definition = @converted.schema.to_definition
+
+        assert_includes(definition, "union Entity = Person | Robot")
+        assert_includes(definition, "type Person implements Named {")
+        assert_includes(definition, "MEMBER @deprecated(reason: \"Use ADMIN\")")
+        assert_includes(definition, "scalar Date @specifiedBy(url: \"https://example.com/date\")")
+        assert_includes(definition, "lastName: String = \"Doe\"")
+        assert_includes(definition, "role: Role = MEMBER")
+      end
+
+      def test_objects_interfaces_and_unions
+        assert_same_result(<<~GQL)
+          {
+            people { name firstName role bornOn }
+            entities { __typename ...on Person { firstName } ...on Robot { serialNumber } }
+            named(role: ADMIN) { __typename name }
+          }
+        GQL
+      end
+
+      def test_custom_scalar_arguments_and_hash_keys
+        assert_same_result(
+          "query($date: Date!) { bornAfter(date: $date) { firstName } settings }",
+          variables: { "date" => "1900-01-01" },
+          root_value: { config: { "theme" => "dark" } },
+        )
+      end
+
+      def test_mutation_with_input_object_and_defaults
+        assert_same_result('mutation { personCreate(name: { firstName: "Grace" }) { person { name role } } }')
+      end
+
+      def test_custom_scalar_coercion_error
+        result = @converted.execute(
+          query: "query($date: Date!) { bornAfter(date: $date) { firstName } }",
+          variables: { "date" => "yesterday" },
+          context: CONTEXT,
+        )
+
+        assert_nil(result.value)
+        assert_equal(["\"yesterday\" is not a date"], result.errors.map(&:message))
+      end
+
+      private
+
+      def assert_same_result(query, variables: {}, root_value: nil)
+        expected = GraphQLSchema.execute(query, variables:, context: CONTEXT, root_value:).to_h
+        result = @converted.execute(query:, variables:, context: CONTEXT, root_value:)
+
+        refute_includes(expected, "errors")
+        assert_empty(result.errors)
+        assert_equal(expected.fetch("data"), result.value)
+      end
+    end
+  end
+end
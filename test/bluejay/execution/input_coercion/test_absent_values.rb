@@ -0,0 +1,240 @@
+# typed: ignore
+# frozen_string_literal: true
+
+require "test_helper"
+
+module Bluejay
+  module Execution
+    module InputCoercion
+      class TestAbsentValues < Minitest::Test
+        class AbsentInput < InputObjectType
+          class << self
+            extend(T::Sig)
+
+            sig { override.returns(T::Array[InputValueDefinition]) }
+            def input_field_definitions
+              [
+                InputValueDefinition.new(name: "undefinedField", type: it(Scalar::Int)),
+                InputValueDefinition.new(name: "nilField", type: it(Scalar::Int), absent_behavior: :nil),
+              ]
+            end
+
+            sig { override.returns(Symbol) }
+            def absent_field_behavior
+              :undefined
+            end
+          end
+        end
+
+        class OmitInput < InputObjectType
+          class << self
+            extend(T::Sig)
+
+            sig { override.returns(T::Array[InputValueDefinition]) }
+            def input_field_definitions
+              [
+                InputValueDefinition.new(name: "value", type: it(Scalar::Int), absent_behavior: :omit),
+              ]
+            end
+          end
+        end
+
+        class QueryRoot < Bluejay::QueryRoot
+          class << self
+            extend(T::Sig)
+
+            sig { override.returns(T::Array[FieldDefinition]) }
+            def field_definitions
+              [
+                FieldDefinition.new(
+                  name: "nilArg",
+                  type: ot!(Scalar::String),
+                  argument_definitions: [InputValueDefinition.new(name: "arg", type: it(Scalar::Int))],
+                ),
+                FieldDefinition.new(
+                  name: "omitArg",
+                  type: ot!(Scalar::String),
+                  argument_definitions: [
+                    InputValueDefinition.new(name: "arg", type: it(Scalar::Int), absent_behavior: :omit),
+                  ],
+                ),
+                FieldDefinition.new(
+                  name: "undefinedArg",
+                  type: ot!(Scalar::String),
+                  argument_definitions: [
+                    InputValueDefinition.new(name: "arg", type: it(Scalar::Int), absent_behavior: :undefined),
+                  ],
+                ),
+                FieldDefinition.new(
+                  name: "absentInput",
+                  type: ot!(Scalar::String),
+                  argument_definitions: [InputValueDefinition.new(name: "input", type: it!(AbsentInput))],
+                ),
+                FieldDefinition.new(
+                  name: "omitInput",
+                  type: ot!(Scalar::String),
+                  argument_definitions: [InputValueDefinition.new(name: "input", type: it!(OmitInput))],
+                ),
+              ]
+            end
+          end
+        end
+
+        class MySchema < Schema
+          class << self
+            extend(T::Sig)
+
+            sig { override.returns(T.class_of(Bluejay::QueryRoot)) }
+            def query
+              QueryRoot
+            end
+          end
+        end
+
+        module Domain
+          class QueryRoot
+            class << self
+              extend(T::Sig)
+              include(Execution::InputCoercion::TestAbsentValues::QueryRoot::Interface)
+
+              sig { params(arg: T.nilable(Integer)).returns(String) }
+              def nil_arg(arg:)
+                arg.inspect
+              end
+
+              sig { params(kwargs: T.untyped).returns(String) }
+              def omit_arg(**kwargs)
+                kwargs.key?(:arg) ? kwargs[:arg].inspect : "absent"
+              end
+
+              sig { params(arg: T.untyped).returns(String) }
+              def undefined_arg(arg:)
+                arg.inspect
+              end
+
+              sig { params(input: AbsentInput).returns(String) }
+              def absent_input(input:)
+                "undefinedField=#{input.undefined_field.inspect}, nilField=#{input.nil_field.inspect}"
+              end
+
+              sig { params(input: OmitInput).returns(String) }
+              def omit_input(input:)
+                input.key?(:value) ? input.value.inspect : "absent"
+              end
+            end
+          end
+
+          class SchemaRoot
+            class << self
+              extend(T::Sig)
+              include(MySchema::Root)
+
+              sig { returns(T.class_of(QueryRoot)) }
+              def query = QueryRoot
+            end
+          end
+        end
+
+        def test_absent_argument_defaults_to_nil
+          assert_equal({ "nilArg" => "nil" }, execute("{ nilArg }"))
+          assert_equal({ "nilArg" => "nil" }, execute("{ nilArg(arg: null) }"))
+          assert_equal({ "nilArg" => "1" }, execute("{ nilArg(arg: 1) }"))
+        end
+
+        def test_absent_argument_omitted
+          assert_equal({ "omitArg" => "absent" }, execute("{ omitArg }"))
+          assert_equal({ "omitArg" => "nil" }, execute("{ omitArg(arg: null) }"))
+          assert_equal({ "omitArg" => "1" }, execute("{ omitArg(arg: 1) }"))
+        end
+
+        def test_absent_argument_undefined
+          assert_equal({ "undefinedArg" => "Bluejay::Undefined" }, execute("{ undefinedArg }"))
+          assert_equal({ "undefinedArg" => "nil" }, execute("{ undefinedArg(arg: null) }"))
+          assert_equal({ "undefinedArg" => "1" }, execute("{ undefinedArg(arg: 1) }"))
+        end
+
+        def test_absent_argument_from_variable_without_value
+          query = "query($arg: Int) { omitArg(arg: $arg) undefinedArg(arg: $arg) }"
+
+          assert_equal(
+            { "omitArg" => "absent", "undefinedArg" => "Bluejay::Undefined" },
+            execute(query),
+          )
+          assert_equal(
+            { "omitArg" => "nil", "undefinedArg" => "nil" },
+            execute(query, variables: { "arg" => nil }),
+          )
+        end
+
+        def test_absent_input_object_fields_from_literal
+          assert_equal(
+            { "absentInput" => "undefinedField=Bluejay::Undefined, nilField=nil" },
+            execute("{ absentInput(input: {}) }"),
+          )
+          assert_equal(
+            { "absentInput" => "undefinedField=nil, nilField=1" },
+            execute("{ absentInput(input: { undefinedField: null, nilField: 1 }) }"),
+          )
+        end
+
+        def test_absent_input_object_fields_from_variables
+          query = "query($input: AbsentInput!) { absentInput(input: $input) }"
+
+          assert_equal(
+            { "absentInput" => "undefinedField=Bluejay::Undefined, nilField=nil" },
+            execute(query, variables: { "input" => {} }),
+          )
+          assert_equal(
+            { "absentInput" => "undefinedField=nil, nilField=nil" },
+            execute(query, variables: { "input" => { "undefinedField" => nil } }),
+          )
+        end
+
+        def test_input_object_initialize_applies_absent_behavior
+          assert_equal(Undefined, AbsentInput.new(nil_field: 1).undefined_field)
+          assert_nil(AbsentInput.new(undefined_field: 1).nil_field)
+        end
+
+        def test_omitted_input_object_fields_are_not_provided
+          assert_equal({ "omitInput" => "absent" }, execute("{ omitInput(input: {}) }"))
+          assert_equal({ "omitInput" => "nil" }, execute("{ omitInput(input: { value: null }) }"))
+          assert_equal({ "omitInput" => "1" }, execute("{ omitInput(input: { value: 1 }) }"))
+
+          query = "query($input: OmitInput!) { omitInput(input: $input) }"
+
+          assert_equal({ "omitInput" => "absent" }, execute(query, variables: { "input" => {} }))
+          assert_equal({ "omitInput" => "nil" }, execute(query, variables: { "input" => { "value" => nil } }))
+        end
+
+        def test_input_object_key
+          refute(OmitInput.new.key?(:value))
+          assert(OmitInput.new(value: nil).key?(:value))
+          refute_equal(OmitInput.new, OmitInput.new(value: nil))
+          refute(AbsentInput.new.key?(:undefined_field))
+          assert(AbsentInput.new.key?(:nil_field))
+        end
+
+        def test_invalid_absent_behavior
+          error = assert_raises(ArgumentError) do
+            InputValueDefinition.new(name: "arg", type: it(Scalar::Int), absent_behavior: :unknown)
+          end
+
+          assert_equal(
+            "Unknown absent behavior `unknown`, expected one of :nil, :omit or :undefined",
+            error.message,
+          )
+        end
+
+        private
+
+        def execute(query, variables: {})
+          result = MySchema.execute(query:, operation_name: nil, variables:, initial_value: Domain::SchemaRoot)
+
+          assert_empty(result.errors)
+
+          result.value
+        end
+      end
+    end
+  end
+end
@@ -116,6 +116,49 @@ module Bluejay
           )
         end
 
+        def test_coerce_enum_type_error_structured_data
+          query = <<~GQL
+            query Query($myEnum: MyEnum!) {
+              myEnum(myEnum: $myEnum)
+            }
+          GQL
+
+          errors = MySchema.coerce_variables(query:, variables: { "myEnum" => "NOT_A_MEMBER" }).unwrap_err
+
+          assert_equal(
+            [
+              CoercionError.new(
+                "No member `NOT_A_MEMBER` on MyEnum",
+                ["myEnum"],
+                expected_type: "MyEnum!",
+                value: "NOT_A_MEMBER",
+              ),
+            ],
+            errors,
+          )
+        end
+
+        def test_coerce_variables_missing_required_variable
+          query = <<~GQL
+            query Query($myEnum: MyEnum!) {
+              myEnum(myEnum: $myEnum)
+            }
+          GQL
+
+          errors = MySchema.coerce_variables(query:).unwrap_err
+
+          assert_equal(1, errors.length)
+          assert_equal("No value was provided for required variable `$myEnum`", errors.first.message)
+          assert_equal(["myEnum"], errors.first.path)
+        end
+
+        def test_coerce_variables_invalid_document
+          assert_raises(ArgumentError) { MySchema.coerce_variables(query: "{") }
+          assert_raises(ArgumentError) do
+            MySchema.coerce_variables(query: "query A { myEnum(myEnum: ONE) }", operation_name: "B")
+          end
+        end
+
         def test_coerce_enum_type_from_variables_not_a_string
           query = <<~GQL
             query Query($myEnum: MyEnum!) {
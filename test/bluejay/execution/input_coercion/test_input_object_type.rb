@@ -228,6 +228,68 @@ module Bluejay
             result.value,
           )
         end
+
+        def test_coerce_variables_valid
+          query = <<~GQL
+            query Query($myInputObject: MyInputObject!) {
+              myInputObject(myInputObject: $myInputObject)
+            }
+          GQL
+
+          result = MySchema.coerce_variables(
+            query:,
+            variables: { "myInputObject" => { "myString" => "A string", "myInt" => 1 } },
+          )
+
+          assert_predicate(result, :ok?)
+          coerced_input_object = result.unwrap["myInputObject"]
+          assert_instance_of(MyInputObject, coerced_input_object)
+          assert_equal("A string", coerced_input_object.my_string)
+          assert_equal(1, coerced_input_object.my_int)
+        end
+
+        def test_coerce_variables_invalid
+          query = <<~GQL
+            query Query($myInputObject: MyInputObject!, $otherInputObject: MyInputObject!) {
+              myInputObject(myInputObject: $myInputObject)
+              other: myInputObject(myInputObject: $otherInputObject)
+            }
+          GQL
+
+          result = MySchema.coerce_variables(
+            query:,
+            variables: { "myInputObject" => { "myString" => "A string", "myInt" => "not an int" } },
+          )
+
+          assert_predicate(result, :err?)
+          assert_equal(
+            [
+              CoercionError.new(
+                "No implicit conversion of string to integer",
+                ["myInputObject", "myInt"],
+                expected_type: "Int!",
+                value: "not an int",
+              ),
+              CoercionError.new(
+                "No value was provided for required variable `$otherInputObject`",
+                ["otherInputObject"],
+              ),
+            ],
+            result.unwrap_err,
+          )
+        end
+
+        def test_coerce_variables_unknown_operation
+          result = MySchema.coerce_variables(
+            query: "query Query { __typename }",
+            operation_name: "Other",
+          )
+
+          assert_equal(
+            [CoercionError.new("No operation definition named `Other`", [])],
+            result.unwrap_err,
+          )
+        end
       end
     end
   end
@@ -28,6 +28,27 @@ module Bluejay
                     InputValueDefinition.new(name: "intListList", type: lit!(lit!(it!(Scalar::Int)))),
                   ],
                 ),
+                FieldDefinition.new(
+                  name: "nullableIntList",
+                  type: lot(ot(Scalar::Int)),
+                  argument_definitions: [
+                    InputValueDefinition.new(name: "arg", type: lit(it(Scalar::Int))),
+                  ],
+                ),
+                FieldDefinition.new(
+                  name: "nullableIntListList",
+                  type: lot(lot(ot(Scalar::Int))),
+                  argument_definitions: [
+                    InputValueDefinition.new(name: "arg", type: lit(lit(it(Scalar::Int)))),
+                  ],
+                ),
+                FieldDefinition.new(
+                  name: "nonNullItemIntList",
+                  type: lot(ot!(Scalar::Int)),
+                  argument_definitions: [
+                    InputValueDefinition.new(name: "arg", type: lit(it!(Scalar::Int))),
+                  ],
+                ),
               ]
             end
           end
@@ -59,6 +80,26 @@ module Bluejay
               def int_list_list(int_list_list:)
                 int_list_list
               end
+
+              sig do
+                params(arg: T.nilable(T::Array[T.nilable(Integer)])).returns(T.nilable(T::Array[T.nilable(Integer)]))
+              end
+              def nullable_int_list(arg:)
+                arg
+              end
+
+              sig do
+                params(arg: T.nilable(T::Array[T.nilable(T::Array[T.nilable(Integer)])]))
+                  .returns(T.nilable(T::Array[T.nilable(T::Array[T.nilable(Integer)])]))
+              end
+              def nullable_int_list_list(arg:)
+                arg
+              end
+
+              sig { params(arg: T.nilable(T::Array[Integer])).returns(T.nilable(T::Array[Integer])) }
+              def non_null_item_int_list(arg:)
+                arg
+              end
             end
           end
 
@@ -73,6 +114,99 @@ module Bluejay
           end
         end
 
+        # Derived from the input coercion table for lists in the GraphQL specification,
+        # extended with the nullability cases. Each row is checked both as a literal argument
+        # and as a variable of the same type. `:error` means coercion must fail.
+        SPEC_COERCION_TABLE = [
+          ["nullableIntList", "[Int]", [1, 2, 3], [1, 2, 3]],
+          ["nullableIntList", "[Int]", [1, "b", true], :error],
+          ["nullableIntList", "[Int]", 1, [1]],
+          ["nullableIntList", "[Int]", nil, nil],
+          ["nullableIntList", "[Int]", [1, nil], [1, nil]],
+          ["nullableIntList", "[Int]", [], []],
+          ["nullableIntListList", "[[Int]]", [[1], [2, 3]], [[1], [2, 3]]],
+          ["nullableIntListList", "[[Int]]", [1, 2, 3], :error],
+          ["nullableIntListList", "[[Int]]", 1, [[1]]],
+          ["nullableIntListList", "[[Int]]", nil, nil],
+          ["nullableIntListList", "[[Int]]", [[1], nil], [[1], nil]],
+          ["nullableIntListList", "[[Int]]", [nil], [nil]],
+          ["nonNullItemIntList", "[Int!]", [1, 2], [1, 2]],
+          ["nonNullItemIntList", "[Int!]", [1, nil], :error],
+          ["nonNullItemIntList", "[Int!]", 1, [1]],
+          ["nonNullItemIntList", "[Int!]", nil, nil],
+          ["intListList", "[[Int!]!]!", [[1], nil], :error],
+          ["intListList", "[[Int!]!]!", [[1, nil]], :error],
+          ["intListList", "[[Int!]!]!", nil, :error],
+        ].freeze
+
+        def test_spec_coercion_table_for_literals
+          SPEC_COERCION_TABLE.each do |field_name, _type, provided, expected|
+            arg_name = field_name.start_with?("intList") ? field_name : "arg"
+            result = MySchema.execute(
+              query: "{ #{field_name}(#{arg_name}: #{graphql_literal(provided)}) }",
+              initial_value: Domain::SchemaRoot,
+            )
+
+            assert_coerced(expected, field_name, result, "literal #{graphql_literal(provided)} for #{field_name}")
+          end
+        end
+
+        def test_spec_coercion_table_for_variables
+          SPEC_COERCION_TABLE.each do |field_name, type, provided, expected|
+            arg_name = field_name.start_with?("intList") ? field_name : "arg"
+            result = MySchema.execute(
+              query: "query Query($value: #{type}) { #{field_name}(#{arg_name}: $value) }",
+              variables: { "value" => provided },
+              initial_value: Domain::SchemaRoot,
+            )
+
+            assert_coerced(expected, field_name, result, "variable #{provided.inspect} for #{field_name}")
+          end
+        end
+
+        def test_variables_inside_list_literal
+          query = <<~GQL
+            query Query($a: Int, $b: Int) {
+              nullableIntList(arg: [$a, $b])
+            }
+          GQL
+
+          result = MySchema.execute(query:, variables: { "a" => 1 }, initial_value: Domain::SchemaRoot)
+
+          assert_empty(result.errors)
+          assert_equal({ "nullableIntList" => [1, nil] }, result.value)
+        end
+
+        def test_absent_variable_inside_list_literal_with_non_null_items
+          query = <<~GQL
+            query Query($a: Int, $b: Int) {
+              nonNullItemIntList(arg: [$a, $b])
+            }
+          GQL
+
+          result = MySchema.execute(query:, variables: { "a" => 1 }, initial_value: Domain::SchemaRoot)
+
+          assert_equal(
+            [ExecutionError.new("No value was provided for $b, which is invalid for Int!")],
+            result.errors,
+          )
+        end
+
+        def test_null_variable_inside_list_literal_with_non_null_items
+          query = <<~GQL
+            query Query($a: Int, $b: Int) {
+              nonNullItemIntList(arg: [$a, $b])
+            }
+          GQL
+
+          result = MySchema.execute(query:, variables: { "a" => 1, "b" => nil }, initial_value: Domain::SchemaRoot)
+
+          assert_equal(
+            [ExecutionError.new("Received `null` for $b, which is invalid for Int!")],
+            result.errors,
+          )
+        end
+
         def test_coerce_list_from_variables_valid_list
           query = <<~GQL
             query Query($intList: [Int!]!) {
@@ -380,6 +514,56 @@ module Bluejay
             result.errors,
           )
         end
+
+        def test_coercion_error_structured_data_from_variables
+          query = <<~GQL
+            query Query($intList: [Int!]!) {
+              intList(intList: $intList)
+            }
+          GQL
+
+          errors = MySchema.coerce_variables(query:, variables: { "intList" => [1, "not an int"] }).unwrap_err
+
+          assert_equal(1, errors.length)
+          error = errors.first
+          assert_equal(["intList", "1"], error.path)
+          assert_equal("[Int!]!", error.expected_type)
+          assert_equal([1, "not an int"], error.value)
+          assert_equal({ "expectedType" => "[Int!]!" }, error.extensions)
+        end
+
+        def test_coercion_error_structured_data_from_hard_coded_argument
+          result = MySchema.execute(
+            query: '{ intListList(intListList: "not an int") }',
+            initial_value: Domain::SchemaRoot,
+          )
+
+          assert_equal(1, result.errors.length)
+          assert_equal(
+            { "expectedType" => "[[Int!]!]!", "fieldPath" => ["intListList"], "argumentName" => "intListList" },
+            result.errors.first.extensions,
+          )
+        end
+
+        private
+
+        def graphql_literal(value)
+          case value
+          when nil then "null"
+          when Array then "[#{value.map { |v| graphql_literal(v) }.join(", ")}]"
+          when String then value.inspect
+          else value.to_s
+          end
+        end
+
+        def assert_coerced(expected, field_name, result, message)
+          if expected == :error
+            refute_empty(result.errors, message)
+          else
+            assert_empty(result.errors, message)
+            assert_equal({ field_name => expected }, result.value, message)
+          end
+        end
       end
     end
   end
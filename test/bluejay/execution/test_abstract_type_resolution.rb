@@ -0,0 +1,222 @@
+# typed: ignore
+# frozen_string_literal: true
+
+require "test_helper"
+
+module Bluejay
+  module Execution
+    class TestAbstractTypeResolution < Minitest::Test
+      class NamedInterfaceType < InterfaceType
+        class << self
+          extend(T::Sig)
+
+          sig { override.returns(T::Array[FieldDefinition]) }
+          def field_definitions
+            [
+              FieldDefinition.new(name: "name", type: ot!(Scalar::String)),
+            ]
+          end
+        end
+      end
+
+      class DogObjectType < ObjectType
+        class << self
+          extend(T::Sig)
+
+          sig { override.returns(String) }
+          def graphql_name
+            "Dog"
+          end
+
+          sig { override.returns(T::Array[FieldDefinition]) }
+          def field_definitions
+            [
+              FieldDefinition.new(name: "name", type: ot!(Scalar::String)),
+            ]
+          end
+
+          sig { override.returns(T::Array[InterfaceImplementation]) }
+          def interface_implementations
+            [InterfaceImplementation.new(interface: NamedInterfaceType)]
+          end
+        end
+      end
+
+      class CatObjectType < ObjectType
+        class << self
+          extend(T::Sig)
+
+          sig { override.returns(String) }
+          def graphql_name
+            "Cat"
+          end
+
+          sig { override.returns(T::Array[FieldDefinition]) }
+          def field_definitions
+            [
+              FieldDefinition.new(name: "name", type: ot!(Scalar::String)),
+            ]
+          end
+
+          sig { override.returns(T::Array[InterfaceImplementation]) }
+          def interface_implementations
+            [InterfaceImplementation.new(interface: NamedInterfaceType)]
+          end
+        end
+      end
+
+      class PetUnionType < UnionType
+        class << self
+          extend(T::Sig)
+
+          sig { override.returns(String) }
+          def graphql_name
+            "Pet"
+          end
+
+          sig { override.returns(T::Array[UnionMemberType]) }
+          def member_types
+            [
+              UnionMemberType.new(type: DogObjectType),
+              UnionMemberType.new(type: CatObjectType),
+            ]
+          end
+        end
+      end
+
+      class QueryRoot < Bluejay::QueryRoot
+        class << self
+          extend(T::Sig)
+
+          sig { override.returns(T::Array[FieldDefinition]) }
+          def field_definitions
+            [
+              FieldDefinition.new(name: "pet", type: ot(PetUnionType)),
+              FieldDefinition.new(name: "named", type: ot(NamedInterfaceType)),
+            ]
+          end
+        end
+      end
+
+      class MySchema < Schema
+        class << self
+          extend(T::Sig)
+
+          sig { override.returns(T.class_of(Bluejay::QueryRoot)) }
+          def query
+            QueryRoot
+          end
+        end
+      end
+
+      Dog = Struct.new(:name) do
+        include(DogObjectType::Interface)
+      end
+
+      TypenamedCat = Struct.new(:name, :resolve_typename)
+
+      QueryRootValue = Struct.new(:pet, :named) do
+        include(QueryRoot::Interface)
+      end
+
+      SchemaRoot = Struct.new(:query) do
+        include(MySchema::Root)
+      end
+
+      def test_resolves_by_interface_module
+        dog = { "__typename" => "Dog", "name" => "Rex" }
+
+        assert_equal({ "pet" => dog, "named" => dog }, execute(pet: Dog.new("Rex"), named: Dog.new("Rex")))
+      end
+
+      def test_resolves_by_typename_enum
+        cat = TypenamedCat.new("Tom", PetUnionType::Typename::Cat)
+
+        assert_equal(
+          { "pet" => { "__typename" => "Cat", "name" => "Tom" }, "named" => nil },
+          execute(pet: cat, named: nil),
+        )
+      end
+
+      def test_resolves_by_interface_typename_enum
+        cat = TypenamedCat.new("Tom", NamedInterfaceType::Typename::Cat)
+
+        assert_equal(
+          { "pet" => nil, "named" => { "__typename" => "Cat", "name" => "Tom" } },
+          execute(pet: nil, named: cat),
+        )
+      end
+
+      def test_resolves_by_typename_string
+        cat = TypenamedCat.new("Tom", "Cat")
+
+        assert_equal(
+          { "pet" => nil, "named" => { "__typename" => "Cat", "name" => "Tom" } },
+          execute(pet: nil, named: cat),
+        )
+      end
+
+      def test_typename_that_is_not_a_possible_type
+        result = MySchema.execute(
+          query: "{ pet { __typename } }",
+          operation_name: nil,
+          initial_value: SchemaRoot.new(QueryRootValue.new(TypenamedCat.new("Tom", "QueryRoot"), nil)),
+        )
+
+        assert_equal({ "pet" => nil }, result.value)
+        assert_equal(
+          ["Internal error: `QueryRoot` is not a possible type for `Pet`"],
+          result.errors.map(&:message),
+        )
+      end
+
+      def test_typename_enum
+        assert_equal(["Dog", "Cat"], PetUnionType::Typename.values.map(&:serialize))
+      end
+
+      def test_interface_typename_enum
+        assert_equal(["Cat", "Dog"], NamedInterfaceType::Typename.values.map(&:serialize))
+      end
+
+      def test_interface_typename_enum_missing_implementor_of_another_schema
+        ferret_object_type = Class.new(ObjectType)
+        ferret_object_type.define_singleton_method(:graphql_name) { "Ferret" }
+        ferret_object_type.define_singleton_method(:field_definitions) do
+          [FieldDefinition.new(name: "name", type: OutputType.new(type: Scalar::String, required: true))]
+        end
+        ferret_object_type.define_singleton_method(:interface_implementations) do
+          [InterfaceImplementation.new(interface: NamedInterfaceType)]
+        end
+        query_root = Class.new(Bluejay::QueryRoot)
+        query_root.define_singleton_method(:graphql_name) { "QueryRoot" }
+        query_root.define_singleton_method(:field_definitions) do
+          [FieldDefinition.new(name: "ferret", type: OutputType.new(type: ferret_object_type, required: false))]
+        end
+        schema = Class.new(Schema)
+        schema.define_singleton_method(:query) { query_root }
+
+        error = assert_raises(Errors::TypenameEnumError) { schema.send(:definition) }
+
+        assert_equal(
+          "`Typename` of `Bluejay::Execution::TestAbstractTypeResolution::NamedInterfaceType` was defined without " \
+            "`Ferret`, which implement it in another schema",
+          error.message,
+        )
+      end
+
+      private
+
+      def execute(pet:, named:)
+        result = MySchema.execute(
+          query: "{ pet { __typename ...on Dog { name } ...on Cat { name } } named { __typename name } }",
+          operation_name: nil,
+          initial_value: SchemaRoot.new(QueryRootValue.new(pet, named)),
+        )
+
+        assert_empty(result.errors)
+
+        result.value
+      end
+    end
+  end
+end
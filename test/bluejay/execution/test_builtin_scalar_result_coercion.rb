@@ -0,0 +1,131 @@
+# typed: ignore
+# frozen_string_literal: true
+
+require "test_helper"
+
+module Bluejay
+  module Execution
+    class TestBuiltinScalarResultCoercion < Minitest::Test
+      class QueryRoot < Bluejay::QueryRoot
+        class << self
+          extend(T::Sig)
+
+          sig { override.returns(T::Array[FieldDefinition]) }
+          def field_definitions
+            [
+              FieldDefinition.new(name: "int", type: ot(Scalar::Int)),
+              FieldDefinition.new(name: "id", type: ot(Scalar::ID)),
+            ]
+          end
+        end
+      end
+
+      class DefaultSchema < Schema
+        class << self
+          extend(T::Sig)
+
+          sig { override.returns(T.class_of(Bluejay::QueryRoot)) }
+          def query
+            QueryRoot
+          end
+        end
+      end
+
+      class LenientSchema < Schema
+        class << self
+          extend(T::Sig)
+
+          sig { override.returns(T.class_of(Bluejay::QueryRoot)) }
+          def query
+            QueryRoot
+          end
+
+          sig { override.returns(T::Boolean) }
+          def strict_result_coercion = true
+
+          sig { override.returns(T::Boolean) }
+          def coerce_integral_floats_to_int = true
+
+          sig { override.returns(T::Boolean) }
+          def wide_integer_ids = true
+        end
+      end
+
+      QueryRootValue = Struct.new(:int, :id) do
+        include(QueryRoot::Interface)
+      end
+
+      SchemaRoot = Struct.new(:query) do
+        include(DefaultSchema::Root)
+        include(LenientSchema::Root)
+      end
+
+      def test_default_rejects_integral_floats_and_wide_ids
+        result = execute(DefaultSchema, int: 1.0, id: 2**40)
+
+        assert_equal({ "int" => nil, "id" => nil }, result.value)
+        assert_equal(
+          [
+            ExecutionError.new("Cannot coerce result to builtin scalar `Int`", ["int"]),
+            ExecutionError.new("Cannot coerce result to builtin scalar `ID`", ["id"]),
+          ],
+          result.errors,
+        )
+      end
+
+      def test_coerces_integral_floats_and_wide_ids
+        result = execute(LenientSchema, int: 1.0, id: 2**70)
+
+        assert_empty(result.errors)
+        assert_equal({ "int" => 1, "id" => (2**70).to_s }, result.value)
+        assert_instance_of(Integer, result.value["int"])
+      end
+
+      def test_wide_integer_ids_within_safe_range_are_serialized_as_integers
+        [1, 2**40, 2**53 - 1, -(2**53 - 1)].each do |id|
+          result = execute(LenientSchema, int: 1, id:)
+
+          assert_empty(result.errors)
+          assert_equal({ "int" => 1, "id" => id }, result.value)
+        end
+      end
+
+      def test_wide_integer_ids_beyond_safe_range_are_serialized_as_strings
+        [2**53, -(2**53), 2**64].each do |id|
+          result = execute(LenientSchema, int: 1, id:)
+
+          assert_empty(result.errors)
+          assert_equal({ "int" => 1, "id" => id.to_s }, result.value)
+        end
+      end
+
+      def test_strict_errors_name_the_value
+        result = execute(LenientSchema, int: 2**40, id: 1.5)
+
+        assert_equal({ "int" => nil, "id" => nil }, result.value)
+        assert_equal(
+          [
+            ExecutionError.new("Cannot coerce result to builtin scalar `Int`, got 1099511627776", ["int"]),
+            ExecutionError.new("Cannot coerce result to builtin scalar `ID`, got 1.5", ["id"]),
+          ],
+          result.errors,
+        )
+      end
+
+      def test_non_integral_float_is_not_coerced_to_int
+        result = execute(LenientSchema, int: 1.5, id: nil)
+
+        assert_equal(
+          [ExecutionError.new("Cannot coerce result to builtin scalar `Int`, got 1.5", ["int"])],
+          result.errors,
+        )
+      end
+
+      private
+
+      def execute(schema, int:, id:)
+        schema.execute(query: "{ int id }", initial_value: SchemaRoot.new(QueryRootValue.new(int, id)))
+      end
+    end
+  end
+end
@@ -0,0 +1,125 @@
+# typed: ignore
+# frozen_string_literal: true
+
+require "test_helper"
+
+module Bluejay
+  module Execution
+    class TestExtraResolverArgs < Minitest::Test
+      class UserType < ObjectType
+        class << self
+          extend(T::Sig)
+
+          sig { override.returns(T::Array[FieldDefinition]) }
+          def field_definitions
+            [
+              FieldDefinition.new(name: "name", type: ot!(Scalar::String)),
+              FieldDefinition.new(
+                name: "isCurrentUser",
+                type: ot!(Scalar::Boolean),
+                resolver: ->(user, context:) { context[:current_user] == user.name },
+                extra_resolver_args: [:context],
+              ),
+              FieldDefinition.new(name: "path", type: lot!(ot!(Scalar::String)), extra_resolver_args: [:path]),
+            ]
+          end
+        end
+      end
+
+      class QueryRoot < Bluejay::QueryRoot
+        class << self
+          extend(T::Sig)
+
+          sig { override.returns(T::Array[FieldDefinition]) }
+          def field_definitions
+            [
+              FieldDefinition.new(
+                name: "users",
+                type: lot!(ot!(UserType)),
+                argument_definitions: [InputValueDefinition.new(name: "first", type: it(Scalar::Int))],
+                extra_resolver_args: [:context, :field_definition],
+              ),
+            ]
+          end
+        end
+      end
+
+      class MySchema < Schema
+        class << self
+          extend(T::Sig)
+
+          sig { override.returns(T.class_of(Bluejay::QueryRoot)) }
+          def query
+            QueryRoot
+          end
+        end
+      end
+
+      User = Struct.new(:name) do
+        include(UserType::Interface)
+
+        def path(path:) = path
+      end
+
+      class Root
+        include(QueryRoot::Interface)
+
+        attr_reader :field_definition
+
+        def users(first:, context:, field_definition:)
+          @field_definition = field_definition
+          context.fetch(:users).first(first || 2)
+        end
+      end
+
+      SchemaRoot = Struct.new(:query) do
+        include(MySchema::Root)
+      end
+
+      def test_extra_resolver_args
+        root = Root.new
+        result = MySchema.execute(
+          query: "{ users(first: 2) { name isCurrentUser path } }",
+          initial_value: SchemaRoot.new(root),
+          context: { current_user: "Bob", users: [User.new("Alice"), User.new("Bob"), User.new("Carol")] },
+        )
+
+        assert_empty(result.errors)
+        assert_equal(
+          {
+            "users" => [
+              { "name" => "Alice", "isCurrentUser" => false, "path" => ["users", "0", "path"] },
+              { "name" => "Bob", "isCurrentUser" => true, "path" => ["users", "1", "path"] },
+            ],
+          },
+          result.value,
+        )
+        assert_instance_of(FieldDefinition, root.field_definition)
+        assert_equal("users", root.field_definition.name)
+      end
+
+      def test_extra_resolver_args_reader
+        field_definition = QueryRoot.send(:definition).field_definitions.find { |fd| fd.name == "users" }
+
+        assert_equal([:context, :field_definition], field_definition.extra_resolver_args.map(&:kwarg_name))
+        assert_equal(["T.untyped", "Bluejay::FieldDefinition"], field_definition.extra_resolver_args.map(&:sorbet_type))
+      end
+
+      def test_unknown_extra_resolver_arg
+        error = assert_raises(ArgumentError) do
+          FieldDefinition.new(
+            name: "myField",
+            type: OutputType.new(type: Scalar::String, required: true),
+            extra_resolver_args: [:lookahead_and_more],
+          )
+        end
+
+        assert_equal(
+          "Unknown extra resolver arg `lookahead_and_more`, expected one of :schema_class, :context, :path, " \
+            ":field_definition or :lookahead",
+          error.message,
+        )
+      end
+    end
+  end
+end
@@ -0,0 +1,143 @@
+# typed: ignore
+# frozen_string_literal: true
+
+require "test_helper"
+
+module Bluejay
+  module Execution
+    class TestFieldResolvers < Minitest::Test
+      class FullNameResolver
+        def initialize(object)
+          @object = object
+        end
+
+        def resolve(separator:)
+          [@object.first_name, @object.last_name].join(separator || " ")
+        end
+      end
+
+      class InitialsResolver
+        def initialize(object)
+          @object = object
+        end
+
+        def resolve
+          "#{@object.first_name[0]}#{@object.last_name[0]}"
+        end
+      end
+
+      SHOUT = ->(object, **) { object.first_name.upcase }
+
+      class PersonType < ObjectType
+        class << self
+          extend(T::Sig)
+
+          sig { override.returns(T::Array[FieldDefinition]) }
+          def field_definitions
+            [
+              FieldDefinition.new(name: "firstName", type: ot!(Scalar::String)),
+              FieldDefinition.new(
+                name: "fullName",
+                type: ot!(Scalar::String),
+                argument_definitions: [InputValueDefinition.new(name: "separator", type: it(Scalar::String))],
+                resolver: FullNameResolver,
+              ),
+              FieldDefinition.new(name: "initials", type: ot!(Scalar::String), resolver: InitialsResolver),
+              FieldDefinition.new(name: "shout", type: ot!(Scalar::String), resolver: SHOUT),
+            ]
+          end
+        end
+      end
+
+      class QueryRoot < Bluejay::QueryRoot
+        class << self
+          extend(T::Sig)
+
+          sig { override.returns(T::Array[FieldDefinition]) }
+          def field_definitions
+            [
+              FieldDefinition.new(
+                name: "person",
+                type: ot!(PersonType),
+                argument_definitions: [InputValueDefinition.new(name: "lastName", type: it!(Scalar::String))],
+                resolver: ->(object, last_name:) { object.people.find { |person| person.last_name == last_name } },
+              ),
+            ]
+          end
+        end
+      end
+
+      class MySchema < Schema
+        class << self
+          extend(T::Sig)
+
+          sig { override.returns(T.class_of(Bluejay::QueryRoot)) }
+          def query
+            QueryRoot
+          end
+        end
+      end
+
+      Person = Struct.new(:first_name, :last_name) do
+        include(PersonType::Interface)
+      end
+
+      QueryRootValue = Struct.new(:people) do
+        include(QueryRoot::Interface)
+      end
+
+      SchemaRoot = Struct.new(:query) do
+        include(MySchema::Root)
+      end
+
+      def test_resolvers
+        result = MySchema.execute(
+          query: <<~GQL,
+            {
+              person(lastName: "Lovelace") {
+                firstName
+                fullName
+                hyphenated: fullName(separator: "-")
+                initials
+                shout
+              }
+            }
+          GQL
+          initial_value: SchemaRoot.new(QueryRootValue.new([Person.new("Ada", "Lovelace")])),
+        )
+
+        assert_empty(result.errors)
+        assert_equal(
+          {
+            "person" => {
+              "firstName" => "Ada",
+              "fullName" => "Ada Lovelace",
+              "hyphenated" => "Ada-Lovelace",
+              "initials" => "AL",
+              "shout" => "ADA",
+            },
+          },
+          result.value,
+        )
+      end
+
+      def test_no_resolver_method_for_fields_with_a_resolver
+        assert_includes(PersonType::Interface.instance_methods, :first_name)
+        refute_includes(PersonType::Interface.instance_methods, :full_name)
+        refute_includes(QueryRoot::Interface.instance_methods, :person)
+      end
+
+      def test_invalid_resolver
+        error = assert_raises(TypeError) do
+          FieldDefinition.new(
+            name: "myField",
+            type: OutputType.new(type: Scalar::String, required: true),
+            resolver: "my_field",
+          )
+        end
+
+        assert_equal("expected a class or an object responding to `call`, got String", error.message)
+      end
+    end
+  end
+end
@@ -0,0 +1,202 @@
+# typed: ignore
+# frozen_string_literal: true
+
+require "test_helper"
+
+module Bluejay
+  module Execution
+    class TestLookahead < Minitest::Test
+      class AuthorType < ObjectType
+        class << self
+          extend(T::Sig)
+
+          sig { override.returns(String) }
+          def graphql_name
+            "Author"
+          end
+
+          sig { override.returns(T::Array[FieldDefinition]) }
+          def field_definitions
+            [
+              FieldDefinition.new(name: "name", type: ot!(Scalar::String)),
+            ]
+          end
+        end
+      end
+
+      class PostType < ObjectType
+        class << self
+          extend(T::Sig)
+
+          sig { override.returns(String) }
+          def graphql_name
+            "Post"
+          end
+
+          sig { override.returns(T::Array[FieldDefinition]) }
+          def field_definitions
+            [
+              FieldDefinition.new(name: "title", type: ot!(Scalar::String)),
+              FieldDefinition.new(name: "author", type: ot!(AuthorType)),
+              FieldDefinition.new(
+                name: "commentCount",
+                type: ot!(Scalar::Int),
+                argument_definitions: [InputValueDefinition.new(name: "approved", type: it(Scalar::Boolean))],
+              ),
+            ]
+          end
+        end
+      end
+
+      class VideoType < ObjectType
+        class << self
+          extend(T::Sig)
+
+          sig { override.returns(String) }
+          def graphql_name
+            "Video"
+          end
+
+          sig { override.returns(T::Array[FieldDefinition]) }
+          def field_definitions
+            [
+              FieldDefinition.new(name: "durationSeconds", type: ot!(Scalar::Int)),
+            ]
+          end
+        end
+      end
+
+      class ContentUnionType < UnionType
+        class << self
+          extend(T::Sig)
+
+          sig { override.returns(String) }
+          def graphql_name
+            "Content"
+          end
+
+          sig { override.returns(T::Array[UnionMemberType]) }
+          def member_types
+            [UnionMemberType.new(type: PostType), UnionMemberType.new(type: VideoType)]
+          end
+        end
+      end
+
+      class QueryRoot < Bluejay::QueryRoot
+        class << self
+          extend(T::Sig)
+
+          sig { override.returns(T::Array[FieldDefinition]) }
+          def field_definitions
+            [
+              FieldDefinition.new(
+                name: "posts",
+                type: lot!(ot!(PostType)),
+                resolver: ->(root, lookahead:) { root.record(lookahead) },
+                extra_resolver_args: [:lookahead],
+              ),
+              FieldDefinition.new(
+                name: "feed",
+                type: lot!(ot!(ContentUnionType)),
+                resolver: ->(root, lookahead:) { root.record(lookahead) },
+                extra_resolver_args: [:lookahead],
+              ),
+            ]
+          end
+        end
+      end
+
+      class MySchema < Schema
+        class << self
+          extend(T::Sig)
+
+          sig { override.returns(T.class_of(Bluejay::QueryRoot)) }
+          def query
+            QueryRoot
+          end
+        end
+      end
+
+      class Root
+        include(QueryRoot::Interface)
+
+        attr_reader :lookahead
+
+        def record(lookahead)
+          @lookahead = lookahead
+          []
+        end
+      end
+
+      SchemaRoot = Struct.new(:query) do
+        include(MySchema::Root)
+      end
+
+      def test_selections
+        lookahead = execute(<<~GQL, variables: { "approved" => true })
+          query($approved: Boolean) {
+            posts {
+              title
+              ...AuthorFields
+              approvedComments: commentCount(approved: $approved)
+              commentCount @skip(if: true)
+            }
+          }
+
+          fragment AuthorFields on Post {
+            author { name }
+            title
+          }
+        GQL
+
+        assert_equal("posts", lookahead.name)
+        assert_equal(["title", "author", "approvedComments"], lookahead.selections.map(&:response_key))
+        assert(lookahead.selects?(:title))
+        assert(lookahead.selects?("commentCount"))
+        assert(lookahead.selects?(:comment_count))
+        refute(lookahead.selects?(:published_at))
+        assert_equal({ approved: true }, lookahead.selection(:comment_count).arguments)
+        assert_equal(["name"], lookahead.selection(:author).selections.map(&:name))
+        assert_empty(lookahead.selection(:title).selections)
+        assert_nil(lookahead.selection(:published_at))
+      end
+
+      def test_selections_on_abstract_types
+        lookahead = execute(<<~GQL)
+          {
+            feed {
+              __typename
+              ...on Post { title }
+              ...on Video { durationSeconds }
+            }
+          }
+        GQL
+
+        assert_equal(["__typename", "title", "durationSeconds"], lookahead.selections.map(&:name))
+      end
+
+      def test_invalid_arguments
+        lookahead = execute(<<~GQL, variables: { "approved" => "yes" })
+          query($approved: String) {
+            posts { commentCount(approved: $approved) }
+          }
+        GQL
+
+        error = assert_raises(Errors::BaseError) { lookahead.selection(:comment_count).arguments }
+        assert_match(/\AInvalid arguments for field `commentCount`/, error.message)
+        assert_predicate(lookahead.selections, :frozen?)
+      end
+
+      private
+
+      def execute(query, variables: {})
+        root = Root.new
+        result = MySchema.execute(query:, variables:, initial_value: SchemaRoot.new(root))
+
+        assert_empty(result.errors)
+
+        root.lookahead
+      end
+    end
+  end
+end
@@ -0,0 +1,195 @@
+# typed: ignore
+# frozen_string_literal: true
+
+require "test_helper"
+require "bluejay/native_scalars"
+
+module Bluejay
+  module Execution
+    class TestNativeScalars < Minitest::Test
+      SCALARS = T.let(
+        {
+          "dateTime" => NativeScalars::DateTime,
+          "date" => NativeScalars::Date,
+          "json" => NativeScalars::JSON,
+          "bigInt" => NativeScalars::BigInt,
+          "url" => NativeScalars::URL,
+          "uuid" => NativeScalars::UUID,
+          "decimal" => NativeScalars::Decimal,
+        },
+        T::Hash[String, T.class_of(NativeScalarType)],
+      )
+
+      class QueryRoot < Bluejay::QueryRoot
+        class << self
+          extend(T::Sig)
+
+          sig { override.returns(T::Array[FieldDefinition]) }
+          def field_definitions
+            SCALARS.map do |name, scalar|
+              FieldDefinition.new(
+                name:,
+                type: OutputType.new(type: scalar, required: false),
+                argument_definitions: [
+                  InputValueDefinition.new(name: "value", type: InputType.new(type: scalar, required: false)),
+                ],
+              )
+            end + [FieldDefinition.new(name: "invalidDate", type: ot(NativeScalars::Date))]
+          end
+        end
+      end
+
+      class MySchema < Schema
+        class << self
+          extend(T::Sig)
+
+          sig { override.returns(T.class_of(Bluejay::QueryRoot)) }
+          def query
+            QueryRoot
+          end
+        end
+      end
+
+      class Root
+        include(QueryRoot::Interface)
+
+        define_method(:date_time) { |value:| value }
+        define_method(:date) { |value:| value }
+        define_method(:json) { |value:| value }
+        define_method(:big_int) { |value:| value }
+        define_method(:url) { |value:| value }
+        define_method(:uuid) { |value:| value }
+        define_method(:decimal) { |value:| value }
+        define_method(:invalid_date) { "2023-01-01" }
+      end
+
+      SchemaRoot = Struct.new(:query) do
+        include(MySchema::Root)
+      end
+
+      def test_specified_by_url
+        assert_equal("https://scalars.graphql.org/andimarek/date-time", NativeScalars::DateTime.specified_by_url)
+        assert_equal("https://www.rfc-editor.org/rfc/rfc8259#section-6", NativeScalars::BigInt.specified_by_url)
+        assert_equal("https://www.rfc-editor.org/rfc/rfc8259#section-6", NativeScalars::Decimal.specified_by_url)
+      end
+
+      def test_date_time_round_trip
+        assert_equal("2023-01-01T12:30:00.5+02:00", round_trip("dateTime", "2023-01-01T12:30:00.500+02:00"))
+        assert_equal("2023-01-01T00:00:00Z", round_trip("dateTime", "2023-01-01t00:00:00z"))
+      end
+
+      def test_date_time_coerce_input
+        result = NativeScalars.coerce_input(:date_time, "2023-01-01T12:30:00-05:00")
+
+        assert_predicate(result, :ok?)
+        assert_equal(Time.utc(2023, 1, 1, 17, 30), result.unwrap)
+        assert_equal(-5 * 3600, result.unwrap.utc_offset)
+      end
+
+      def test_date_time_sub_minute_utc_offset
+        result = NativeScalars.coerce_result(:date_time, Time.new(2023, 1, 1, 12, 30, 0, "+01:00:30"))
+
+        assert_predicate(result, :err?)
+        assert_equal("Expected a Time with a UTC offset in whole minutes", result.unwrap_err)
+      end
+
+      def test_date_round_trip
+        assert_equal("2024-02-29", round_trip("date", "2024-02-29"))
+        assert_equal(::Date.new(2024, 2, 29), NativeScalars.coerce_input(:date, "2024-02-29").unwrap)
+      end
+
+      def test_date_before_gregorian_reform
+        date = NativeScalars.coerce_input(:date, "1000-01-01").unwrap
+
+        assert_equal(::Date.new(1000, 1, 1, ::Date::GREGORIAN), date)
+        assert_equal([1000, 1, 1], [date.year, date.month, date.mday])
+        assert_equal("1000-01-01", round_trip("date", "1000-01-01"))
+        assert_equal("1000-01-01", NativeScalars.coerce_result(:date, ::Date.new(1000, 1, 1, ::Date::GREGORIAN)).unwrap)
+      end
+
+      def test_json_round_trip
+        value = { "a" => [1, 2.5, "b", nil, true] }
+
+        assert_equal(value, round_trip("json", value))
+      end
+
+      def test_big_int_round_trip
+        assert_equal("123456789012345678901234567890", round_trip("bigInt", "123456789012345678901234567890"))
+        assert_equal("-12", round_trip("bigInt", -12))
+        assert_equal(
+          123456789012345678901234567890,
+          NativeScalars.coerce_input(:big_int, "123456789012345678901234567890").unwrap,
+        )
+      end
+
+      def test_url_round_trip
+        assert_equal("https://example.com/path?q=1", round_trip("url", "https://example.com/path?q=1"))
+      end
+
+      def test_uuid_round_trip
+        assert_equal(
+          "0f8fad5b-d9cb-469f-a165-70867728950e",
+          round_trip("uuid", "0F8FAD5B-D9CB-469F-A165-70867728950E"),
+        )
+      end
+
+      def test_decimal_round_trip
+        assert_equal("1.1", round_trip("decimal", "1.10"))
+        assert_equal(BigDecimal("0.1"), NativeScalars.coerce_input(:decimal, "0.1").unwrap)
+      end
+
+      def test_invalid_inputs
+        {
+          "dateTime" => ["2023-01-01", "Expected an RFC 3339 date-time string"],
+          "date" => ["2023-02-30", "Expected an RFC 3339 full-date string"],
+          "bigInt" => ["1.5", "Expected an integer or a string encoding one"],
+          "url" => ["not a url", "Expected an absolute URL string"],
+          "uuid" => ["0f8fad5b", "Expected a UUID string"],
+          "decimal" => ["one", "Expected a decimal number or a string encoding one"],
+        }.each do |field, (value, message)|
+          result = execute(field, value)
+
+          assert_nil(result.value, field)
+          assert_equal([ExecutionError.new(message)], result.errors, field)
+        end
+      end
+
+      def test_invalid_result
+        result = MySchema.execute(query: "{ invalidDate }", initial_value: SchemaRoot.new(Root.new))
+
+        assert_equal({ "invalidDate" => nil }, result.value)
+        assert_equal(
+          [ExecutionError.new("Expected a Date", ["invalidDate"])],
+          result.errors,
+        )
+      end
+
+      def test_unknown_native_scalar
+        error = assert_raises(ArgumentError) { NativeScalars.coerce_input(:time, "12:00") }
+
+        assert_equal(
+          "Unknown native scalar `time`, expected one of :date_time, :date, :json, :big_int, :url, :uuid or :decimal",
+          error.message,
+        )
+      end
+
+      private
+
+      def execute(field, value)
+        MySchema.execute(
+          query: "query($value: #{SCALARS.fetch(field).graphql_name}) { #{field}(value: $value) }",
+          variables: { "value" => value },
+          initial_value: SchemaRoot.new(Root.new),
+        )
+      end
+
+      def round_trip(field, value)
+        result = execute(field, value)
+
+        assert_empty(result.errors)
+
+        result.value.fetch(field)
+      end
+    end
+  end
+end
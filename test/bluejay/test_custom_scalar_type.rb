@@ -28,6 +28,113 @@ module Bluejay
       end
     end
 
+    class RaisingDateScalar < CustomScalarType
+      extend(T::Generic)
+
+      InternalRepresentation = type_template { { fixed: Date } }
+
+      class << self
+        extend(T::Sig)
+
+        sig { override.returns(String) }
+        def graphql_name = "RaisingDate"
+
+        sig { override.returns(T.class_of(StandardError)) }
+        def coerce_input_exception_class = Date::Error
+
+        sig { override.returns(T.class_of(StandardError)) }
+        def coerce_result_exception_class = ArgumentError
+
+        sig { override.params(value: InternalRepresentation).returns(String) }
+        def coerce_result(value)
+          raise ArgumentError, "Date is in the future" if value > Date.today
+
+          value.iso8601
+        end
+
+        sig { override.params(value: T.untyped).returns(Date) }
+        def coerce_input(value)
+          Date.iso8601(value)
+        end
+      end
+    end
+
+    class IdentityScalar < CustomScalarType
+      extend(T::Generic)
+
+      InternalRepresentation = type_template { { fixed: String } }
+
+      class << self
+        extend(T::Sig)
+
+        sig { override.returns(String) }
+        def graphql_name = "Identity"
+
+        sig { override.params(value: T.untyped).returns(Result[String, String]) }
+        def coerce_input(value)
+          value.is_a?(String) ? Result.ok(value) : Result.err("Expected a string")
+        end
+      end
+    end
+
+    class QueryRoot < Bluejay::QueryRoot
+      class << self
+        extend(T::Sig)
+
+        sig { override.returns(T::Array[FieldDefinition]) }
+        def field_definitions
+          [
+            FieldDefinition.new(
+              name: "date",
+              type: ot!(RaisingDateScalar),
+              argument_definitions: [InputValueDefinition.new(name: "date", type: it!(RaisingDateScalar))],
+            ),
+            FieldDefinition.new(
+              name: "identity",
+              type: ot!(IdentityScalar),
+              argument_definitions: [InputValueDefinition.new(name: "value", type: it!(IdentityScalar))],
+            ),
+          ]
+        end
+      end
+    end
+
+    class MySchema < Schema
+      class << self
+        extend(T::Sig)
+
+        sig { override.returns(T.class_of(Bluejay::QueryRoot)) }
+        def query
+          QueryRoot
+        end
+      end
+    end
+
+    module Domain
+      class QueryRoot
+        class << self
+          extend(T::Sig)
+          include(TestCustomScalarType::QueryRoot::Interface)
+
+          sig { params(date: Date).returns(Date) }
+          def date(date:) = date
+
+          sig { params(value: String).returns(String) }
+          def identity(value:) = value
+        end
+      end
+
+      class SchemaRoot
+        class << self
+          extend(T::Sig)
+          include(MySchema::Root)
+
+          sig { returns(T.class_of(QueryRoot)) }
+          def query = QueryRoot
+        end
+      end
+    end
+
     def test_definition_exists
       refute_nil(DateScalar.send(:definition))
     end
@@ -38,6 +145,46 @@ module Bluejay
       assert_equal(Result.ok("2023-01-01"), DateScalar.coerce_result(date))
     end
 
-    # TODO: test coerce_input and coerce_result with signatures that raise instead of return results
+    def test_exception_signatures_valid
+      result = execute_date("2023-01-01")
+
+      assert_empty(result.errors)
+      assert_equal({ "date" => "2023-01-01" }, result.value)
+    end
+
+    def test_exception_signature_input_coercion_error
+      result = execute_date("not a date")
+
+      assert_nil(result.value)
+      assert_equal([ExecutionError.new("invalid date")], result.errors)
+    end
+
+    def test_exception_signature_result_coercion_error
+      result = execute_date(Date.today.next_day.iso8601)
+
+      assert_nil(result.value)
+      assert_equal(
+        [ExecutionError.new("Date is in the future", ["date"])],
+        result.errors,
+      )
+      assert_equal({ "scalarName" => "RaisingDate" }, result.errors.first.extensions)
+    end
+
+    def test_default_coerce_result
+      result = MySchema.execute(query: "{ identity(value: \"abc\") }", initial_value: Domain::SchemaRoot)
+
+      assert_empty(result.errors)
+      assert_equal({ "identity" => "abc" }, result.value)
+    end
+
+    private
+
+    def execute_date(date)
+      MySchema.execute(
+        query: "query($date: RaisingDate!) { date(date: $date) }",
+        variables: { "date" => date },
+        initial_value: Domain::SchemaRoot,
+      )
+    end
   end
 end
@@ -0,0 +1,267 @@
+# typed: ignore
+# frozen_string_literal: true
+
+require "test_helper"
+
+module Bluejay
+  class TestDeprecatedUsages < Minitest::Test
+    class ColourEnum < EnumType
+      class << self
+        extend(T::Sig)
+
+        sig { override.returns(T::Array[EnumValueDefinition]) }
+        def enum_value_definitions
+          [
+            EnumValueDefinition.new(name: "RED"),
+            EnumValueDefinition.new(name: "BLURPLE", deprecation_reason: "Not a real colour"),
+          ]
+        end
+      end
+    end
+
+    class FilterInput < InputObjectType
+      class << self
+        extend(T::Sig)
+
+        sig { override.returns(T::Array[InputValueDefinition]) }
+        def input_field_definitions
+          [
+            InputValueDefinition.new(name: "colours", type: lit(it!(ColourEnum))),
+            InputValueDefinition.new(name: "legacy", type: it(Scalar::Boolean), deprecation_reason: "Unused"),
+          ]
+        end
+      end
+    end
+
+    class Tint < Directive
+      class << self
+        extend(T::Sig)
+
+        sig { override.returns(String) }
+        def graphql_name
+          "tint"
+        end
+
+        sig { override.returns(T::Array[InputValueDefinition]) }
+        def argument_definitions
+          [
+            InputValueDefinition.new(name: "colour", type: it(ColourEnum)),
+            InputValueDefinition.new(name: "shade", type: it(Scalar::Int), deprecation_reason: "Use `colour`"),
+          ]
+        end
+
+        sig { override.returns(T::Array[DirectiveLocation]) }
+        def locations
+          [DirectiveLocation::FIELD_DEFINITION, DirectiveLocation::FIELD]
+        end
+      end
+    end
+
+    class Widget < ObjectType
+      class << self
+        extend(T::Sig)
+
+        sig { override.returns(T::Array[FieldDefinition]) }
+        def field_definitions
+          [
+            FieldDefinition.new(name: "name", type: ot!(Scalar::String), directives: [Tint.new]),
+            FieldDefinition.new(name: "oldName", type: ot!(Scalar::String), deprecation_reason: "Use `name`"),
+          ]
+        end
+      end
+    end
+
+    class QueryRoot < Bluejay::QueryRoot
+      class << self
+        extend(T::Sig)
+
+        sig { override.returns(T::Array[FieldDefinition]) }
+        def field_definitions
+          [
+            FieldDefinition.new(
+              name: "widgets",
+              type: lot!(ot!(Widget)),
+              argument_definitions: [
+                InputValueDefinition.new(name: "filter", type: it(FilterInput)),
+                InputValueDefinition.new(name: "limit", type: it(Scalar::Int), deprecation_reason: "Paginate instead"),
+              ],
+            ),
+          ]
+        end
+      end
+    end
+
+    class MySchema < Schema
+      class << self
+        extend(T::Sig)
+
+        sig { override.returns(T.class_of(Bluejay::QueryRoot)) }
+        def query
+          QueryRoot
+        end
+      end
+    end
+
+    def test_no_deprecated_usages
+      assert_empty(MySchema.deprecated_usages(query: "{ widgets { name } }"))
+    end
+
+    def test_deprecated_usages
+      query = <<~GQL
+        query Widgets {
+          widgets(limit: 1, filter: { colours: [RED, BLURPLE], legacy: true }) {
+            name
+            ...WidgetFields
+          }
+        }
+
+        fragment WidgetFields on Widget {
+          previousName: oldName
+        }
+      GQL
+
+      usages = MySchema.deprecated_usages(query:)
+
+      assert_equal(
+        [
+          {
+            "kind" => :argument,
+            "coordinate" => "QueryRoot.widgets(limit:)",
+            "reason" => "Paginate instead",
+            "path" => ["widgets"],
+            "argument_path" => ["limit"],
+            "location" => { "line" => 2, "column" => 11 },
+          },
+          {
+            "kind" => :enum_value,
+            "coordinate" => "ColourEnum.BLURPLE",
+            "reason" => "Not a real colour",
+            "path" => ["widgets"],
+            "argument_path" => ["filter", "colours", "1"],
+            "location" => { "line" => 2, "column" => 46 },
+          },
+          {
+            "kind" => :input_field,
+            "coordinate" => "FilterInput.legacy",
+            "reason" => "Unused",
+            "path" => ["widgets"],
+            "argument_path" => ["filter", "legacy"],
+            "location" => { "line" => 2, "column" => 56 },
+          },
+          {
+            "kind" => :field,
+            "coordinate" => "Widget.oldName",
+            "reason" => "Use `name`",
+            "path" => ["widgets", "previousName"],
+            "argument_path" => nil,
+            "location" => { "line" => 9, "column" => 17 },
+          },
+        ],
+        usages.map(&:to_h),
+      )
+    end
+
+    def test_deprecated_usages_with_operation_name
+      query = <<~GQL
+        query A { widgets { oldName } }
+        query B { widgets { name } }
+      GQL
+
+      assert_empty(MySchema.deprecated_usages(query:, operation_name: "B"))
+      assert_equal(1, MySchema.deprecated_usages(query:, operation_name: "A").length)
+    end
+
+    def test_deprecated_usages_in_variables
+      query = <<~GQL
+        query Widgets($filter: FilterInput, $limit: Int = 5) {
+          widgets(filter: $filter, limit: $limit) { name }
+        }
+      GQL
+
+      usages = MySchema.deprecated_usages(
+        query:,
+        variables: { "filter" => { "colours" => ["RED", "BLURPLE"], "legacy" => false } },
+      )
+
+      assert_equal(
+        [
+          {
+            "kind" => :enum_value,
+            "coordinate" => "ColourEnum.BLURPLE",
+            "reason" => "Not a real colour",
+            "path" => ["widgets"],
+            "argument_path" => ["filter", "colours", "1"],
+            "location" => { "line" => 2, "column" => 19 },
+          },
+          {
+            "kind" => :input_field,
+            "coordinate" => "FilterInput.legacy",
+            "reason" => "Unused",
+            "path" => ["widgets"],
+            "argument_path" => ["filter", "legacy"],
+            "location" => { "line" => 2, "column" => 19 },
+          },
+          {
+            "kind" => :argument,
+            "coordinate" => "QueryRoot.widgets(limit:)",
+            "reason" => "Paginate instead",
+            "path" => ["widgets"],
+            "argument_path" => ["limit"],
+            "location" => { "line" => 2, "column" => 28 },
+          },
+        ],
+        usages.map(&:to_h),
+      )
+    end
+
+    def test_deprecated_usages_in_variable_default_values
+      query = <<~GQL
+        query Widgets($colours: [ColourEnum!] = [BLURPLE]) {
+          widgets(filter: { colours: $colours }) { name }
+        }
+      GQL
+
+      usages = MySchema.deprecated_usages(query:)
+
+      assert_equal(1, usages.length)
+      assert_equal("ColourEnum.BLURPLE", usages.first.coordinate)
+      assert_equal(["filter", "colours", "0"], usages.first.argument_path)
+      assert_equal(1, usages.first.line)
+      assert_equal(42, usages.first.column)
+
+      assert_empty(MySchema.deprecated_usages(query:, variables: { "colours" => ["RED"] }))
+    end
+
+    def test_deprecated_usages_in_directive_arguments
+      query = "{ widgets { name @tint(shade: 1, colour: BLURPLE) } }"
+
+      usages = MySchema.deprecated_usages(query:)
+
+      assert_equal(
+        [
+          {
+            "kind" => :argument,
+            "coordinate" => "@tint(shade:)",
+            "reason" => "Use `colour`",
+            "path" => ["widgets", "name"],
+            "argument_path" => ["shade"],
+            "location" => { "line" => 1, "column" => 24 },
+          },
+          {
+            "kind" => :enum_value,
+            "coordinate" => "ColourEnum.BLURPLE",
+            "reason" => "Not a real colour",
+            "path" => ["widgets", "name"],
+            "argument_path" => ["colour"],
+            "location" => { "line" => 1, "column" => 42 },
+          },
+        ],
+        usages.map(&:to_h),
+      )
+    end
+
+    def test_invalid_query
+      assert_raises(ArgumentError) { MySchema.deprecated_usages(query: "{") }
+    end
+  end
+end
@@ -24,5 +24,11 @@ module Bluejay
     def test_definition_exists
       refute_nil(MyEnumType.send(:definition))
     end
+
+    def test_value_constants
+      assert_equal("ONE", MyEnumType::ONE)
+      assert_equal("TWO", MyEnumType::TWO)
+      assert_raises(NameError) { MyEnumType::THREE }
+    end
   end
 end
@@ -56,7 +56,12 @@ module Bluejay
       assert_predicate(result, :err?)
       assert_equal(1, result.unwrap_err.length)
       assert_equal(
-        Bluejay::CoercionError.new("No field named `notAField` on MyInputObjectType", []),
+        Bluejay::CoercionError.new(
+          "No field named `notAField` on MyInputObjectType",
+          [],
+          expected_type: "MyInputObjectType",
+          value: { "myArg" => [], "notAField" => nil },
+        ),
         result.unwrap_err.first,
       )
     end
@@ -67,7 +72,12 @@ module Bluejay
       assert_predicate(result, :err?)
       assert_equal(1, result.unwrap_err.length)
       assert_equal(
-        Bluejay::CoercionError.new("No implicit conversion of integer to String", ["myArg"]),
+        Bluejay::CoercionError.new(
+          "No implicit conversion of integer to String",
+          ["myArg"],
+          expected_type: "[String!]!",
+          value: 1,
+        ),
         result.unwrap_err.first,
       )
     end
@@ -78,11 +88,29 @@ module Bluejay
       assert_predicate(result, :err?)
       assert_equal(1, result.unwrap_err.length)
       assert_equal(
-        Bluejay::CoercionError.new("Got null when a non-null value was expected", ["mySelf", "myArg"]),
+        Bluejay::CoercionError.new(
+          "Got null when a non-null value was expected",
+          ["mySelf", "myArg"],
+          expected_type: "[String!]!",
+          value: nil,
+        ),
         result.unwrap_err.first,
       )
     end
 
+    def test_coerce_input_error_in_list_of_nested_input_object
+      result = MyInputObjectType.coerce_input({ "myArg" => [], "mySelf" => { "myArg" => ["X", 1] } })
+
+      assert_predicate(result, :err?)
+      assert_equal(1, result.unwrap_err.length)
+      error = result.unwrap_err.first
+      # the path leads to the list item, while the expected type and value are those of the innermost input field
+      assert_equal(["mySelf", "myArg", "1"], error.path)
+      assert_equal("[String!]!", error.expected_type)
+      assert_equal(["X", 1], error.value)
+      assert_equal({ "expectedType" => "[String!]!" }, error.extensions)
+    end
+
     def test_initialize_and_accessors
       instance = MyInputObjectType.new(
         my_arg: ["X"],
@@ -28,6 +28,12 @@ module Bluejay
       assert_instance_of(Module, MyInterfaceType.const_get(:Interface))
     end
 
+    def test_typename_before_schema_definition
+      error = assert_raises(NameError) { MyInterfaceType.const_get(:Typename) }
+
+      assert_equal(:Typename, error.name)
+    end
+
     def test_const_missing
       assert_raises(NameError) { MyInterfaceType.const_get(:Foo) }
     end
@@ -0,0 +1,47 @@
+# typed: ignore
+# frozen_string_literal: true
+
+require "test_helper"
+
+module Bluejay
+  class TestNormalizeOperation < Minitest::Test
+    def test_normalize_operation
+      query = <<~GQL
+        query Foo($b: Int = 5, $a: Boolean!) {
+          zAlias: z(n: 3, arg: "x") @include(if: $a) { y x }
+          a
+          ...Frag
+        }
+
+        fragment Frag on Query { c(list: [1, 2]) }
+      GQL
+
+      result = Bluejay.normalize_operation(query)
+
+      assert_equal(
+        'query Foo($a:Boolean!,$b:Int=0){...on Query{c(list:[])} a z(arg:"",n:0)@include(if:$a){x y}}',
+        result[:signature],
+      )
+      assert_equal(Digest::SHA256.hexdigest(result[:signature]), result[:hash])
+    end
+
+    def test_normalize_operation_ignores_literals_aliases_and_order
+      a = Bluejay.normalize_operation("{ foo(x: 1) { bar baz: qux } }")
+      b = Bluejay.normalize_operation("query {\n  foo(x: 2) {\n    qux\n    bar\n  }\n}")
+
+      assert_equal(a, b)
+    end
+
+    def test_normalize_operation_with_operation_name
+      query = "query A { a } query B { b }"
+
+      assert_equal("query B{b}", Bluejay.normalize_operation(query, operation_name: "B")[:signature])
+      assert_raises(ArgumentError) { Bluejay.normalize_operation(query) }
+      assert_raises(ArgumentError) { Bluejay.normalize_operation(query, operation_name: "C") }
+    end
+
+    def test_normalize_operation_unparseable
+      assert_raises(ArgumentError) { Bluejay.normalize_operation("{") }
+    end
+  end
+end
@@ -0,0 +1,381 @@
+# typed: false
+# frozen_string_literal: true
+
+require "test_helper"
+
+module Bluejay
+  class TestOperationTypes < Minitest::Test
+    class EchoInput < InputObjectType
+      class << self
+        extend(T::Sig)
+
+        sig { override.returns(T::Array[InputValueDefinition]) }
+        def input_field_definitions
+          [
+            InputValueDefinition.new(name: "text", type: it!(Scalar::String)),
+            InputValueDefinition.new(name: "tags", type: lit(it!(Scalar::String))),
+          ]
+        end
+      end
+    end
+
+    class Colour < EnumType
+      class << self
+        extend(T::Sig)
+
+        sig { override.returns(T::Array[EnumValueDefinition]) }
+        def enum_value_definitions
+          [
+            EnumValueDefinition.new(name: "RED"),
+            EnumValueDefinition.new(name: "GREEN"),
+          ]
+        end
+      end
+    end
+
+    class Data < InputObjectType
+      class << self
+        extend(T::Sig)
+
+        sig { override.returns(T::Array[InputValueDefinition]) }
+        def input_field_definitions
+          [InputValueDefinition.new(name: "text", type: it!(Scalar::String))]
+        end
+      end
+    end
+
+    class FooObjectType < ObjectType
+      class << self
+        extend(T::Sig)
+
+        sig { override.returns(T::Array[FieldDefinition]) }
+        def field_definitions
+          [
+            FieldDefinition.new(name: "foo", type: ot!(Scalar::String)),
+            FieldDefinition.new(name: "count", type: ot(Scalar::Int)),
+          ]
+        end
+      end
+    end
+
+    class BarObjectType < ObjectType
+      class << self
+        extend(T::Sig)
+
+        sig { override.returns(T::Array[FieldDefinition]) }
+        def field_definitions
+          [
+            FieldDefinition.new(name: "bar", type: ot!(Scalar::String)),
+          ]
+        end
+      end
+    end
+
+    class FooOrBarUnionType < UnionType
+      class << self
+        extend(T::Sig)
+
+        sig { override.returns(T::Array[UnionMemberType]) }
+        def member_types
+          [
+            UnionMemberType.new(type: FooObjectType),
+            UnionMemberType.new(type: BarObjectType),
+          ]
+        end
+      end
+    end
+
+    class QueryRoot < Bluejay::QueryRoot
+      class << self
+        extend(T::Sig)
+
+        sig { override.returns(T::Array[FieldDefinition]) }
+        def field_definitions
+          [
+            FieldDefinition.new(
+              name: "echo",
+              type: ot!(Scalar::String),
+              argument_definitions: [InputValueDefinition.new(name: "input", type: it!(EchoInput))],
+            ),
+            FieldDefinition.new(
+              name: "foos",
+              type: lot!(ot!(FooObjectType)),
+              argument_definitions: [InputValueDefinition.new(name: "first", type: it(Scalar::Int))],
+            ),
+            FieldDefinition.new(name: "fooOrBar", type: ot!(FooOrBarUnionType)),
+            FieldDefinition.new(
+              name: "colours",
+              type: lot!(ot!(Colour)),
+              argument_definitions: [InputValueDefinition.new(name: "favourite", type: it!(Colour))],
+            ),
+            FieldDefinition.new(
+              name: "data",
+              type: ot!(Scalar::String),
+              argument_definitions: [InputValueDefinition.new(name: "data", type: it!(Data))],
+            ),
+          ]
+        end
+      end
+    end
+
+    class MySchema < Schema
+      class << self
+        extend(T::Sig)
+
+        sig { override.returns(T.class_of(Bluejay::QueryRoot)) }
+        def query
+          QueryRoot
+        end
+      end
+    end
+
+    module Domain
+      class Foo
+        class << self
+          extend(T::Sig)
+          include(FooObjectType::Interface)
+
+          sig { returns(String) }
+          def foo = "foo"
+
+          sig { returns(T.nilable(Integer)) }
+          def count = 1
+        end
+      end
+
+      class QueryRoot
+        class << self
+          extend(T::Sig)
+          include(TestOperationTypes::QueryRoot::Interface)
+
+          sig { params(input: EchoInput).returns(String) }
+          def echo(input:)
+            "#{input.text}: #{input.tags&.join(", ")}"
+          end
+
+          sig { params(first: T.nilable(Integer)).returns(T::Array[T.class_of(Foo)]) }
+          def foos(first:)
+            Array.new(first || 2) { Foo }
+          end
+
+          sig { returns(T.class_of(Foo)) }
+          def foo_or_bar = Foo
+
+          sig { params(favourite: String).returns(T::Array[String]) }
+          def colours(favourite:) = [favourite, "GREEN"]
+
+          sig { params(data: Data).returns(String) }
+          def data(data:) = data.text
+        end
+      end
+
+      class SchemaRoot
+        class << self
+          extend(T::Sig)
+          include(MySchema::Root)
+
+          sig { returns(T.class_of(QueryRoot)) }
+          def query = QueryRoot
+        end
+      end
+    end
+
+    QUERY = <<~GQL
+      query Everything($input: EchoInput!, $first: Int, $favourite: Colour!) {
+        echo(input: $input)
+        colours(favourite: $favourite)
+        foos(first: $first) { foo count }
+        fooOrBar {
+          __typename
+          ...on FooObjectType { foo }
+          ...on BarObjectType { bar }
+        }
+      }
+    GQL
+
+    def test_generated_types_round_trip
+      types = generated_types(QUERY)
+
+      variables = types::Everything::Variables.new(
+        input: types::Everything::EchoInput.new(text: "hi", tags: ["a"]),
+        favourite: types::Everything::Colour::Red,
+      )
+
+      assert_equal(
+        { "input" => { "text" => "hi", "tags" => ["a"] }, "favourite" => "RED" },
+        variables.to_h,
+      )
+
+      result = MySchema.execute(
+        query: QUERY,
+        variables: variables.to_h,
+        initial_value: Domain::SchemaRoot,
+      )
+
+      assert_empty(result.errors)
+
+      data = types::Everything::Data.from_h(result.value)
+
+      assert_equal("hi: a", data.echo)
+      assert_equal(2, data.foos.length)
+      assert_equal(["foo", 1], [data.foos.first.foo, data.foos.first.count])
+      assert_instance_of(types::Everything::Data::FooOrBar::FooObjectType, data.foo_or_bar)
+      assert_equal("foo", data.foo_or_bar.foo)
+      assert_equal([types::Everything::Colour::Red, types::Everything::Colour::Green], data.colours)
+    end
+
+    def test_execute_with_response_class
+      types = generated_types(QUERY)
+
+      result = MySchema.execute(
+        query: QUERY,
+        variables: { "input" => { "text" => "hi" }, "first" => 1, "favourite" => "RED" },
+        initial_value: Domain::SchemaRoot,
+        response_class: types::Everything::Data,
+      )
+
+      assert_empty(result.errors)
+
+      data = result.value
+
+      assert_instance_of(types::Everything::Data, data)
+      assert_equal("hi: ", data.echo)
+      assert_equal(1, data.foos.length)
+      assert_instance_of(types::Everything::Data::Foos, data.foos.first)
+      assert_equal("foo", data.foos.first.foo)
+      assert_instance_of(types::Everything::Data::FooOrBar::FooObjectType, data.foo_or_bar)
+      assert_equal("FooObjectType", data.foo_or_bar.__typename)
+      assert_equal([types::Everything::Colour::Red, types::Everything::Colour::Green], data.colours)
+    end
+
+    def test_execute_with_hand_written_response_class
+      response_class = Class.new do
+        attr_reader(:foos)
+
+        define_method(:initialize) { |foos:| @foos = foos }
+      end
+      response_class.const_set(:Foos, Struct.new(:foo, keyword_init: true))
+
+      result = MySchema.execute(
+        query: "{ foos { foo } }",
+        initial_value: Domain::SchemaRoot,
+        response_class:,
+      )
+
+      assert_empty(result.errors)
+      assert_equal(["foo", "foo"], result.value.foos.map(&:foo))
+    end
+
+    def test_execute_with_response_class_missing_nested_class
+      result = MySchema.execute(
+        query: "{ foos { foo } }",
+        initial_value: Domain::SchemaRoot,
+        response_class: Class.new,
+      )
+
+      assert_nil(result.value)
+      assert_equal(1, result.errors.length)
+      assert_equal(["foos"], result.errors.first.path)
+    end
+
+    def test_execute_with_response_class_ignores_inherited_constants
+      parent = Class.new
+      parent.const_set(:Foos, Struct.new(:foo, keyword_init: true))
+
+      result = MySchema.execute(
+        query: "{ foos { foo } }",
+        initial_value: Domain::SchemaRoot,
+        response_class: Class.new(parent),
+      )
+
+      assert_nil(result.value)
+      assert_equal(["foos"], result.errors.first.path)
+    end
+
+    def test_execute_with_response_class_instantiation_error
+      response_class = Class.new do
+        define_method(:initialize) { |foos:| @foos = foos }
+      end
+      response_class.const_set(:Foos, Class.new)
+
+      result = MySchema.execute(
+        query: "{ foos { foo } }",
+        initial_value: Domain::SchemaRoot,
+        response_class:,
+      )
+
+      assert_nil(result.value)
+      assert_includes(result.errors.map(&:path), ["foos", "0"])
+    end
+
+    def test_generated_types_source
+      source = MySchema.generate_operation_types(query: QUERY)
+
+      assert_includes(source, "const :first, T.nilable(Integer)")
+      assert_includes(source, "const :foos, T::Array[Foos]")
+      assert_includes(
+        source,
+        "const :foo_or_bar, T.any(FooOrBar::FooObjectType, FooOrBar::BarObjectType)",
+      )
+      assert_includes(source, "when \"BarObjectType\" then BarObjectType.from_h(hash)")
+      assert_includes(source, "const :favourite, Colour")
+      assert_includes(source, "const :colours, T::Array[Colours]")
+    end
+
+    def test_reserved_type_class_name
+      error = assert_raises(ArgumentError) do
+        MySchema.generate_operation_types(query: "query Q($data: Data!) { data(data: $data) }")
+      end
+
+      assert_equal(
+        "Type `Data` generates the class `Data`, which is reserved in the types of `Q`",
+        error.message,
+      )
+    end
+
+    def test_colliding_field_class_names
+      error = assert_raises(ArgumentError) do
+        MySchema.generate_operation_types(
+          query: "query Q { foo_or_bar: foos { foo } fooOrBar { __typename } }",
+        )
+      end
+
+      assert_equal(
+        "Fields `foo_or_bar` and `fooOrBar` of `Q` generate the same class `FooOrBar`, " \
+          "alias one of them to generate types for it",
+        error.message,
+      )
+    end
+
+    def test_type_conditions_require_typename
+      error = assert_raises(ArgumentError) do
+        MySchema.generate_operation_types(query: "query Q { fooOrBar { ...on FooObjectType { foo } } }")
+      end
+
+      assert_equal(
+        "Selection on `Q.fooOrBar` uses type conditions and must select `__typename` to generate types for it",
+        error.message,
+      )
+    end
+
+    def test_anonymous_operation
+      error = assert_raises(ArgumentError) do
+        MySchema.generate_operation_types(query: "{ echo(input: { text: \"hi\" }) }")
+      end
+
+      assert_equal("Operations must be named to generate types for them", error.message)
+    end
+
+    def test_invalid_document
+      assert_raises(ArgumentError) do
+        MySchema.generate_operation_types(query: "query Q { unknownField }")
+      end
+    end
+
+    private
+
+    def generated_types(query)
+      Module.new.tap { |mod| mod.module_eval(MySchema.generate_operation_types(query:)) }
+    end
+  end
+end
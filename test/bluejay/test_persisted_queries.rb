@@ -0,0 +1,164 @@
+# typed: ignore
+# frozen_string_literal: true
+
+require "test_helper"
+require "tmpdir"
+
+module Bluejay
+  class TestPersistedQueries < Minitest::Test
+    class QueryRoot < Bluejay::QueryRoot
+      class << self
+        extend(T::Sig)
+
+        sig { override.returns(T::Array[FieldDefinition]) }
+        def field_definitions
+          [FieldDefinition.new(name: "hello", type: ot!(Scalar::String))]
+        end
+      end
+    end
+
+    PERSISTED_QUERIES = PersistedQueries.new({ "hello" => "{ hello }" })
+
+    class MySchema < Schema
+      class << self
+        extend(T::Sig)
+
+        sig { override.returns(T.class_of(Bluejay::QueryRoot)) }
+        def query = QueryRoot
+
+        sig { override.returns(T.nilable(PersistedQueries)) }
+        def persisted_queries = PERSISTED_QUERIES
+      end
+    end
+
+    class OnlyPersistedSchema < Schema
+      class << self
+        extend(T::Sig)
+
+        sig { override.returns(T.class_of(Bluejay::QueryRoot)) }
+        def query = QueryRoot
+
+        sig { override.returns(T.nilable(PersistedQueries)) }
+        def persisted_queries = PERSISTED_QUERIES
+
+        sig { override.returns(T::Boolean) }
+        def only_persisted_queries = true
+      end
+    end
+
+    module Domain
+      class QueryRoot
+        class << self
+          extend(T::Sig)
+          include(TestPersistedQueries::QueryRoot::Interface)
+
+          sig { returns(String) }
+          def hello = "world"
+        end
+      end
+
+      class SchemaRoot
+        class << self
+          extend(T::Sig)
+          include(MySchema::Root)
+
+          sig { returns(T.class_of(QueryRoot)) }
+          def query = QueryRoot
+        end
+      end
+    end
+
+    def test_lookup
+      assert_equal("{ hello }", PERSISTED_QUERIES["hello"])
+      assert_equal("{ hello }", PERSISTED_QUERIES[PersistedQueries.hash_query("{ hello }")])
+      assert_nil(PERSISTED_QUERIES["goodbye"])
+    end
+
+    def test_add_defaults_id_to_hash
+      persisted_queries = PersistedQueries.new
+      id = persisted_queries.add("{ hello }")
+
+      assert_equal(PersistedQueries.hash_query("{ hello }"), id)
+      assert(persisted_queries.key?(id))
+    end
+
+    def test_execute_document_id
+      result = MySchema.execute(document_id: "hello", initial_value: Domain::SchemaRoot)
+
+      assert_equal({ "hello" => "world" }, result.value)
+      assert_empty(result.errors)
+
+      document_id = PersistedQueries.hash_query("{ hello }")
+      result = MySchema.execute(document_id:, initial_value: Domain::SchemaRoot)
+
+      assert_equal({ "hello" => "world" }, result.value)
+    end
+
+    def test_execute_unknown_document_id
+      result = MySchema.execute(document_id: "goodbye", initial_value: Domain::SchemaRoot)
+
+      assert_nil(result.value)
+      assert_equal([ExecutionError.new("No persisted query with id `goodbye`")], result.errors)
+    end
+
+    def test_execute_requires_query_or_document_id
+      assert_raises(ArgumentError) { MySchema.execute(initial_value: Domain::SchemaRoot) }
+      assert_raises(ArgumentError) do
+        MySchema.execute(query: "{ hello }", document_id: "hello", initial_value: Domain::SchemaRoot)
+      end
+    end
+
+    def test_only_persisted_queries
+      result = OnlyPersistedSchema.execute(query: "{ hello }", initial_value: Domain::SchemaRoot)
+
+      assert_equal([ExecutionError.new("Only persisted queries can be executed")], result.errors)
+
+      result = OnlyPersistedSchema.execute(document_id: "hello", initial_value: Domain::SchemaRoot)
+
+      assert_equal({ "hello" => "world" }, result.value)
+    end
+
+    def test_execute_batch_document_id
+      results = MySchema.execute_batch(
+        requests: [{ document_id: "hello" }, { query: "{ hello }" }, { document_id: "goodbye" }],
+        initial_value: Domain::SchemaRoot,
+      )
+
+      assert_equal([{ "hello" => "world" }, { "hello" => "world" }, nil], results.map(&:value))
+      assert_equal([ExecutionError.new("No persisted query with id `goodbye`")], results.last.errors)
+    end
+
+    def test_only_persisted_queries_execute_batch
+      results = OnlyPersistedSchema.execute_batch(
+        requests: [{ query: "{ hello }" }, { document_id: "hello" }],
+        initial_value: Domain::SchemaRoot,
+      )
+
+      assert_nil(results.first.value)
+      assert_equal([ExecutionError.new("Only persisted queries can be executed")], results.first.errors)
+      assert_equal({ "hello" => "world" }, results.last.value)
+    end
+
+    def test_validate
+      MySchema.validate_persisted_queries!
+
+      persisted_queries = PersistedQueries.new({
+        "valid" => "{ hello }",
+        "invalid" => "{ goodbye }",
+        "unparseable" => "{",
+      })
+      error = assert_raises(Errors::PersistedQueryValidationError) { persisted_queries.validate!(MySchema) }
+
+      assert_equal(["invalid", "unparseable"], error.errors.keys)
+    end
+
+    def test_from_directory
+      Dir.mktmpdir do |dir|
+        File.write(File.join(dir, "hello.graphql"), "{ hello }")
+        persisted_queries = PersistedQueries.from_directory(dir)
+
+        assert_equal("{ hello }", persisted_queries["hello"])
+      end
+    end
+  end
+end
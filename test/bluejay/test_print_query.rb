@@ -0,0 +1,111 @@
+# typed: ignore
+# frozen_string_literal: true
+
+require "test_helper"
+
+module Bluejay
+  class TestPrintQuery < Minitest::Test
+    QUERY = <<~GQL
+      query Foo($a: Int = 1, $b: [String!]!) @dir {
+        alias: field(arg: 1.5, s: "x") @include(if: true) { sub ...Frag ... on Bar { baz } }
+      }
+      fragment Frag on Baz { qux(e: ENUM, o: { k: [null] }) }
+    GQL
+
+    TRANSFORMABLE_QUERY = <<~GQL
+      query A($x: Int, $y: Int) { a(x: $x) ...F }
+      query B { b }
+      fragment F on Query { c }
+      fragment G on Query { d }
+    GQL
+
+    def test_print_query
+      expected = <<~GQL.chomp
+        query Foo($a: Int = 1, $b: [String!]!) @dir {
+          alias: field(arg: 1.5, s: "x") @include(if: true) {
+            sub
+            ...Frag
+            ... on Bar {
+              baz
+            }
+          }
+        }
+
+        fragment Frag on Baz {
+          qux(e: ENUM, o: {k: [null]})
+        }
+      GQL
+
+      assert_equal(expected, Bluejay.print_query(QUERY))
+      assert_equal(expected, Bluejay.print_query(expected))
+    end
+
+    def test_print_query_shorthand
+      assert_equal("{\n  a\n}", Bluejay.print_query("query { a }"))
+    end
+
+    def test_minify_query
+      expected = 'query Foo($a:Int=1,$b:[String!]!)@dir{alias:field(arg:1.5,s:"x")@include(if:true)' \
+        "{sub...Frag...on Bar{baz}}}fragment Frag on Baz{qux(e:ENUM,o:{k:[null]})}"
+
+      assert_equal(expected, Bluejay.minify_query(QUERY))
+      assert(Bluejay.parse(expected))
+    end
+
+    def test_removed_operations_and_unused
+      expected = <<~GQL.chomp
+        query A($x: Int) {
+          a(x: $x)
+          ...F
+        }
+
+        fragment F on Query {
+          c
+        }
+      GQL
+
+      assert_equal(
+        expected,
+        Bluejay.print_query(TRANSFORMABLE_QUERY, removed_operations: ["B"], remove_unused: true),
+      )
+    end
+
+    def test_inline_fragments
+      assert_equal(
+        "query A($x:Int){a(x:$x)...on Query{c}}",
+        Bluejay.minify_query(
+          TRANSFORMABLE_QUERY,
+          removed_operations: ["B"],
+          inline_fragments: true,
+          remove_unused: true,
+        ),
+      )
+    end
+
+    def test_variable_definition_directives
+      query = "query Q($a: Int = 1 @dir(x: 2), $b: Int @other) { a(a: $a, b: $b) }"
+
+      assert_equal(
+        "query Q($a: Int = 1 @dir(x: 2), $b: Int @other) {\n  a(a: $a, b: $b)\n}",
+        Bluejay.print_query(query),
+      )
+      assert_equal("query Q($a:Int=1@dir(x:2),$b:Int@other){a(a:$a,b:$b)}", Bluejay.minify_query(query))
+    end
+
+    def test_inline_fragments_keeps_fragment_definition_directives
+      query = <<~GQL
+        query Q { ...F @include(if: true) }
+        fragment F on Query @dir { a }
+      GQL
+
+      assert_equal(
+        "query Q{...on Query@include(if:true)@dir{a}}",
+        Bluejay.minify_query(query, inline_fragments: true),
+      )
+    end
+
+    def test_print_query_unparseable
+      assert_raises(ArgumentError) { Bluejay.print_query("{") }
+    end
+  end
+end
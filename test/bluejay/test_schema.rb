@@ -289,6 +289,37 @@ module Bluejay
       )
     end
 
+    def test_execute_batch
+      query = <<~GQL
+        query First { hello(name: { first: "John" last: "Smith" }) }
+        query Second($name: NameInputObject!) { hello(name: $name) }
+      GQL
+      root = Domain::SchemaRoot.new
+
+      results = MySchema.execute_batch(
+        requests: [
+          { query:, operation_name: "First" },
+          { query:, operation_name: "Second", variables: { "name" => { "first" => "Adam", "last" => "Petro" } } },
+          { query:, operation_name: nil },
+          { query: "{" },
+        ],
+        initial_value: root,
+      )
+
+      assert_equal(4, results.length)
+      assert_empty(results[0].errors)
+      assert_equal({ "hello" => "Hello, John Smith!" }, results[0].value)
+      assert_empty(results[1].errors)
+      assert_equal({ "hello" => "Hello, Adam Petro!" }, results[1].value)
+      assert_nil(results[2].value)
+      assert_equal(
+        ["Operation name is required when document does not contain exactly 1 operation definition"],
+        results[2].errors.map(&:message),
+      )
+      assert_nil(results[3].value)
+      refute_empty(results[3].errors)
+    end
+
     def test_execute_custom_scalar_coerce_result_error
       query = "{ today }"
       root = Domain::SchemaRoot.new(query: Domain::QueryRoot.new(today: Date.today.next_day))
@@ -304,6 +335,37 @@ module Bluejay
         ExecutionError.new("Did not return today", ["today"]),
         result.errors.first,
       )
+      assert_equal({ "scalarName" => "Date" }, result.errors.first.extensions)
+    end
+
+    def test_execution_result_to_json
+      result = MySchema.execute(
+        query: '{ hello(name: { first: "John", last: "Smith" }) }',
+        initial_value: Domain::SchemaRoot.new,
+      )
+
+      assert_equal('{"data":{"hello":"Hello, John Smith!"}}', result.to_json)
+      assert_equal(
+        '{"data":{"hello":"Hello, John Smith!"},"extensions":{"cost":1.0}}',
+        result.with_extensions({ "cost" => 1.0 }).to_json,
+      )
+    end
+
+    def test_execution_result_to_json_with_errors
+      root = Domain::SchemaRoot.new(query: Domain::QueryRoot.new(today: Date.today.next_day))
+
+      result = MySchema.execute(query: "{\n  today\n}", initial_value: root)
+
+      assert_equal(
+        '{"data":null,"errors":[{"message":"Did not return today",' \
+          '"locations":[{"line":2,"column":3}],"path":["today"],"extensions":{"scalarName":"Date"}}]}',
+        result.to_json,
+      )
+      assert_equal([{ "line" => 2, "column" => 3 }], result.errors.first.locations)
+
+      result = MySchema.execute(query: "{ hello }", operation_name: "Missing", initial_value: root)
+
+      assert_equal('{"errors":[{"message":"No operation definition named `Missing`"}]}', result.to_json)
     end
 
     def test_validate_query
@@ -324,6 +386,21 @@ module Bluejay
       assert_empty(MySchema.validate_query(query:))
     end
 
+    def test_validate_query_from_multiple_threads
+      errors = Array.new(4) do
+        Thread.new { MySchema.validate_query(query: "{ doesNotExist }") }
+      end.map(&:value)
+
+      errors.each do |thread_errors|
+        assert_equal(["Field `doesNotExist` does not exist on `QueryRoot`"], thread_errors.map(&:message))
+      end
+    end
+
+    def test_parse
+      assert(Bluejay.parse("{ __typename }"))
+      refute(Bluejay.parse("{"))
+    end
+
     def test_interface_module_exists
       assert_instance_of(Module, MySchema.const_get(:Root))
     end
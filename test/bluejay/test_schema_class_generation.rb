@@ -0,0 +1,154 @@
+# typed: ignore
+# frozen_string_literal: true
+
+require "test_helper"
+
+module Bluejay
+  class TestSchemaClassGeneration < Minitest::Test
+    SDL = <<~GQL
+      type BarObjectType {
+        bar: String!
+      }
+
+      scalar Date @specifiedBy(url: "https://example.com")
+
+      type FooObjectType {
+        foo: String!
+      }
+
+      union FooOrBarUnionType = FooObjectType | BarObjectType
+
+      enum MyEnumType {
+        ONE @deprecated(reason: "Testing deprecation")
+
+        TWO
+      }
+
+      input NameInputObject {
+        first: String! @deprecated(reason: "Testing deprecation")
+
+        last: String!
+      }
+
+      type QueryRoot {
+        hello(
+          name: NameInputObject! = { first: "DefaultFirst", last: "DefaultLast" }
+        ): String!
+
+        today: Date!
+
+        isToday(
+          date: Date!
+        ): Boolean!
+
+        deprecatedField: MyEnumType @deprecated(reason: "Testing deprecation")
+
+        fooOrBar: FooOrBarUnionType!
+      }
+
+      schema {
+        query: QueryRoot
+      }
+    GQL
+
+    def test_generated_file_names
+      assert_equal(
+        [
+          "bar_object_type.rb",
+          "date.rb",
+          "foo_object_type.rb",
+          "foo_or_bar_union_type.rb",
+          "my_enum_type.rb",
+          "name_input_object.rb",
+          "query_root.rb",
+          "schema.rb",
+        ],
+        Bluejay.generate_schema_classes(SDL, nil).keys.sort,
+      )
+    end
+
+    def test_generated_source
+      files = Bluejay.generate_schema_classes(SDL, "MyApp::Graph")
+
+      assert_equal(<<~RUBY, files.fetch("foo_or_bar_union_type.rb"))
+        # typed: strict
+        # frozen_string_literal: true
+
+        module MyApp
+          module Graph
+            class FooOrBarUnionType < Bluejay::UnionType
+              class << self
+                extend(T::Sig)
+
+                sig { override.returns(T::Array[Bluejay::UnionMemberType]) }
+                def member_types
+                  [
+                    Bluejay::UnionMemberType.new(type: FooObjectType),
+                    Bluejay::UnionMemberType.new(type: BarObjectType),
+                  ]
+                end
+              end
+            end
+          end
+        end
+      RUBY
+      assert_includes(files.fetch("query_root.rb"), "class QueryRoot < Bluejay::QueryRoot")
+      assert_includes(
+        files.fetch("query_root.rb"),
+        'Bluejay::FieldDefinition.new(name: "fooOrBar", type: ot!(FooOrBarUnionType)),',
+      )
+    end
+
+    def test_generated_custom_scalar_source
+      source = Bluejay.generate_schema_classes(SDL, nil).fetch("date.rb")
+
+      assert_includes(source, "InternalRepresentation = type_template { { fixed: T.untyped } }")
+      assert_includes(
+        source,
+        "sig { override.overridable.params(value: T.untyped)" \
+          ".returns(Bluejay::Result[InternalRepresentation, String]) }",
+      )
+      refute_includes(source, "TODO")
+    end
+
+    def test_type_named_schema
+      error = assert_raises(ArgumentError) do
+        Bluejay.generate_schema_classes("type Schema { foo: String }\n\nschema { query: Schema }", nil)
+      end
+
+      assert_equal("Type `Schema` generates the class `Schema`, which is reserved for the schema", error.message)
+    end
+
+    def test_types_with_the_same_file_name
+      error = assert_raises(ArgumentError) do
+        Bluejay.generate_schema_classes(
+          "type Query { foo: FooBar bar: Foo_Bar }\n\ntype FooBar { foo: String }\n\ntype Foo_Bar { foo: String }",
+          nil,
+        )
+      end
+
+      assert_equal("Types `FooBar` and `Foo_Bar` both generate the file `foo_bar.rb`", error.message)
+    end
+
+    def test_type_with_the_schema_file_name
+      error = assert_raises(ArgumentError) do
+        Bluejay.generate_schema_classes("type Query { foo: SCHEMA }\n\ntype SCHEMA { foo: String }", nil)
+      end
+
+      assert_equal("Type `SCHEMA` generates the file `schema.rb`, which is reserved for the schema", error.message)
+    end
+
+    def test_round_trip
+      namespace = Module.new
+      Bluejay.generate_schema_classes(SDL, nil).each_value { |source| namespace.module_eval(source) }
+
+      assert_equal(SDL, namespace.const_get(:Schema).to_definition)
+    end
+
+    def test_invalid_document
+      error = assert_raises(ArgumentError) { Bluejay.generate_schema_classes("type Query {", nil) }
+
+      assert_match(/\ADocument could not be parsed: /, error.message)
+    end
+  end
+end
@@ -44,5 +44,39 @@ module Bluejay
     def test_foo
       refute_nil(MyUnionType.send(:definition))
     end
+
+    def test_type_alias
+      assert(MyUnionType::Type.valid?(Object.new.extend(MyObjectType::Interface)))
+      refute(MyUnionType::Type.valid?(Object.new))
+    end
+
+    def test_typename_enum
+      assert_equal(["MyObjectType"], MyUnionType::Typename.values.map(&:serialize))
+    end
+
+    def test_typename_enum_with_name_that_cannot_be_a_constant
+      object_type = Class.new(ObjectType)
+      object_type.define_singleton_method(:graphql_name) { "_Private" }
+      object_type.define_singleton_method(:field_definitions) do
+        [FieldDefinition.new(name: "myField", type: OutputType.new(type: Scalar::String, required: true))]
+      end
+      union_type = Class.new(UnionType)
+      union_type.define_singleton_method(:graphql_name) { "PrivateUnion" }
+      union_type.define_singleton_method(:member_types) { [UnionMemberType.new(type: object_type)] }
+
+      error = assert_raises(Errors::TypenameEnumError) { union_type.send(:definition) }
+
+      assert_equal(
+        "Object type name `_Private` cannot be a value of a `Typename` enum, as it does not start with a letter",
+        error.message,
+      )
+    end
+
+    def test_sorbet_type
+      assert_equal(
+        "Bluejay::TestUnionType::MyObjectType::Interface",
+        OutputType.new(type: MyUnionType, required: true).sorbet_type,
+      )
+    end
   end
 end
@@ -0,0 +1,202 @@
+# typed: ignore
+# frozen_string_literal: true
+
+require "test_helper"
+
+module Bluejay
+  module Validation
+    class TestTypeSystemValidation < Minitest::Test
+      class TagDirective < Directive
+        class << self
+          extend(T::Sig)
+
+          sig { override.returns(String) }
+          def graphql_name
+            "tag"
+          end
+
+          sig { override.returns(T::Array[InputValueDefinition]) }
+          def argument_definitions
+            []
+          end
+
+          sig { override.returns(T::Array[DirectiveLocation]) }
+          def locations
+            [DirectiveLocation::FIELD_DEFINITION]
+          end
+        end
+      end
+
+      class NodeInterface < InterfaceType
+        class << self
+          extend(T::Sig)
+
+          sig { override.returns(String) }
+          def graphql_name
+            "Node"
+          end
+
+          sig { override.returns(T::Array[FieldDefinition]) }
+          def field_definitions
+            [FieldDefinition.new(name: "id", type: ot!(Scalar::ID))]
+          end
+        end
+      end
+
+      class EntityInterface < InterfaceType
+        class << self
+          extend(T::Sig)
+
+          sig { override.returns(String) }
+          def graphql_name
+            "Entity"
+          end
+
+          sig { override.returns(T::Array[FieldDefinition]) }
+          def field_definitions
+            [
+              FieldDefinition.new(name: "id", type: ot!(Scalar::ID)),
+              FieldDefinition.new(
+                name: "name",
+                type: ot(Scalar::String),
+                argument_definitions: [InputValueDefinition.new(name: "locale", type: it(Scalar::String))],
+              ),
+              FieldDefinition.new(name: "createdAt", type: ot(Scalar::String)),
+              FieldDefinition.new(name: "friend", type: ot(EntityInterface)),
+            ]
+          end
+
+          sig { override.returns(T::Array[InterfaceImplementation]) }
+          def interface_implementations
+            [InterfaceImplementation.new(interface: NodeInterface)]
+          end
+        end
+      end
+
+      class UserType < ObjectType
+        class << self
+          extend(T::Sig)
+
+          sig { override.returns(String) }
+          def graphql_name
+            "User"
+          end
+
+          sig { override.returns(T::Array[FieldDefinition]) }
+          def field_definitions
+            [
+              FieldDefinition.new(name: "id", type: ot(Scalar::ID)),
+              FieldDefinition.new(
+                name: "name",
+                type: ot(Scalar::String),
+                argument_definitions: [
+                  InputValueDefinition.new(name: "locale", type: it(Scalar::Int)),
+                  InputValueDefinition.new(name: "formal", type: it!(Scalar::Boolean)),
+                ],
+              ),
+              FieldDefinition.new(name: "friend", type: ot!(UserType)),
+              FieldDefinition.new(name: "__secret", type: ot(Scalar::String)),
+            ]
+          end
+
+          sig { override.returns(T::Array[InterfaceImplementation]) }
+          def interface_implementations
+            [InterfaceImplementation.new(interface: EntityInterface)]
+          end
+
+          sig { override.returns(T::Array[Directive]) }
+          def directives
+            [TagDirective.new]
+          end
+        end
+      end
+
+      class CycleAInput < InputObjectType
+        class << self
+          extend(T::Sig)
+
+          sig { override.returns(String) }
+          def graphql_name
+            "CycleA"
+          end
+
+          sig { override.returns(T::Array[InputValueDefinition]) }
+          def input_field_definitions
+            [InputValueDefinition.new(name: "b", type: it!(CycleBInput))]
+          end
+        end
+      end
+
+      class CycleBInput < InputObjectType
+        class << self
+          extend(T::Sig)
+
+          sig { override.returns(String) }
+          def graphql_name
+            "CycleB"
+          end
+
+          sig { override.returns(T::Array[InputValueDefinition]) }
+          def input_field_definitions
+            [InputValueDefinition.new(name: "a", type: it!(CycleAInput))]
+          end
+        end
+      end
+
+      class QueryRoot < Bluejay::QueryRoot
+        class << self
+          extend(T::Sig)
+
+          sig { override.returns(T::Array[FieldDefinition]) }
+          def field_definitions
+            [
+              FieldDefinition.new(name: "user", type: ot(UserType)),
+              FieldDefinition.new(
+                name: "cycle",
+                type: ot(Scalar::String),
+                argument_definitions: [InputValueDefinition.new(name: "input", type: it(CycleAInput))],
+              ),
+            ]
+          end
+        end
+      end
+
+      def test_type_system_validation
+        klass = Class.new(Schema) do
+          class << self
+            def query
+              QueryRoot
+            end
+          end
+        end
+
+        e = assert_raises(Errors::TypeSystemValidationError) do
+          klass.send(:definition)
+        end
+
+        assert_equal(
+          [
+            [:non_null_input_object_cycle, "CycleA"],
+            [:reserved_name, "User.__secret"],
+            [:missing_transitive_interface, "User"],
+            [:invalid_interface_field_type, "User.id"],
+            [:invalid_interface_field_argument_type, "User.name(locale:)"],
+            [:required_additional_argument, "User.name(formal:)"],
+            [:missing_interface_field, "User.createdAt"],
+            [:directive_in_invalid_location, "User"],
+          ],
+          e.errors.map { |error| [error.kind, error.coordinate] },
+        )
+        assert_equal(
+          "Input object `CycleA` references itself through non-null fields that cannot be broken: " \
+            "`CycleA.b` -> `CycleB.a`",
+          e.errors.first.message,
+        )
+        assert_equal(
+          "Invalid type system. Errors:\n#{e.errors.map(&:message).join("\n")}",
+          e.message,
+        )
+      end
+    end
+  end
+end